@@ -0,0 +1,76 @@
+//! Frame time of an inspector showing a `Vec` of 100 000 items, for several page sizes.
+//!
+//! Run with `cargo bench -p egui_field_editor --bench large_vec`.
+
+use std::time::{Duration, Instant};
+
+use egui_field_editor::EguiInspector;
+
+const LEN: usize = 100_000;
+
+fn run_frame(ctx: &egui::Context, samples: &mut Vec<f32>, page_size: usize, events: Vec<egui::Event>) -> (Duration, egui::FullOutput) {
+    let input = egui::RawInput {
+        events,
+        ..Default::default()
+    };
+    let start = Instant::now();
+    let mut output = ctx.run(input, |ctx| {
+        egui::CentralPanel::default().show(ctx, |ui| {
+            ui.add(EguiInspector::new(samples).with_page_size(page_size));
+        });
+    });
+    let elapsed = start.elapsed();
+    output.textures_delta.clear();
+    (elapsed, output)
+}
+
+/// Texts laid out in a frame, with their center.
+fn texts(output: &egui::FullOutput) -> Vec<(String, egui::Pos2)> {
+    output
+        .shapes
+        .iter()
+        .filter_map(|clipped| match &clipped.shape {
+            egui::epaint::Shape::Text(text) => Some((text.galley.text().to_owned(), text.pos + text.galley.rect.center().to_vec2())),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Clicks on the header of the list so that its items are rendered.
+///
+/// Panics if the first item is not laid out afterwards, as the frames would then not be
+/// rendering the list.
+fn open_list(ctx: &egui::Context, samples: &mut Vec<f32>, page_size: usize) {
+    let (_, output) = run_frame(ctx, samples, page_size, vec![]);
+    let header = texts(&output);
+    let Some((_, pos)) = header.first().cloned() else {
+        panic!("the list header is not shown");
+    };
+    let click = |pressed| egui::Event::PointerButton {
+        pos,
+        button: egui::PointerButton::Primary,
+        pressed,
+        modifiers: Default::default(),
+    };
+    run_frame(ctx, samples, page_size, vec![egui::Event::PointerMoved(pos), click(true)]);
+    run_frame(ctx, samples, page_size, vec![click(false)]);
+    let (_, output) = run_frame(ctx, samples, page_size, vec![]);
+    let texts = texts(&output);
+    assert!(
+        texts.iter().any(|(text, _)| text == "Item 0"),
+        "the list did not open, header {header:?}, shown {texts:?}"
+    );
+}
+
+fn main() {
+    let mut samples: Vec<f32> = (0..LEN).map(|i| i as f32).collect();
+    for (page_size, frames) in [(100, 100), (1_000, 20), (LEN, 3)] {
+        let ctx = egui::Context::default();
+        open_list(&ctx, &mut samples, page_size);
+        let total: Duration = (0..frames).map(|_| run_frame(&ctx, &mut samples, page_size, vec![]).0).sum();
+        println!(
+            "{LEN} items, page size {page_size:>6}: {:>10.3} ms/frame",
+            total.as_secs_f64() * 1000. / frames as f64
+        );
+    }
+}
@@ -89,8 +89,8 @@ impl MyApp {
 fn println_hello() {
 	println!("Hello");
 }
-fn inspect_num(data: &mut i16, label: &str, tooltip:&str, read_only: bool, ui: &mut egui::Ui) {
-	egui_field_editor::add_number(data, label, tooltip, read_only, None, ui);
+fn inspect_num(data: &mut i16, label: &str, tooltip:&str, read_only: bool, ui: &mut egui::Ui) -> egui_field_editor::InspectResponse {
+	egui_field_editor::add_number(data, label, tooltip, read_only, None, ui)
 }
 impl eframe::App for MyApp {
 	fn update(&mut self, ctx: &egui::Context, _frame: &mut eframe::Frame) {
@@ -33,24 +33,25 @@ struct MyApp {
 	an_ipv4:Ipv4Addr
 }
 impl egui_field_editor::EguiInspect for MyApp {
-	fn inspect_with_custom_id(&mut self, _parent_id: egui::Id, label: &str, _tooltip: &str, read_only: bool, ui: &mut egui::Ui) {
+	fn inspect_with_custom_id(&mut self, _parent_id: egui::Id, label: &str, _tooltip: &str, read_only: bool, ui: &mut egui::Ui) -> egui_field_editor::InspectResponse {
 		let id = if _parent_id == egui::Id::NULL { ui.next_auto_id() } else { _parent_id.with(label) };
 		let _parent_id_to_provide_to_children = if _parent_id == egui::Id::NULL { egui::Id::NULL } else { id };
 		let mut add_content=|ui:&mut egui::Ui| {
-			egui_field_editor::add_bool(&mut self.a_bool, "Bool", "Boolean Tooltip", read_only, ui);
-			egui_field_editor::add_number(&mut self.an_int, "Integer", "Integer Tooltip", read_only, None, ui);
-			egui_field_editor::add_number(&mut self.an_uint, "Unsigned Integer", "Unsigned Integer Tooltip with min/max", read_only, Some((12, 50000)), ui);
-			egui_field_editor::add_number_slider(&mut self.a_float, "Float", "Float Slider Tooltip", read_only, -12., 50., ui);
-			egui_field_editor::add_color(&mut self.a_color, "Color", "", read_only, ui);
-			egui_field_editor::add_string_singleline(&mut self.a_string, "String", "", read_only, ui);
-			egui_field_editor::add_string_multiline(&mut self.a_second_string, "Multiline String", "", read_only, 4, ui);
-			egui_field_editor::add_combobox(&mut self.an_index, "Combobox", "", read_only, &["Choice 1".to_string(),"Choice 2".to_string(),"Choice 3".to_string()], ui);
-			egui_field_editor::add_string_convertible(&mut self.an_ipv4, "IPv4", "", false, ui);
+			let mut response = egui_field_editor::add_bool(&mut self.a_bool, "Bool", "Boolean Tooltip", read_only, ui);
+			response |= egui_field_editor::add_number(&mut self.an_int, "Integer", "Integer Tooltip", read_only, None, ui);
+			response |= egui_field_editor::add_number(&mut self.an_uint, "Unsigned Integer", "Unsigned Integer Tooltip with min/max", read_only, Some((12, 50000)), ui);
+			response |= egui_field_editor::add_number_slider(&mut self.a_float, "Float", "Float Slider Tooltip", read_only, -12., 50., ui);
+			response |= egui_field_editor::add_color(&mut self.a_color, "Color", "", read_only, ui);
+			response |= egui_field_editor::add_string_singleline(&mut self.a_string, "String", "", read_only, ui);
+			response |= egui_field_editor::add_string_multiline(&mut self.a_second_string, "Multiline String", "", read_only, 4, ui);
+			response |= egui_field_editor::add_combobox(&mut self.an_index, "Combobox", "", read_only, &["Choice 1".to_string(),"Choice 2".to_string(),"Choice 3".to_string()], ui);
+			response |= egui_field_editor::add_string_convertible(&mut self.an_ipv4, "IPv4", "", false, ui);
+			response
 		};
 		if !label.is_empty() {
-			egui::CollapsingHeader::new(label).id_salt(id).show(ui, add_content);
+			egui::CollapsingHeader::new(label).id_salt(id).show(ui, add_content).body_returned.unwrap_or_default()
 		} else {
-			add_content(ui);
+			add_content(ui)
 		}
 	}
 }
@@ -1,16 +1,200 @@
 use std::cell::RefCell;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::hash::{BuildHasher, Hash};
+use std::marker::PhantomData;
 use std::rc::Rc;
 use std::sync::{Arc, Mutex, RwLock};
 use std::ops::Add;
-use egui::{Color32, Ui};
-use crate::EguiInspect;
+use egui::{emath::Numeric, Color32, Ui};
+use std::any::Any;
+use std::fmt::Display;
+use crate::{ChangeKind, EguiDisplay, EguiInspect, FieldChange, FieldInfo, FieldScope, InspectResponse, ValidationError};
+use crate::derive_support::{no_field, set_from_str, split_field, split_index};
+use crate::context::{field_label, label_text};
+use crate::field_menu::add_field_menu;
+use crate::search::{self, header_open, is_field_visible};
+use crate::multi::{is_mixed, MIXED};
+use crate::baseline::{reset_button, reset_width};
+use crate::pages::{page_items, page_range};
+use crate::range::NumericRange;
+use crate::list::{apply_item_action, item_label, item_menu, list_buttons, move_item, ItemAction, ItemKeys, ListLimits};
+
+/// [`EguiInspect::diff`] of the types compared as a whole.
+fn diff_by_eq<T: PartialEq + ?Sized>(a: &T, b: &T, path: &str, diffs: &mut Vec<String>) {
+	if a != b {
+		diffs.push(path.to_owned());
+	}
+}
+
+/// [`EguiInspect::diff`] of the containers: compares the items by index.
+fn diff_items<T: EguiInspect>(a: &[T], b: &[T], path: &str, diffs: &mut Vec<String>) {
+	for (index, (a, b)) in a.iter().zip(b).enumerate() {
+		a.diff(b, &format!("{path}[{index}]"), diffs);
+	}
+	for index in a.len().min(b.len())..a.len().max(b.len()) {
+		diffs.push(format!("{path}[{index}]"));
+	}
+}
+
+/// [`EguiInspect::collect_changes`] of the types compared as a whole.
+fn change_by_eq<T: PartialEq + Display + ?Sized>(old: &T, new: &T, path: &str, changes: &mut Vec<FieldChange>) {
+	if old != new {
+		changes.push(FieldChange::set(path, old, new));
+	}
+}
+
+/// `true` if [`EguiInspect::diff`] finds no difference between `a` and `b`.
+fn same<T: EguiInspect>(a: &T, b: &T) -> bool {
+	crate::diff_paths(a, b).is_empty()
+}
+
+/// [`EguiInspect::collect_changes`] of the containers of fixed length: reports an item moved by
+/// a drag and drop, or compares the items by index.
+fn item_changes<T: EguiInspect>(old: &[T], new: &[T], path: &str, changes: &mut Vec<FieldChange>) {
+	if old.len() == new.len() {
+		let differs = |index: &usize| !same(&old[*index], &new[*index]);
+		if let (Some(first), Some(last)) = ((0..old.len()).find(differs), (0..old.len()).rfind(differs)) {
+			let moved_down = same(&old[first], &new[last])
+				&& old[first + 1..=last].iter().zip(&new[first..last]).all(|(a, b)| same(a, b));
+			let moved_up = same(&old[last], &new[first])
+				&& old[first..last].iter().zip(&new[first + 1..=last]).all(|(a, b)| same(a, b));
+			if first < last && (moved_down || moved_up) {
+				let (from, to) = if moved_down { (first, last) } else { (last, first) };
+				changes.push(FieldChange::new(path, ChangeKind::Move { from, to }));
+				return;
+			}
+		}
+	}
+	for (index, (a, b)) in old.iter().zip(new).enumerate() {
+		a.collect_changes(b, &format!("{path}[{index}]"), changes);
+	}
+}
+
+/// [`EguiInspect::collect_changes`] of the lists: reports an inserted or removed item, or
+/// compares the items by index and reports the items added or removed at the end.
+fn list_changes<T: EguiInspect + Default>(old: &[T], new: &[T], path: &str, changes: &mut Vec<FieldChange>) {
+	let first = old.iter().zip(new).position(|(a, b)| !same(a, b)).unwrap_or(old.len().min(new.len()));
+	if new.len() == old.len() + 1 && old[first..].iter().zip(&new[first + 1..]).all(|(a, b)| same(a, b)) {
+		inserted(&new[first], &format!("{path}[{first}]"), changes);
+		return;
+	}
+	if old.len() == new.len() + 1 && old[first + 1..].iter().zip(&new[first..]).all(|(a, b)| same(a, b)) {
+		changes.push(FieldChange::new(&format!("{path}[{first}]"), ChangeKind::Remove));
+		return;
+	}
+	let len = old.len().min(new.len());
+	item_changes(&old[..len], &new[..len], path, changes);
+	// from the end, so that the indices stay valid when the changes are replayed
+	for index in (len..old.len()).rev() {
+		changes.push(FieldChange::new(&format!("{path}[{index}]"), ChangeKind::Remove));
+	}
+	for (index, item) in new.iter().enumerate().skip(len) {
+		inserted(item, &format!("{path}[{index}]"), changes);
+	}
+}
+
+/// Reports `value` as inserted at `path`: a [`ChangeKind::Insert`] followed by its differences
+/// with the default value (see [`EguiInspect::default_value`]).
+fn inserted<T: EguiInspect>(value: &T, path: &str, changes: &mut Vec<FieldChange>) {
+	changes.push(FieldChange::new(path, ChangeKind::Insert));
+	if let Some(default) = T::default_value() {
+		default.collect_changes(value, path, changes);
+	}
+}
+
+/// [`EguiInspect::copy_field_from`] of the types without fields.
+fn copy_value<T: Clone>(value: &mut T, other: &T, path: &str) -> Result<(), String> {
+	if !path.is_empty() {
+		return Err(no_field(path));
+	}
+	value.clone_from(other);
+	Ok(())
+}
+
+/// [`EguiInspect::copy_field_from`] of the containers: `path` starts with the index of the
+/// item, or is empty to copy every item (`items` must be as long as `other`).
+fn copy_items<T: EguiInspect>(items: &mut [T], other: &[T], path: &str) -> Result<(), String> {
+	if path.is_empty() {
+		return items.iter_mut().zip(other).try_for_each(|(item, other)| item.copy_field_from(other, ""));
+	}
+	let (index, rest) = split_index(path).ok_or_else(|| no_field(path))?;
+	match (items.get_mut(index), other.get(index)) {
+		(Some(item), Some(other)) => item.copy_field_from(other, rest),
+		_ => Err(no_field(path)),
+	}
+}
+
+/// [`EguiInspect::get_by_path`] of the types without fields.
+fn get_value<'a, T: Any>(value: &'a T, path: &str) -> Option<&'a dyn Any> {
+	path.is_empty().then_some(value as &dyn Any)
+}
+
+/// [`EguiInspect::collect_field_paths`] of the containers: lists the items by index.
+fn collect_items<'a, T: EguiInspect + 'a>(items: impl IntoIterator<Item = &'a T>, path: &str, fields: &mut Vec<FieldInfo>) {
+	for (index, item) in items.into_iter().enumerate() {
+		let item_path = format!("{path}[{index}]");
+		fields.push(FieldInfo {
+			path: item_path.clone(),
+			label: format!("Item {index}"),
+			tooltip: String::new(),
+			type_name: std::any::type_name::<T>(),
+			read_only: false,
+			attributes: &[],
+		});
+		item.collect_field_paths(&item_path, fields);
+	}
+}
+
+/// [`EguiInspect::get_by_path`] of the containers: `path` starts with the index of the item.
+fn get_item<'a, T: EguiInspect>(items: &'a [T], path: &str) -> Option<&'a dyn Any> {
+	let (index, rest) = split_index(path)?;
+	items.get(index)?.get_by_path(rest)
+}
+
+/// [`EguiInspect::set_by_path_from_str`] of the containers: `path` starts with the index of the item.
+fn set_item<T: EguiInspect>(items: &mut [T], path: &str, value: &str) -> Result<(), String> {
+	let (index, rest) = split_index(path).ok_or_else(|| no_field(path))?;
+	items.get_mut(index).ok_or_else(|| no_field(path))?.set_by_path_from_str(rest, value)
+}
+
+/// Implements [`EguiDisplay`] by inspecting a read-only copy of the value.
+macro_rules! impl_display_by_copy {
+	($($t:ty),+) => {
+		$(
+			impl crate::EguiDisplay for $t {
+				fn display_with_custom_id(&self, parent_id: egui::Id, label: &str, tooltip: &str, ui: &mut egui::Ui) {
+					let mut value = *self;
+					crate::EguiInspect::inspect_with_custom_id(&mut value, parent_id, label, tooltip, true, ui);
+				}
+			}
+		)*
+	}
+}
 
 macro_rules! impl_inspect_number {
 	($($t:ty),+) => {
 		$(
 			impl crate::EguiInspect for $t {
-				fn inspect_with_custom_id(&mut self, _parent_id: egui::Id, label: &str, tooltip: &str, read_only: bool, ui: &mut egui::Ui) {
-					crate::add_number(self, label.into(), tooltip, read_only, None, ui);
+				fn inspect_with_custom_id(&mut self, _parent_id: egui::Id, label: &str, tooltip: &str, read_only: bool, ui: &mut egui::Ui) -> InspectResponse {
+					crate::add_number(self, label.into(), tooltip, read_only, None, ui)
+				}
+				fn diff(&self, other: &Self, path: &str, diffs: &mut Vec<String>) {
+					diff_by_eq(self, other, path, diffs)
+				}
+				fn collect_changes(&self, other: &Self, path: &str, changes: &mut Vec<FieldChange>) {
+					change_by_eq(self, other, path, changes)
+				}
+				fn get_by_path(&self, path: &str) -> Option<&dyn Any> {
+					get_value(self, path)
+				}
+				fn set_by_path_from_str(&mut self, path: &str, value: &str) -> Result<(), String> {
+					set_from_str(self, path, value)
+				}
+				fn copy_field_from(&mut self, other: &Self, path: &str) -> Result<(), String> {
+					copy_value(self, other, path)
+				}
+				fn default_value() -> Option<Self> {
+					Some(Self::default())
 				}
 			}
 		)*
@@ -23,16 +207,89 @@ impl_inspect_number!(i16, u16);
 impl_inspect_number!(i32, u32);
 impl_inspect_number!(i64, u64);
 impl_inspect_number!(isize, usize);
+impl_display_by_copy!(f32, f64, i8, u8, i16, u16, i32, u32, i64, u64, isize, usize);
+impl_display_by_copy!(&'static str, bool, char, Color32);
 
 impl<T:EguiInspect> EguiInspect for &mut T {
-	fn inspect_with_custom_id(&mut self, parent_id: egui::Id, label: &str, tooltip: &str, read_only: bool, ui: &mut egui::Ui) {
-		<T as EguiInspect>::inspect_with_custom_id(*self, parent_id, label, tooltip, read_only, ui);
+	fn inspect_with_custom_id(&mut self, parent_id: egui::Id, label: &str, tooltip: &str, read_only: bool, ui: &mut egui::Ui) -> InspectResponse {
+		<T as EguiInspect>::inspect_with_custom_id(*self, parent_id, label, tooltip, read_only, ui)
+	}
+	fn validate(&self, path: &str, errors: &mut Vec<ValidationError>) {
+		(**self).validate(path, errors)
+	}
+	fn diff(&self, other: &Self, path: &str, diffs: &mut Vec<String>) {
+		(**self).diff(other, path, diffs)
+	}
+	fn collect_changes(&self, other: &Self, path: &str, changes: &mut Vec<FieldChange>) {
+		(**self).collect_changes(other, path, changes)
+	}
+	fn collect_field_paths(&self, path: &str, fields: &mut Vec<FieldInfo>) {
+		(**self).collect_field_paths(path, fields)
+	}
+	fn get_by_path(&self, path: &str) -> Option<&dyn Any> {
+		(**self).get_by_path(path)
+	}
+	fn set_by_path_from_str(&mut self, path: &str, value: &str) -> Result<(), String> {
+		(**self).set_by_path_from_str(path, value)
+	}
+	fn copy_field_from(&mut self, other: &Self, path: &str) -> Result<(), String> {
+		(**self).copy_field_from(other, path)
 	}
 }
 
 impl<T:EguiInspect> EguiInspect for Box<T> {
-	fn inspect_with_custom_id(&mut self, parent_id: egui::Id, label: &str, tooltip: &str, read_only: bool, ui: &mut egui::Ui) {
-		<T as EguiInspect>::inspect_with_custom_id(&mut *self, parent_id, label, tooltip, read_only, ui);
+	fn inspect_with_custom_id(&mut self, parent_id: egui::Id, label: &str, tooltip: &str, read_only: bool, ui: &mut egui::Ui) -> InspectResponse {
+		<T as EguiInspect>::inspect_with_custom_id(&mut *self, parent_id, label, tooltip, read_only, ui)
+	}
+	fn validate(&self, path: &str, errors: &mut Vec<ValidationError>) {
+		(**self).validate(path, errors)
+	}
+	fn diff(&self, other: &Self, path: &str, diffs: &mut Vec<String>) {
+		(**self).diff(other, path, diffs)
+	}
+	fn collect_changes(&self, other: &Self, path: &str, changes: &mut Vec<FieldChange>) {
+		(**self).collect_changes(other, path, changes)
+	}
+	fn collect_field_paths(&self, path: &str, fields: &mut Vec<FieldInfo>) {
+		(**self).collect_field_paths(path, fields)
+	}
+	fn get_by_path(&self, path: &str) -> Option<&dyn Any> {
+		(**self).get_by_path(path)
+	}
+	fn set_by_path_from_str(&mut self, path: &str, value: &str) -> Result<(), String> {
+		(**self).set_by_path_from_str(path, value)
+	}
+	fn copy_field_from(&mut self, other: &Self, path: &str) -> Result<(), String> {
+		(**self).copy_field_from(other, path)
+	}
+	fn default_value() -> Option<Self> {
+		T::default_value().map(Box::new)
+	}
+}
+
+impl<T: EguiDisplay + ?Sized> EguiDisplay for &T {
+	fn display_with_custom_id(&self, parent_id: egui::Id, label: &str, tooltip: &str, ui: &mut egui::Ui) {
+		<T as EguiDisplay>::display_with_custom_id(*self, parent_id, label, tooltip, ui)
+	}
+}
+impl<T: EguiDisplay + ?Sized> EguiDisplay for &mut T {
+	fn display_with_custom_id(&self, parent_id: egui::Id, label: &str, tooltip: &str, ui: &mut egui::Ui) {
+		<T as EguiDisplay>::display_with_custom_id(*self, parent_id, label, tooltip, ui)
+	}
+}
+impl<T: EguiDisplay + ?Sized> EguiDisplay for Box<T> {
+	fn display_with_custom_id(&self, parent_id: egui::Id, label: &str, tooltip: &str, ui: &mut egui::Ui) {
+		<T as EguiDisplay>::display_with_custom_id(self, parent_id, label, tooltip, ui)
+	}
+}
+impl<T: EguiDisplay + ?Sized> EguiDisplay for Rc<T> {
+	fn display_with_custom_id(&self, parent_id: egui::Id, label: &str, tooltip: &str, ui: &mut egui::Ui) {
+		<T as EguiDisplay>::display_with_custom_id(self, parent_id, label, tooltip, ui)
+	}
+}
+impl<T: EguiDisplay + ?Sized> EguiDisplay for Arc<T> {
+	fn display_with_custom_id(&self, parent_id: egui::Id, label: &str, tooltip: &str, ui: &mut egui::Ui) {
+		<T as EguiDisplay>::display_with_custom_id(self, parent_id, label, tooltip, ui)
 	}
 }
 /*
@@ -49,9 +306,57 @@ impl<T: EguiInspect+Display> EguiInspect for Rc<RefCell<T>> {
 	}
 }*/
 impl<T: EguiInspect> EguiInspect for Rc<RefCell<T>> {
-	fn inspect_with_custom_id(&mut self, parent_id: egui::Id, label: &str, tooltip: &str, read_only: bool, ui: &mut egui::Ui) {
+	fn inspect_with_custom_id(&mut self, parent_id: egui::Id, label: &str, tooltip: &str, read_only: bool, ui: &mut egui::Ui) -> InspectResponse {
 		if let Ok(mut inner) = self.try_borrow_mut() {
-			inner.inspect_with_custom_id(parent_id, label, tooltip, read_only, ui);
+			inner.inspect_with_custom_id(parent_id, label, tooltip, read_only, ui)
+		} else {
+			ui.label("🔒 Already borrowed");
+			InspectResponse::default()
+		}
+	}
+	fn validate(&self, path: &str, errors: &mut Vec<ValidationError>) {
+		if let Ok(inner) = self.try_borrow() {
+			inner.validate(path, errors)
+		}
+	}
+	fn diff(&self, other: &Self, path: &str, diffs: &mut Vec<String>) {
+		if let (Ok(inner), Ok(other)) = (self.try_borrow(), other.try_borrow()) {
+			inner.diff(&other, path, diffs)
+		}
+	}
+	fn collect_changes(&self, other: &Self, path: &str, changes: &mut Vec<FieldChange>) {
+		// a clone of the object shares the data: its edits can't be found
+		if Rc::ptr_eq(self, other) {
+			return;
+		}
+		if let (Ok(inner), Ok(other)) = (self.try_borrow(), other.try_borrow()) {
+			inner.collect_changes(&other, path, changes)
+		}
+	}
+	fn collect_field_paths(&self, path: &str, fields: &mut Vec<FieldInfo>) {
+		if let Ok(inner) = self.try_borrow() {
+			inner.collect_field_paths(path, fields)
+		}
+	}
+	// the fields can't be borrowed out of the `RefCell`: `get_by_path` always returns `None`
+	fn set_by_path_from_str(&mut self, path: &str, value: &str) -> Result<(), String> {
+		self.try_borrow_mut().map_err(|e| e.to_string())?.set_by_path_from_str(path, value)
+	}
+	fn copy_field_from(&mut self, other: &Self, path: &str) -> Result<(), String> {
+		if Rc::ptr_eq(self, other) {
+			return Ok(());
+		}
+		let other = other.try_borrow().map_err(|e| e.to_string())?;
+		self.try_borrow_mut().map_err(|e| e.to_string())?.copy_field_from(&other, path)
+	}
+	fn default_value() -> Option<Self> {
+		T::default_value().map(|value| Rc::new(RefCell::new(value)))
+	}
+}
+impl<T: EguiDisplay> EguiDisplay for RefCell<T> {
+	fn display_with_custom_id(&self, parent_id: egui::Id, label: &str, tooltip: &str, ui: &mut egui::Ui) {
+		if let Ok(inner) = self.try_borrow() {
+			inner.display_with_custom_id(parent_id, label, tooltip, ui)
 		} else {
 			ui.label("🔒 Already borrowed");
 		}
@@ -65,10 +370,65 @@ impl<T: EguiInspect> EguiInspect for Arc<Mutex<T>> {
 		tooltip: &str,
 		read_only: bool,
 		ui: &mut Ui,
-	) {
+	) -> InspectResponse {
 		match self.lock() {
 			Ok(mut inner) => {
-				inner.inspect_with_custom_id(parent_id, label, tooltip, read_only, ui);
+				inner.inspect_with_custom_id(parent_id, label, tooltip, read_only, ui)
+			}
+			Err(_) => {
+				ui.label("❌ Failed to acquire lock");
+				InspectResponse::default()
+			}
+		}
+	}
+	fn validate(&self, path: &str, errors: &mut Vec<ValidationError>) {
+		if let Ok(inner) = self.lock() {
+			inner.validate(path, errors)
+		}
+	}
+	fn diff(&self, other: &Self, path: &str, diffs: &mut Vec<String>) {
+		// Locking the same mutex twice would deadlock
+		if Arc::ptr_eq(self, other) {
+			return;
+		}
+		if let (Ok(inner), Ok(other)) = (self.lock(), other.lock()) {
+			inner.diff(&other, path, diffs)
+		}
+	}
+	fn collect_changes(&self, other: &Self, path: &str, changes: &mut Vec<FieldChange>) {
+		// a clone of the object shares the data: its edits can't be found
+		if Arc::ptr_eq(self, other) {
+			return;
+		}
+		if let (Ok(inner), Ok(other)) = (self.lock(), other.lock()) {
+			inner.collect_changes(&other, path, changes)
+		}
+	}
+	fn collect_field_paths(&self, path: &str, fields: &mut Vec<FieldInfo>) {
+		if let Ok(inner) = self.lock() {
+			inner.collect_field_paths(path, fields)
+		}
+	}
+	// the fields can't be borrowed out of the `Mutex`: `get_by_path` always returns `None`
+	fn set_by_path_from_str(&mut self, path: &str, value: &str) -> Result<(), String> {
+		self.lock().map_err(|e| e.to_string())?.set_by_path_from_str(path, value)
+	}
+	fn copy_field_from(&mut self, other: &Self, path: &str) -> Result<(), String> {
+		if Arc::ptr_eq(self, other) {
+			return Ok(());
+		}
+		let other = other.lock().map_err(|e| e.to_string())?;
+		self.lock().map_err(|e| e.to_string())?.copy_field_from(&other, path)
+	}
+	fn default_value() -> Option<Self> {
+		T::default_value().map(|value| Arc::new(Mutex::new(value)))
+	}
+}
+impl<T: EguiDisplay> EguiDisplay for Mutex<T> {
+	fn display_with_custom_id(&self, parent_id: egui::Id, label: &str, tooltip: &str, ui: &mut egui::Ui) {
+		match self.lock() {
+			Ok(inner) => {
+				inner.display_with_custom_id(parent_id, label, tooltip, ui)
 			}
 			Err(_) => {
 				ui.label("❌ Failed to acquire lock");
@@ -84,33 +444,145 @@ impl<T: EguiInspect> EguiInspect for Arc<RwLock<T>> {
 		tooltip: &str,
 		read_only: bool,
 		ui: &mut Ui,
-	) {
+	) -> InspectResponse {
+		// even when `read_only`: `EguiDisplay` (e.g. `EguiInspector::view`) only takes a read lock
 		match self.write() {
 			Ok(mut inner) => {
-				inner.inspect_with_custom_id(parent_id, label, tooltip, read_only, ui);
+				inner.inspect_with_custom_id(parent_id, label, tooltip, read_only, ui)
 			}
 			Err(_) => {
 				ui.label("❌ Failed to acquire write lock");
+				InspectResponse::default()
+			}
+		}
+	}
+	fn validate(&self, path: &str, errors: &mut Vec<ValidationError>) {
+		if let Ok(inner) = self.read() {
+			inner.validate(path, errors)
+		}
+	}
+	fn diff(&self, other: &Self, path: &str, diffs: &mut Vec<String>) {
+		if Arc::ptr_eq(self, other) {
+			return;
+		}
+		if let (Ok(inner), Ok(other)) = (self.read(), other.read()) {
+			inner.diff(&other, path, diffs)
+		}
+	}
+	fn collect_changes(&self, other: &Self, path: &str, changes: &mut Vec<FieldChange>) {
+		// a re-entrant read lock may deadlock
+		if Arc::ptr_eq(self, other) {
+			return;
+		}
+		if let (Ok(inner), Ok(other)) = (self.read(), other.read()) {
+			inner.collect_changes(&other, path, changes)
+		}
+	}
+	fn collect_field_paths(&self, path: &str, fields: &mut Vec<FieldInfo>) {
+		if let Ok(inner) = self.read() {
+			inner.collect_field_paths(path, fields)
+		}
+	}
+	// the fields can't be borrowed out of the `RwLock`: `get_by_path` always returns `None`
+	fn set_by_path_from_str(&mut self, path: &str, value: &str) -> Result<(), String> {
+		self.write().map_err(|e| e.to_string())?.set_by_path_from_str(path, value)
+	}
+	fn copy_field_from(&mut self, other: &Self, path: &str) -> Result<(), String> {
+		if Arc::ptr_eq(self, other) {
+			return Ok(());
+		}
+		let other = other.read().map_err(|e| e.to_string())?;
+		self.write().map_err(|e| e.to_string())?.copy_field_from(&other, path)
+	}
+	fn default_value() -> Option<Self> {
+		T::default_value().map(|value| Arc::new(RwLock::new(value)))
+	}
+}
+impl<T: EguiDisplay> EguiDisplay for RwLock<T> {
+	fn display_with_custom_id(&self, parent_id: egui::Id, label: &str, tooltip: &str, ui: &mut egui::Ui) {
+		match self.read() {
+			Ok(inner) => {
+				inner.display_with_custom_id(parent_id, label, tooltip, ui)
+			}
+			Err(_) => {
+				ui.label("❌ Failed to acquire read lock");
 			}
 		}
 	}
 }
 
 impl crate::EguiInspect for &'static str {
-	fn inspect_with_custom_id(&mut self, _parent_id: egui::Id, label: &str, tooltip: &str, read_only: bool, ui: &mut egui::Ui) {
-		crate::add_string_singleline(self, label, tooltip, read_only, ui);
+	fn inspect_with_custom_id(&mut self, _parent_id: egui::Id, label: &str, tooltip: &str, read_only: bool, ui: &mut egui::Ui) -> InspectResponse {
+		crate::add_string_singleline(self, label, tooltip, read_only, ui)
+	}
+	fn diff(&self, other: &Self, path: &str, diffs: &mut Vec<String>) {
+		diff_by_eq(self, other, path, diffs)
+	}
+	fn collect_changes(&self, other: &Self, path: &str, changes: &mut Vec<FieldChange>) {
+		change_by_eq(self, other, path, changes)
+	}
+	fn get_by_path(&self, path: &str) -> Option<&dyn Any> {
+		get_value(self, path)
+	}
+	fn copy_field_from(&mut self, other: &Self, path: &str) -> Result<(), String> {
+		copy_value(self, other, path)
+	}
+	fn default_value() -> Option<Self> {
+		Some("")
 	}
 }
 
 impl crate::EguiInspect for String {
-	fn inspect_with_custom_id(&mut self, _parent_id: egui::Id, label: &str, tooltip: &str, read_only: bool, ui: &mut egui::Ui) {
-		crate::add_string_singleline(self, label, tooltip, read_only, ui);
+	fn inspect_with_custom_id(&mut self, _parent_id: egui::Id, label: &str, tooltip: &str, read_only: bool, ui: &mut egui::Ui) -> InspectResponse {
+		crate::add_string_singleline(self, label, tooltip, read_only, ui)
+	}
+	fn diff(&self, other: &Self, path: &str, diffs: &mut Vec<String>) {
+		diff_by_eq(self, other, path, diffs)
+	}
+	fn collect_changes(&self, other: &Self, path: &str, changes: &mut Vec<FieldChange>) {
+		change_by_eq(self, other, path, changes)
+	}
+	fn get_by_path(&self, path: &str) -> Option<&dyn Any> {
+		get_value(self, path)
+	}
+	fn set_by_path_from_str(&mut self, path: &str, value: &str) -> Result<(), String> {
+		set_from_str(self, path, value)
+	}
+	fn copy_field_from(&mut self, other: &Self, path: &str) -> Result<(), String> {
+		copy_value(self, other, path)
+	}
+	fn default_value() -> Option<Self> {
+		Some(Self::default())
+	}
+}
+
+impl crate::EguiDisplay for String {
+	fn display_with_custom_id(&self, _parent_id: egui::Id, label: &str, tooltip: &str, ui: &mut egui::Ui) {
+		crate::add_string_singleline(&mut self.as_str(), label, tooltip, true, ui);
 	}
 }
 
 impl crate::EguiInspect for bool {
-	fn inspect_with_custom_id(&mut self, _parent_id: egui::Id, label: &str, tooltip: &str, read_only: bool, ui: &mut egui::Ui) {
-		crate::add_bool(self, label, tooltip, read_only, ui);
+	fn inspect_with_custom_id(&mut self, _parent_id: egui::Id, label: &str, tooltip: &str, read_only: bool, ui: &mut egui::Ui) -> InspectResponse {
+		crate::add_bool(self, label, tooltip, read_only, ui)
+	}
+	fn diff(&self, other: &Self, path: &str, diffs: &mut Vec<String>) {
+		diff_by_eq(self, other, path, diffs)
+	}
+	fn collect_changes(&self, other: &Self, path: &str, changes: &mut Vec<FieldChange>) {
+		change_by_eq(self, other, path, changes)
+	}
+	fn get_by_path(&self, path: &str) -> Option<&dyn Any> {
+		get_value(self, path)
+	}
+	fn set_by_path_from_str(&mut self, path: &str, value: &str) -> Result<(), String> {
+		set_from_str(self, path, value)
+	}
+	fn copy_field_from(&mut self, other: &Self, path: &str) -> Result<(), String> {
+		copy_value(self, other, path)
+	}
+	fn default_value() -> Option<Self> {
+		Some(Self::default())
 	}
 }
 struct CharString(String);
@@ -143,10 +615,29 @@ impl egui::TextBuffer for CharString {
 	}
 }
 impl crate::EguiInspect for char {
-	fn inspect_with_custom_id(&mut self, _parent_id: egui::Id, label: &str, tooltip: &str, read_only: bool, ui: &mut egui::Ui) {
+	fn inspect_with_custom_id(&mut self, _parent_id: egui::Id, label: &str, tooltip: &str, read_only: bool, ui: &mut egui::Ui) -> InspectResponse {
 		let mut string = CharString::new(*self);
-		crate::add_string_singleline( &mut string, label, tooltip, read_only, ui);
+		let response = crate::add_string_singleline( &mut string, label, tooltip, read_only, ui);
 		*self=string.char();
+		response
+	}
+	fn diff(&self, other: &Self, path: &str, diffs: &mut Vec<String>) {
+		diff_by_eq(self, other, path, diffs)
+	}
+	fn collect_changes(&self, other: &Self, path: &str, changes: &mut Vec<FieldChange>) {
+		change_by_eq(self, other, path, changes)
+	}
+	fn get_by_path(&self, path: &str) -> Option<&dyn Any> {
+		get_value(self, path)
+	}
+	fn set_by_path_from_str(&mut self, path: &str, value: &str) -> Result<(), String> {
+		set_from_str(self, path, value)
+	}
+	fn copy_field_from(&mut self, other: &Self, path: &str) -> Result<(), String> {
+		copy_value(self, other, path)
+	}
+	fn default_value() -> Option<Self> {
+		Some(Self::default())
 	}
 }
 
@@ -154,116 +645,1070 @@ impl crate::EguiInspect for char {
 struct EnumeratedItem<T> {
 	item: T,
 	index: usize,
+	label: String,
+	salt_id: egui::Id
+}
+
+impl<T: crate::EguiInspect> egui_dnd::DragDropItem for EnumeratedItem<&mut T> {
+	fn id(&self) -> egui::Id {
+		self.salt_id.with(&self.label)
+	}
+}
+
+/// Inspects the given items of a container (with their index) matching the search, without
+/// drag and drop.
+fn inspect_found_items<'a, T: crate::EguiInspect + 'a>(items: impl Iterator<Item = (usize, &'a mut T)>, keys: Option<&ItemKeys>, parent_id: egui::Id, tooltip: &str, read_only: bool, ui: &mut Ui) -> InspectResponse {
+	let mut inspect_response = InspectResponse::default();
+	for (index, item) in items {
+		let _scope = FieldScope::index(ui, index);
+		if is_field_visible(ui) {
+			inspect_response |= item.inspect_with_custom_id(parent_id, &item_label(keys, index), tooltip, read_only, ui);
+		}
+	}
+	inspect_response
+}
+
+/// Inspects the items of a list: only the items of the current page (see [`page_items`]) are
+/// rendered, but every item is searched.
+///
+/// The items are labelled with their `keys` if any (see [`item_label`]). Unless `read_only`, the
+/// items can be reordered by drag and drop, and their drag handle has a
+/// context menu (see [`item_menu`]) whose selected action is returned with the item index.
+#[allow(clippy::too_many_arguments)]
+fn inspect_items<T: crate::EguiInspect>(items: &mut [T], keys: Option<&ItemKeys>, id: egui::Id, parent_id: egui::Id, tooltip: &str, read_only: bool, limits: ListLimits, duplicate: Option<fn(&T) -> Option<T>>, ui: &mut Ui) -> (InspectResponse, Option<(usize, ItemAction<T>)>) {
+	if search::is_active(ui) {
+		let found = page_items(items.len(), id, ui);
+		let items = items.iter_mut().enumerate().filter(|(index, _)| found.binary_search(index).is_ok());
+		return (inspect_found_items(items, keys, parent_id, tooltip, read_only, ui), None);
+	}
+	let len = items.len();
+	let range = page_range(len, id, ui);
+	let offset = range.start;
+	let page = &mut items[range];
+	if read_only {
+		let items = page.iter_mut().enumerate().map(|(i, item)| (offset + i, item));
+		return (inspect_found_items(items, keys, parent_id, tooltip, read_only, ui), None);
+	}
+	let mut inspect_response = InspectResponse::default();
+	let mut action = None;
+	let response = egui_dnd::dnd(ui, id.with("dnd"))
+		.with_animation_time(0.0)
+		.show(
+			page
+				.iter_mut()
+				.enumerate()
+				.map(|(i, item)| EnumeratedItem { item, index: offset + i, label: item_label(keys, offset + i), salt_id: id }),
+			|ui, item, handle, state| {
+				ui.horizontal(|ui| {
+					let handle_response = handle.sense(egui::Sense::click_and_drag()).ui(ui, |ui| {
+						if state.dragged {
+							ui.label("≡");
+						} else {
+							ui.label("☰");
+						}
+					});
+					let index = item.index;
+					if let Some(item_action) = item_menu(&handle_response.on_hover_text("Drag to reorder, right-click for more actions"), &*item.item, index, len, limits, duplicate) {
+						action = Some((index, item_action));
+					}
+					let _scope = FieldScope::index(ui, index);
+					inspect_response |= item.item.inspect_with_custom_id(parent_id, &item.label, tooltip, read_only, ui);
+				});
+			},
+		);
+	if response.is_drag_finished() {
+		response.update_vec(page);
+		inspect_response.mark_changed();
+	}
+	if action.is_some() {
+		inspect_response.mark_changed();
+	}
+	(inspect_response, action)
+}
+
+/// Copy of `item` for "Duplicate": its [`EguiInspect::duplicate`], or else a default item
+/// into which every field of `item` is copied, if they can be.
+fn duplicate<T: crate::EguiInspect + Default>(item: &T) -> Option<T> {
+	item.duplicate().or_else(|| {
+		let mut copy = T::default();
+		copy.copy_field_from(item, "").ok().map(|()| copy)
+	})
+}
+
+impl<T: crate::EguiInspect, const N: usize> crate::EguiInspect for [T; N] {
+	fn inspect_with_custom_id(&mut self, _parent_id: egui::Id, label: &str, tooltip: &str, read_only: bool, ui: &mut Ui) -> InspectResponse {
+		let id = if _parent_id == egui::Id::NULL { ui.next_auto_id() } else { _parent_id.with(label) };
+		let parent_id = if _parent_id == egui::Id::NULL { egui::Id::NULL } else { id };
+		let keys = ItemKeys::take(ui);
+		let collapsing = egui::CollapsingHeader::new(label_text(&label.to_string().add(format!("[{N}]").as_str()), ui)).id_salt(id.with("collapse")).open(header_open(ui)).show(ui, |ui| {
+			let (inspect_response, action) = inspect_items(self, keys.as_ref(), id, parent_id, tooltip, read_only, ListLimits::FIXED, None, ui);
+			if let Some((index, action)) = action {
+				move_item(self, index, &action);
+			}
+			inspect_response
+		});
+		add_field_menu(&collapsing.header_response, read_only, ui);
+		collapsing.body_returned.unwrap_or_default()
+	}
+	fn validate(&self, path: &str, errors: &mut Vec<ValidationError>) {
+		for (index, item) in self.iter().enumerate() {
+			item.validate(&format!("{path}[{index}]"), errors);
+		}
+	}
+	fn diff(&self, other: &Self, path: &str, diffs: &mut Vec<String>) {
+		diff_items(self, other, path, diffs)
+	}
+	fn collect_changes(&self, other: &Self, path: &str, changes: &mut Vec<FieldChange>) {
+		item_changes(self, other, path, changes)
+	}
+	fn collect_field_paths(&self, path: &str, fields: &mut Vec<FieldInfo>) {
+		collect_items(self, path, fields)
+	}
+	fn get_by_path(&self, path: &str) -> Option<&dyn Any> {
+		get_item(self, path)
+	}
+	fn set_by_path_from_str(&mut self, path: &str, value: &str) -> Result<(), String> {
+		set_item(self, path, value)
+	}
+	fn copy_field_from(&mut self, other: &Self, path: &str) -> Result<(), String> {
+		copy_items(self, other, path)
+	}
+	fn default_value() -> Option<Self> {
+		let items: Option<Vec<T>> = (0..N).map(|_| T::default_value()).collect();
+		items?.try_into().ok()
+	}
+}
+
+impl<T: crate::EguiInspect + Default> crate::EguiInspect for Vec<T> {
+	fn inspect_with_custom_id(
+		&mut self,
+		_parent_id: egui::Id,
+		label: &str,
+		tooltip: &str,
+		read_only: bool,
+		ui: &mut Ui,
+	) -> InspectResponse {
+		let id = if _parent_id == egui::Id::NULL { ui.next_auto_id() } else { _parent_id.with(label) };
+		let parent_id = if _parent_id == egui::Id::NULL { egui::Id::NULL } else { id };
+		let limits = ListLimits::take(ui);
+		let keys = ItemKeys::take(ui);
+		let collapsing = egui::CollapsingHeader::new(label_text(&label.to_string().add(format!("[{}]", self.len()).as_str()), ui)).id_salt(id.with("collapse")).open(header_open(ui)).show(ui, |ui| {
+			let (inspect_response, action) = inspect_items(self, keys.as_ref(), id, parent_id, tooltip, read_only, limits, Some(duplicate::<T>), ui);
+			if let Some((index, action)) = action {
+				apply_item_action(self, index, action);
+			}
+			inspect_response
+		});
+		add_field_menu(&collapsing.header_response, read_only, ui);
+		let inspect_response = collapsing.body_returned.unwrap_or_default();
+		inspect_response | list_buttons(self, id, limits, read_only, ui)
+	}
+	fn validate(&self, path: &str, errors: &mut Vec<ValidationError>) {
+		for (index, item) in self.iter().enumerate() {
+			item.validate(&format!("{path}[{index}]"), errors);
+		}
+	}
+	fn diff(&self, other: &Self, path: &str, diffs: &mut Vec<String>) {
+		diff_items(self, other, path, diffs)
+	}
+	fn collect_changes(&self, other: &Self, path: &str, changes: &mut Vec<FieldChange>) {
+		list_changes(self, other, path, changes)
+	}
+	fn collect_field_paths(&self, path: &str, fields: &mut Vec<FieldInfo>) {
+		collect_items(self, path, fields)
+	}
+	fn get_by_path(&self, path: &str) -> Option<&dyn Any> {
+		get_item(self, path)
+	}
+	fn set_by_path_from_str(&mut self, path: &str, value: &str) -> Result<(), String> {
+		set_item(self, path, value)
+	}
+	fn copy_field_from(&mut self, other: &Self, path: &str) -> Result<(), String> {
+		if path.is_empty() {
+			self.truncate(other.len());
+			self.resize_with(other.len(), T::default);
+		}
+		copy_items(self, other, path)
+	}
+	fn default_value() -> Option<Self> {
+		Some(Self::default())
+	}
+}
+
+impl<T: EguiDisplay> EguiDisplay for [T] {
+	fn display_with_custom_id(&self, _parent_id: egui::Id, label: &str, tooltip: &str, ui: &mut Ui) {
+		let id = if _parent_id == egui::Id::NULL { ui.next_auto_id() } else { _parent_id.with(label) };
+		let parent_id = if _parent_id == egui::Id::NULL { egui::Id::NULL } else { id };
+		let keys = ItemKeys::take(ui);
+		egui::CollapsingHeader::new(label_text(&label.to_string().add(format!("[{}]", self.len()).as_str()), ui)).id_salt(id.with("collapse")).open(header_open(ui)).show(ui, |ui| {
+			for index in page_items(self.len(), id, ui) {
+				let item = &self[index];
+				let _scope = FieldScope::index(ui, index);
+				if !is_field_visible(ui) {
+					continue;
+				}
+				item.display_with_custom_id(parent_id, &item_label(keys.as_ref(), index), tooltip, ui);
+			}
+		});
+	}
+}
+impl<T: EguiDisplay, const N: usize> EguiDisplay for [T; N] {
+	fn display_with_custom_id(&self, parent_id: egui::Id, label: &str, tooltip: &str, ui: &mut Ui) {
+		self.as_slice().display_with_custom_id(parent_id, label, tooltip, ui)
+	}
+}
+impl<T: EguiDisplay> EguiDisplay for Vec<T> {
+	fn display_with_custom_id(&self, parent_id: egui::Id, label: &str, tooltip: &str, ui: &mut Ui) {
+		self.as_slice().display_with_custom_id(parent_id, label, tooltip, ui)
+	}
+}
+
+/// Maps inspected by [`inspect_map`]: their entries are shown sorted by key, or in insertion
+/// order for an `IndexMap`, and the paths of their fields are `map[index].key` and
+/// `map[index].value`, the index being the position of the entry in this order.
+trait InspectMap {
+	type Key: EguiInspect + Clone + PartialEq + Default + Send + Sync + 'static;
+	type Value: EguiInspect + Default;
+	/// `true` if the entries can be reordered by drag and drop.
+	const REORDERABLE: bool = false;
+	fn entry_count(&self) -> usize;
+	/// The entries, in the order they are shown.
+	fn entries(&self) -> Vec<(&Self::Key, &Self::Value)>;
+	/// The entries, in the order they are shown.
+	fn entries_mut(&mut self) -> Vec<(&Self::Key, &mut Self::Value)>;
+	fn value(&self, key: &Self::Key) -> Option<&Self::Value>;
+	fn insert_entry(&mut self, key: Self::Key, value: Self::Value);
+	fn remove_entry(&mut self, key: &Self::Key) -> Option<Self::Value>;
+	fn clear_entries(&mut self);
+	/// Changes the key of an entry, `new_key` being unused.
+	fn rename(&mut self, key: &Self::Key, new_key: Self::Key) {
+		if let Some(value) = self.remove_entry(key) {
+			self.insert_entry(new_key, value);
+		}
+	}
+	/// Moves the entry `from` before the entry `to`, if the map is [`Self::REORDERABLE`].
+	fn move_entry(&mut self, _from: usize, _to: usize) {}
+}
+
+/// Error of an edit giving the key of another entry to an entry of a map.
+const DUPLICATE_KEY: &str = "Another entry has this key";
+
+/// Edited key of an entry of a map: its original key and the edited one.
+///
+/// The key of the entry is only replaced once the edit is finished, so that an entry of a
+/// sorted map doesn't move (with the keyboard focus) at each typed character.
+type KeyDraft<K> = Option<(K, K)>;
+
+/// Action on an entry of a map, applied once the entries are rendered.
+enum EntryAction<K> {
+	/// The edit of the key (see [`KeyDraft`]) is finished.
+	CommitKey,
+	Remove(K),
+}
+
+/// Convenient struct to store a dragable entry of a map
+struct MapEntry<'a, K, V> {
+	key: &'a K,
+	value: &'a mut V,
+	index: usize,
 	salt_id: egui::Id
 }
 
-impl<T: crate::EguiInspect> egui_dnd::DragDropItem for EnumeratedItem<&mut T> {
-	fn id(&self) -> egui::Id {
-		egui::Id::new(self.salt_id.with(self.index))
+impl<K, V> egui_dnd::DragDropItem for MapEntry<'_, K, V> {
+	fn id(&self) -> egui::Id {
+		self.salt_id.with(self.index)
+	}
+}
+
+/// [`EguiInspect::inspect_with_custom_id`] of the maps: the entries, then a button adding an
+/// entry with the default key (if it is unused).
+fn inspect_map<M: InspectMap>(map: &mut M, _parent_id: egui::Id, label: &str, tooltip: &str, read_only: bool, ui: &mut Ui) -> InspectResponse {
+	let id = if _parent_id == egui::Id::NULL { ui.next_auto_id() } else { _parent_id.with(label) };
+	let collapsing = egui::CollapsingHeader::new(label_text(&label.to_string().add(format!("[{}]", map.entry_count()).as_str()), ui)).id_salt(id.with("collapse")).open(header_open(ui)).show(ui, |ui| {
+		inspect_entries(map, id, _parent_id != egui::Id::NULL, tooltip, read_only, ui)
+	});
+	add_field_menu(&collapsing.header_response, read_only, ui);
+	let mut inspect_response = collapsing.body_returned.unwrap_or_default();
+	let can_add = map.value(&M::Key::default()).is_none();
+	if add_button(can_add, "An entry has the default key, change it to add another entry", read_only, ui) {
+		map.insert_entry(M::Key::default(), M::Value::default());
+		inspect_response.mark_changed();
+	}
+	inspect_response
+}
+
+/// Shows the `+` button below a map or a set, disabled with the `disabled_text` tooltip unless
+/// `can_add`, and returns `true` if it was clicked.
+fn add_button(can_add: bool, disabled_text: &str, read_only: bool, ui: &mut Ui) -> bool {
+	ui.add_enabled_ui(!read_only, |ui| {
+		ui.with_layout(egui::Layout::right_to_left(egui::Align::Min), |ui| {
+			ui.add_enabled(can_add, egui::Button::new("+").min_size(egui::Vec2::new(20., 20.)))
+				.on_hover_text("Add an entry")
+				.on_disabled_hover_text(disabled_text)
+				.clicked()
+		}).inner
+	}).inner
+}
+
+/// Inspects the entries of a map: only the entries of the current page (see [`page_items`])
+/// are rendered, but every entry is searched.
+///
+/// Unless `read_only`, the entries can be removed and their key edited (the edit is rejected
+/// if another entry has the same key), and the entries of an `IndexMap` can be reordered.
+fn inspect_entries<M: InspectMap>(map: &mut M, id: egui::Id, has_parent_id: bool, tooltip: &str, read_only: bool, ui: &mut Ui) -> InspectResponse {
+	let draft_id = id.with("egui_field_editor::key_draft");
+	let mut draft = ui.data(|d| d.get_temp::<KeyDraft<M::Key>>(draft_id)).flatten();
+	let duplicate = draft.as_ref().is_some_and(|(key, edited)| edited != key && map.value(edited).is_some());
+	let searching = search::is_active(ui);
+	let shown = page_items(map.entry_count(), id, ui);
+	let mut inspect_response = InspectResponse::default();
+	let mut action = None;
+	let mut moved = None;
+	{
+		let entries = map.entries_mut().into_iter().enumerate().filter(|(index, _)| shown.binary_search(index).is_ok());
+		let mut entry_ui = |index: usize, key: &M::Key, value: &mut M::Value, ui: &mut Ui| {
+			let parent_id = if has_parent_id { id.with(index) } else { egui::Id::NULL };
+			let (response, entry_action) = inspect_entry(key, value, &mut draft, duplicate, parent_id, tooltip, read_only, ui);
+			inspect_response |= response;
+			if entry_action.is_some() {
+				action = entry_action;
+			}
+		};
+		if M::REORDERABLE && !read_only && !searching {
+			let response = egui_dnd::dnd(ui, id.with("dnd"))
+				.with_animation_time(0.0)
+				.show(
+					entries.map(|(index, (key, value))| MapEntry { key, value, index, salt_id: id }),
+					|ui, entry, handle, state| {
+						ui.horizontal(|ui| {
+							handle.ui(ui, |ui| {
+								ui.label(if state.dragged { "≡" } else { "☰" }).on_hover_text("Drag to reorder");
+							});
+							let _scope = FieldScope::index(ui, entry.index);
+							entry_ui(entry.index, entry.key, entry.value, ui);
+						});
+					},
+				);
+			moved = response.final_update();
+		} else {
+			for (index, (key, value)) in entries {
+				let _scope = FieldScope::index(ui, index);
+				if is_field_visible(ui) {
+					entry_ui(index, key, value, ui);
+				}
+			}
+		}
+	}
+	match action {
+		Some(EntryAction::CommitKey) => {
+			// the edit is dropped if another entry has the edited key
+			if let Some((key, edited)) = draft.take()
+				&& edited != key
+				&& map.value(&edited).is_none()
+			{
+				map.rename(&key, edited);
+				inspect_response.mark_changed();
+			}
+		}
+		Some(EntryAction::Remove(key)) => {
+			map.remove_entry(&key);
+			inspect_response.mark_changed();
+		}
+		None => {}
+	}
+	if let Some(update) = moved {
+		map.move_entry(update.from, update.to);
+		inspect_response.mark_changed();
+	}
+	ui.data_mut(|d| d.insert_temp(draft_id, draft));
+	inspect_response
+}
+
+/// Inspects an entry of a map: its remove button next to its key and value.
+#[allow(clippy::too_many_arguments)]
+fn inspect_entry<K: EguiInspect + Clone + PartialEq, V: EguiInspect>(key: &K, value: &mut V, draft: &mut KeyDraft<K>, duplicate: bool, parent_id: egui::Id, tooltip: &str, read_only: bool, ui: &mut Ui) -> (InspectResponse, Option<EntryAction<K>>) {
+	let mut action = None;
+	let inspect_response = ui.horizontal(|ui| {
+		if !read_only && ui.small_button("🗑").on_hover_text("Remove this entry").clicked() {
+			action = Some(EntryAction::Remove(key.clone()));
+		}
+		ui.vertical(|ui| {
+			let commit = {
+				let _scope = FieldScope::new(ui, "key");
+				let error = duplicate.then_some(DUPLICATE_KEY);
+				inspect_key(key, draft, error, parent_id, "Key", tooltip, read_only, ui)
+			};
+			if commit {
+				action = Some(EntryAction::CommitKey);
+			}
+			let _scope = FieldScope::new(ui, "value");
+			value.inspect_with_custom_id(parent_id, "Value", tooltip, read_only, ui)
+		}).inner
+	}).inner;
+	(inspect_response, action)
+}
+
+/// Inspects the key of an entry of a map, or an item of a set, which can't be modified in place.
+///
+/// The edited key is kept in `draft` until the edit is finished, i.e. until its widget loses the
+/// keyboard focus or is released, and `error` is shown below it while it is edited (e.g. if
+/// another entry has the same key). Returns `true` when the edit is finished.
+#[allow(clippy::too_many_arguments)]
+fn inspect_key<K: EguiInspect + Clone + PartialEq>(key: &K, draft: &mut KeyDraft<K>, error: Option<&str>, parent_id: egui::Id, label: &str, tooltip: &str, read_only: bool, ui: &mut Ui) -> bool {
+	let mut edited = match draft {
+		Some((original, edited)) if original == key => edited.clone(),
+		_ => key.clone(),
+	};
+	let key_field = ui.scope(|ui| edited.inspect_with_custom_id(parent_id, label, tooltip, read_only, ui));
+	if key_field.inner.changed() {
+		*draft = Some((key.clone(), edited));
+	}
+	if !draft.as_ref().is_some_and(|(original, _)| original == key) {
+		return false;
+	}
+	if let Some(error) = error {
+		crate::add_validation_error(key_field.response.rect, error, ui);
+	}
+	let idle = ui.memory(|m| m.focused().is_none()) && !ui.input(|i| i.pointer.any_down());
+	key_field.inner.lost_focus() || (idle && !key_field.inner.changed())
+}
+
+/// [`EguiDisplay::display_with_custom_id`] of the maps, `entries` being in the order they are shown.
+fn display_map<K: EguiDisplay, V: EguiDisplay>(entries: Vec<(&K, &V)>, _parent_id: egui::Id, label: &str, tooltip: &str, ui: &mut Ui) {
+	let id = if _parent_id == egui::Id::NULL { ui.next_auto_id() } else { _parent_id.with(label) };
+	egui::CollapsingHeader::new(label_text(&label.to_string().add(format!("[{}]", entries.len()).as_str()), ui)).id_salt(id.with("collapse")).open(header_open(ui)).show(ui, |ui| {
+		for index in page_items(entries.len(), id, ui) {
+			let (key, value) = entries[index];
+			let _scope = FieldScope::index(ui, index);
+			if !is_field_visible(ui) {
+				continue;
+			}
+			let parent_id = if _parent_id == egui::Id::NULL { egui::Id::NULL } else { id.with(index) };
+			{
+				let _scope = FieldScope::new(ui, "key");
+				key.display_with_custom_id(parent_id, "Key", tooltip, ui);
+			}
+			let _scope = FieldScope::new(ui, "value");
+			value.display_with_custom_id(parent_id, "Value", tooltip, ui);
+		}
+	});
+}
+
+/// Splits the path of a field of a map entry, e.g. `value.port`: `true` for the key, `false` for
+/// the value, and the path of the field in it.
+fn split_entry_field(path: &str) -> Option<(bool, &str)> {
+	[("key", true), ("value", false)].into_iter().find_map(|(field, is_key)| {
+		let rest = path.strip_prefix(field)?;
+		if rest.is_empty() || rest.starts_with('[') {
+			Some((is_key, rest))
+		} else {
+			Some((is_key, rest.strip_prefix('.')?))
+		}
+	})
+}
+
+/// [`EguiInspect::validate`] of the maps.
+fn validate_map<M: InspectMap>(map: &M, path: &str, errors: &mut Vec<ValidationError>) {
+	for (index, (key, value)) in map.entries().into_iter().enumerate() {
+		key.validate(&format!("{path}[{index}].key"), errors);
+		value.validate(&format!("{path}[{index}].value"), errors);
+	}
+}
+
+/// [`EguiInspect::diff`] of the maps: compares the values if both maps have the same keys, in
+/// the same order, else reports the whole map.
+fn diff_map<M: InspectMap>(a: &M, b: &M, path: &str, diffs: &mut Vec<String>) {
+	let (a, b) = (a.entries(), b.entries());
+	if a.len() != b.len() || a.iter().zip(&b).any(|((a, _), (b, _))| a != b) {
+		diffs.push(path.to_owned());
+		return;
+	}
+	for (index, ((_, a), (_, b))) in a.into_iter().zip(b).enumerate() {
+		a.diff(b, &format!("{path}[{index}].value"), diffs);
+	}
+}
+
+/// [`EguiInspect::collect_changes`] of the maps: reports the removed entries, then the
+/// inserted entries and the changes of the values of the others.
+fn map_changes<M: InspectMap>(old: &M, new: &M, path: &str, changes: &mut Vec<FieldChange>) {
+	// from the end, so that the indices stay valid when the changes are replayed
+	for (index, (key, _)) in old.entries().into_iter().enumerate().rev() {
+		if new.value(key).is_none() {
+			changes.push(FieldChange::new(&format!("{path}[{index}]"), ChangeKind::Remove));
+		}
+	}
+	for (index, (key, value)) in new.entries().into_iter().enumerate() {
+		let entry_path = format!("{path}[{index}]");
+		match old.value(key) {
+			Some(old_value) => old_value.collect_changes(value, &format!("{entry_path}.value"), changes),
+			None => {
+				changes.push(FieldChange::new(&entry_path, ChangeKind::Insert));
+				M::Key::default().collect_changes(key, &format!("{entry_path}.key"), changes);
+				M::Value::default().collect_changes(value, &format!("{entry_path}.value"), changes);
+			}
+		}
+	}
+}
+
+/// [`EguiInspect::collect_field_paths`] of the maps: lists the keys and values of the entries.
+fn collect_entries<M: InspectMap>(map: &M, path: &str, fields: &mut Vec<FieldInfo>) {
+	for (index, (key, value)) in map.entries().into_iter().enumerate() {
+		for (field, label, type_name) in [("key", "Key", std::any::type_name::<M::Key>()), ("value", "Value", std::any::type_name::<M::Value>())] {
+			fields.push(FieldInfo {
+				path: format!("{path}[{index}].{field}"),
+				label: label.to_owned(),
+				tooltip: String::new(),
+				type_name,
+				read_only: false,
+				attributes: &[],
+			});
+		}
+		key.collect_field_paths(&format!("{path}[{index}].key"), fields);
+		value.collect_field_paths(&format!("{path}[{index}].value"), fields);
+	}
+}
+
+/// [`EguiInspect::get_by_path`] of the maps.
+fn get_entry<'a, M: InspectMap>(map: &'a M, path: &str) -> Option<&'a dyn Any> {
+	let (index, rest) = split_index(path)?;
+	let (key, value) = *map.entries().get(index)?;
+	match split_entry_field(rest)? {
+		(true, rest) => key.get_by_path(rest),
+		(false, rest) => value.get_by_path(rest),
+	}
+}
+
+/// Changes the key of the entry `index` of `map` with `edit`, unless another entry has the
+/// resulting key.
+fn edit_key<M: InspectMap>(map: &mut M, index: usize, path: &str, edit: impl FnOnce(&mut M::Key) -> Result<(), String>) -> Result<(), String> {
+	let key = map.entries().get(index).map(|(key, _)| (*key).clone()).ok_or_else(|| no_field(path))?;
+	let mut new_key = key.clone();
+	edit(&mut new_key)?;
+	if new_key != key {
+		if map.value(&new_key).is_some() {
+			return Err(DUPLICATE_KEY.to_owned());
+		}
+		map.rename(&key, new_key);
+	}
+	Ok(())
+}
+
+/// [`EguiInspect::set_by_path_from_str`] of the maps.
+fn set_entry<M: InspectMap>(map: &mut M, path: &str, value: &str) -> Result<(), String> {
+	let (index, rest) = split_index(path).ok_or_else(|| no_field(path))?;
+	match split_entry_field(rest).ok_or_else(|| no_field(path))? {
+		(true, rest) => edit_key(map, index, path, |key| key.set_by_path_from_str(rest, value)),
+		(false, rest) => {
+			let (_, entry_value) = map.entries_mut().into_iter().nth(index).ok_or_else(|| no_field(path))?;
+			entry_value.set_by_path_from_str(rest, value)
+		}
+	}
+}
+
+/// [`EguiInspect::copy_field_from`] of the maps: `path` starts with the index of the entry, or
+/// is empty to copy every entry.
+fn copy_entries<M: InspectMap>(map: &mut M, other: &M, path: &str) -> Result<(), String> {
+	if path.is_empty() {
+		let mut copies = Vec::with_capacity(other.entry_count());
+		for (key, value) in other.entries() {
+			let mut copy = M::Value::default();
+			copy.copy_field_from(value, "")?;
+			copies.push((key.clone(), copy));
+		}
+		map.clear_entries();
+		for (key, value) in copies {
+			map.insert_entry(key, value);
+		}
+		return Ok(());
+	}
+	let (index, rest) = split_index(path).ok_or_else(|| no_field(path))?;
+	let (other_key, other_value) = *other.entries().get(index).ok_or_else(|| no_field(path))?;
+	match split_entry_field(rest).ok_or_else(|| no_field(path))? {
+		(true, rest) => edit_key(map, index, path, |key| key.copy_field_from(other_key, rest)),
+		(false, rest) => {
+			let (_, value) = map.entries_mut().into_iter().nth(index).ok_or_else(|| no_field(path))?;
+			value.copy_field_from(other_value, rest)
+		}
+	}
+}
+
+/// Implements the methods of [`EguiInspect`] of a map implementing [`InspectMap`].
+macro_rules! impl_map_inspect {
+	() => {
+		fn inspect_with_custom_id(&mut self, parent_id: egui::Id, label: &str, tooltip: &str, read_only: bool, ui: &mut egui::Ui) -> crate::InspectResponse {
+			crate::base_type_inspect::inspect_map(self, parent_id, label, tooltip, read_only, ui)
+		}
+		fn validate(&self, path: &str, errors: &mut Vec<crate::ValidationError>) {
+			crate::base_type_inspect::validate_map(self, path, errors)
+		}
+		fn diff(&self, other: &Self, path: &str, diffs: &mut Vec<String>) {
+			crate::base_type_inspect::diff_map(self, other, path, diffs)
+		}
+		fn collect_changes(&self, other: &Self, path: &str, changes: &mut Vec<crate::FieldChange>) {
+			crate::base_type_inspect::map_changes(self, other, path, changes)
+		}
+		fn collect_field_paths(&self, path: &str, fields: &mut Vec<crate::FieldInfo>) {
+			crate::base_type_inspect::collect_entries(self, path, fields)
+		}
+		fn get_by_path(&self, path: &str) -> Option<&dyn std::any::Any> {
+			crate::base_type_inspect::get_entry(self, path)
+		}
+		fn set_by_path_from_str(&mut self, path: &str, value: &str) -> Result<(), String> {
+			crate::base_type_inspect::set_entry(self, path, value)
+		}
+		fn copy_field_from(&mut self, other: &Self, path: &str) -> Result<(), String> {
+			crate::base_type_inspect::copy_entries(self, other, path)
+		}
+	}
+}
+
+impl<K, V, S> InspectMap for HashMap<K, V, S>
+where
+	K: EguiInspect + Clone + Ord + Hash + Default + Send + Sync + 'static,
+	V: EguiInspect + Default,
+	S: BuildHasher,
+{
+	type Key = K;
+	type Value = V;
+	fn entry_count(&self) -> usize {
+		self.len()
+	}
+	fn entries(&self) -> Vec<(&K, &V)> {
+		let mut entries: Vec<_> = self.iter().collect();
+		entries.sort_unstable_by_key(|(key, _)| *key);
+		entries
+	}
+	fn entries_mut(&mut self) -> Vec<(&K, &mut V)> {
+		let mut entries: Vec<_> = self.iter_mut().collect();
+		entries.sort_unstable_by_key(|(key, _)| *key);
+		entries
+	}
+	fn value(&self, key: &K) -> Option<&V> {
+		self.get(key)
+	}
+	fn insert_entry(&mut self, key: K, value: V) {
+		self.insert(key, value);
+	}
+	fn remove_entry(&mut self, key: &K) -> Option<V> {
+		self.remove(key)
+	}
+	fn clear_entries(&mut self) {
+		self.clear()
+	}
+}
+
+impl<K, V, S> crate::EguiInspect for HashMap<K, V, S>
+where
+	K: EguiInspect + Clone + Ord + Hash + Default + Send + Sync + 'static,
+	V: EguiInspect + Default,
+	S: BuildHasher + Default,
+{
+	impl_map_inspect!();
+	fn default_value() -> Option<Self> {
+		Some(Self::default())
+	}
+}
+
+impl<K: EguiDisplay + Ord, V: EguiDisplay, S> EguiDisplay for HashMap<K, V, S> {
+	fn display_with_custom_id(&self, parent_id: egui::Id, label: &str, tooltip: &str, ui: &mut Ui) {
+		let mut entries: Vec<_> = self.iter().collect();
+		entries.sort_unstable_by_key(|(key, _)| *key);
+		display_map(entries, parent_id, label, tooltip, ui)
+	}
+}
+
+impl<K, V> InspectMap for BTreeMap<K, V>
+where
+	K: EguiInspect + Clone + Ord + Default + Send + Sync + 'static,
+	V: EguiInspect + Default,
+{
+	type Key = K;
+	type Value = V;
+	fn entry_count(&self) -> usize {
+		self.len()
+	}
+	fn entries(&self) -> Vec<(&K, &V)> {
+		self.iter().collect()
+	}
+	fn entries_mut(&mut self) -> Vec<(&K, &mut V)> {
+		self.iter_mut().collect()
+	}
+	fn value(&self, key: &K) -> Option<&V> {
+		self.get(key)
+	}
+	fn insert_entry(&mut self, key: K, value: V) {
+		self.insert(key, value);
+	}
+	fn remove_entry(&mut self, key: &K) -> Option<V> {
+		self.remove(key)
+	}
+	fn clear_entries(&mut self) {
+		self.clear()
+	}
+}
+
+impl<K, V> crate::EguiInspect for BTreeMap<K, V>
+where
+	K: EguiInspect + Clone + Ord + Default + Send + Sync + 'static,
+	V: EguiInspect + Default,
+{
+	impl_map_inspect!();
+	fn default_value() -> Option<Self> {
+		Some(Self::default())
+	}
+}
+
+impl<K: EguiDisplay, V: EguiDisplay> EguiDisplay for BTreeMap<K, V> {
+	fn display_with_custom_id(&self, parent_id: egui::Id, label: &str, tooltip: &str, ui: &mut Ui) {
+		display_map(self.iter().collect(), parent_id, label, tooltip, ui)
+	}
+}
+
+/// Sets inspected by [`inspect_set`]: their items are shown sorted, and the paths of their
+/// fields are `set[index]`, the index being the position of the item in this order.
+trait InspectSet {
+	type Item: EguiInspect + Clone + PartialEq + Default + Send + Sync + 'static;
+	/// The items, in the order they are shown.
+	fn items(&self) -> Vec<&Self::Item>;
+	fn contains_item(&self, item: &Self::Item) -> bool;
+	fn insert_item(&mut self, item: Self::Item);
+	fn remove_item(&mut self, item: &Self::Item);
+	/// Replaces `item` by `new_item`, unless the set already contains `new_item`.
+	fn replace_item(&mut self, item: &Self::Item, new_item: Self::Item) -> Result<(), String> {
+		if new_item != *item {
+			if self.contains_item(&new_item) {
+				return Err(DUPLICATE_ITEM.to_owned());
+			}
+			self.remove_item(item);
+			self.insert_item(new_item);
+		}
+		Ok(())
+	}
+}
+
+/// Error of an edit giving the value of another item to an item of a set.
+const DUPLICATE_ITEM: &str = "The set already contains this value";
+
+/// [`EguiInspect::inspect_with_custom_id`] of the sets: a checkbox per value if the type of the
+/// items lists them (see [`EguiInspect::all_variants`]), else the items and a button adding the
+/// default value (if the set doesn't contain it).
+fn inspect_set<S: InspectSet>(set: &mut S, _parent_id: egui::Id, label: &str, tooltip: &str, read_only: bool, ui: &mut Ui) -> InspectResponse {
+	let variants = S::Item::all_variants();
+	if !variants.is_empty() {
+		return crate::add_custom_ui(label, tooltip, read_only, ui, |ui, _field_width| {
+			let mut response = InspectResponse::default();
+			ui.vertical(|ui| {
+				for (variant, variant_label) in variants {
+					let mut checked = set.contains_item(&variant);
+					if ui.checkbox(&mut checked, variant_label).changed() {
+						if checked {
+							set.insert_item(variant);
+						} else {
+							set.remove_item(&variant);
+						}
+						response.mark_changed();
+					}
+				}
+			});
+			response
+		});
+	}
+	let id = if _parent_id == egui::Id::NULL { ui.next_auto_id() } else { _parent_id.with(label) };
+	let items = set.items();
+	let collapsing = egui::CollapsingHeader::new(label_text(&label.to_string().add(format!("[{}]", items.len()).as_str()), ui)).id_salt(id.with("collapse")).open(header_open(ui)).show(ui, |ui| {
+		inspect_set_items(items, id, _parent_id != egui::Id::NULL, tooltip, read_only, ui)
+	});
+	add_field_menu(&collapsing.header_response, read_only, ui);
+	let mut inspect_response = InspectResponse::default();
+	let changed = match collapsing.body_returned.flatten() {
+		// the edit is dropped if another item has the edited value
+		Some((EntryAction::CommitKey, Some((item, edited)))) => set.replace_item(&item, edited).is_ok(),
+		Some((EntryAction::Remove(item), _)) => {
+			set.remove_item(&item);
+			true
+		}
+		_ => false,
+	};
+	if changed {
+		inspect_response.mark_changed();
+	}
+	let can_add = !set.contains_item(&S::Item::default());
+	if add_button(can_add, "The set contains the default value, change it to add another item", read_only, ui) {
+		set.insert_item(S::Item::default());
+		inspect_response.mark_changed();
+	}
+	inspect_response
+}
+
+/// Inspects the items of a set: only the items of the current page (see [`page_items`]) are
+/// rendered, but every item is searched.
+///
+/// Unless `read_only`, the items can be removed and edited (see [`inspect_key`]). Returns the
+/// selected action, with the edited item for [`EntryAction::CommitKey`].
+#[allow(clippy::type_complexity)]
+fn inspect_set_items<T: EguiInspect + Clone + PartialEq + Send + Sync + 'static>(items: Vec<&T>, id: egui::Id, has_parent_id: bool, tooltip: &str, read_only: bool, ui: &mut Ui) -> Option<(EntryAction<T>, KeyDraft<T>)> {
+	let draft_id = id.with("egui_field_editor::key_draft");
+	let mut draft = ui.data(|d| d.get_temp::<KeyDraft<T>>(draft_id)).flatten();
+	let duplicate = draft.as_ref().is_some_and(|(item, edited)| edited != item && items.contains(&edited));
+	let mut action = None;
+	for index in page_items(items.len(), id, ui) {
+		let item = items[index];
+		let _scope = FieldScope::index(ui, index);
+		if !is_field_visible(ui) {
+			continue;
+		}
+		let parent_id = if has_parent_id { id.with(index) } else { egui::Id::NULL };
+		ui.horizontal(|ui| {
+			if !read_only && ui.small_button("🗑").on_hover_text("Remove this item").clicked() {
+				action = Some(EntryAction::Remove(item.clone()));
+			}
+			// in a vertical layout, which items with a collapsing header need
+			ui.vertical(|ui| {
+				let error = duplicate.then_some(DUPLICATE_ITEM);
+				if inspect_key(item, &mut draft, error, parent_id, &item_label(None, index), tooltip, read_only, ui) {
+					action = Some(EntryAction::CommitKey);
+				}
+			});
+		});
+	}
+	let committed = if matches!(action, Some(EntryAction::CommitKey)) { draft.take() } else { None };
+	ui.data_mut(|d| d.insert_temp(draft_id, draft));
+	action.map(|action| (action, committed))
+}
+
+/// [`EguiDisplay::display_with_custom_id`] of the sets, `items` being in the order they are shown.
+fn display_set<T: EguiDisplay>(items: Vec<&T>, _parent_id: egui::Id, label: &str, tooltip: &str, ui: &mut Ui) {
+	let id = if _parent_id == egui::Id::NULL { ui.next_auto_id() } else { _parent_id.with(label) };
+	let parent_id = if _parent_id == egui::Id::NULL { egui::Id::NULL } else { id };
+	egui::CollapsingHeader::new(label_text(&label.to_string().add(format!("[{}]", items.len()).as_str()), ui)).id_salt(id.with("collapse")).open(header_open(ui)).show(ui, |ui| {
+		for index in page_items(items.len(), id, ui) {
+			let item = items[index];
+			let _scope = FieldScope::index(ui, index);
+			if is_field_visible(ui) {
+				item.display_with_custom_id(parent_id, &item_label(None, index), tooltip, ui);
+			}
+		}
+	});
+}
+
+/// [`EguiInspect::collect_changes`] of the sets: reports the removed items, then the inserted ones.
+fn set_changes<S: InspectSet>(old: &S, new: &S, path: &str, changes: &mut Vec<FieldChange>) {
+	// from the end, so that the indices stay valid when the changes are replayed
+	for (index, item) in old.items().into_iter().enumerate().rev() {
+		if !new.contains_item(item) {
+			changes.push(FieldChange::new(&format!("{path}[{index}]"), ChangeKind::Remove));
+		}
+	}
+	for (index, item) in new.items().into_iter().enumerate() {
+		if !old.contains_item(item) {
+			inserted(item, &format!("{path}[{index}]"), changes);
+		}
+	}
+}
+
+/// [`EguiInspect::set_by_path_from_str`] and [`EguiInspect::copy_field_from`] of the sets:
+/// replaces the item `index` of `set` by its copy changed with `edit`, unless the set already
+/// contains the result.
+fn edit_item<S: InspectSet>(set: &mut S, path: &str, edit: impl FnOnce(&mut S::Item, &str) -> Result<(), String>) -> Result<(), String> {
+	let (index, rest) = split_index(path).ok_or_else(|| no_field(path))?;
+	let item = set.items().get(index).map(|item| (*item).clone()).ok_or_else(|| no_field(path))?;
+	let mut new_item = item.clone();
+	edit(&mut new_item, rest)?;
+	set.replace_item(&item, new_item)
+}
+
+/// Implements the methods of [`EguiInspect`] of a set implementing [`InspectSet`].
+macro_rules! impl_set_inspect {
+	() => {
+		fn inspect_with_custom_id(&mut self, parent_id: egui::Id, label: &str, tooltip: &str, read_only: bool, ui: &mut egui::Ui) -> InspectResponse {
+			inspect_set(self, parent_id, label, tooltip, read_only, ui)
+		}
+		fn validate(&self, path: &str, errors: &mut Vec<ValidationError>) {
+			for (index, item) in self.items().into_iter().enumerate() {
+				item.validate(&format!("{path}[{index}]"), errors);
+			}
+		}
+		fn diff(&self, other: &Self, path: &str, diffs: &mut Vec<String>) {
+			diff_by_eq(self, other, path, diffs)
+		}
+		fn collect_changes(&self, other: &Self, path: &str, changes: &mut Vec<FieldChange>) {
+			set_changes(self, other, path, changes)
+		}
+		fn collect_field_paths(&self, path: &str, fields: &mut Vec<FieldInfo>) {
+			collect_items(self.items(), path, fields)
+		}
+		fn get_by_path(&self, path: &str) -> Option<&dyn Any> {
+			let (index, rest) = split_index(path)?;
+			self.items().get(index)?.get_by_path(rest)
+		}
+		fn set_by_path_from_str(&mut self, path: &str, value: &str) -> Result<(), String> {
+			edit_item(self, path, |item, rest| item.set_by_path_from_str(rest, value))
+		}
+		fn copy_field_from(&mut self, other: &Self, path: &str) -> Result<(), String> {
+			if path.is_empty() {
+				self.clone_from(other);
+				return Ok(());
+			}
+			let (index, _) = split_index(path).ok_or_else(|| no_field(path))?;
+			let other_item = *other.items().get(index).ok_or_else(|| no_field(path))?;
+			edit_item(self, path, |item, rest| item.copy_field_from(other_item, rest))
+		}
+	}
+}
+
+impl<T, S> InspectSet for HashSet<T, S>
+where
+	T: EguiInspect + Clone + Ord + Hash + Default + Send + Sync + 'static,
+	S: BuildHasher,
+{
+	type Item = T;
+	fn items(&self) -> Vec<&T> {
+		let mut items: Vec<_> = self.iter().collect();
+		items.sort_unstable();
+		items
+	}
+	fn contains_item(&self, item: &T) -> bool {
+		self.contains(item)
+	}
+	fn insert_item(&mut self, item: T) {
+		self.insert(item);
+	}
+	fn remove_item(&mut self, item: &T) {
+		self.remove(item);
+	}
+}
+
+impl<T, S> crate::EguiInspect for HashSet<T, S>
+where
+	T: EguiInspect + Clone + Ord + Hash + Default + Send + Sync + 'static,
+	S: BuildHasher + Clone + Default,
+{
+	impl_set_inspect!();
+	fn default_value() -> Option<Self> {
+		Some(Self::default())
 	}
 }
 
-impl<T: crate::EguiInspect, const N: usize> crate::EguiInspect for [T; N] {
-	fn inspect_with_custom_id(&mut self, _parent_id: egui::Id, label: &str, tooltip: &str, read_only: bool, ui: &mut Ui) {
-		let id = if _parent_id == egui::Id::NULL { ui.next_auto_id() } else { _parent_id.with(label) };
-		let parent_id = if _parent_id == egui::Id::NULL { egui::Id::NULL } else { id };
-		egui::CollapsingHeader::new(label.to_string().add(format!("[{N}]").as_str())).id_salt(id.with("collapse")).show(ui, |ui| {
-			let response = egui_dnd::dnd(ui, id.with("dnd"))
-				.with_animation_time(0.0)
-				.show(
-					self
-						.iter_mut()
-						.enumerate()
-						.map(|(i, item)| EnumeratedItem { item, index: i, salt_id:id }),
-					|ui, item, handle, state| {
-						ui.horizontal(|ui| {
-							handle.ui(ui, |ui| {
-								if state.dragged {
-									ui.label("≡");
-								} else {
-									ui.label("☰");
-								}
-							});
-							let index = item.index;
-							item.item.inspect_with_custom_id(parent_id, format!("Item {index}").as_str(), tooltip, read_only, ui);
-						});
-					},
-				);
-				if response.is_drag_finished() {
-					response.update_vec(self);
-				}
-		});
+impl<T: EguiDisplay + Ord, S> EguiDisplay for HashSet<T, S> {
+	fn display_with_custom_id(&self, parent_id: egui::Id, label: &str, tooltip: &str, ui: &mut Ui) {
+		let mut items: Vec<_> = self.iter().collect();
+		items.sort_unstable();
+		display_set(items, parent_id, label, tooltip, ui)
 	}
 }
 
-impl<T: crate::EguiInspect + Default> crate::EguiInspect for Vec<T> {
-	fn inspect_with_custom_id(
-		&mut self,
-		_parent_id: egui::Id,
-		label: &str,
-		tooltip: &str,
-		read_only: bool,
-		ui: &mut Ui,
-	) {
-		let id = if _parent_id == egui::Id::NULL { ui.next_auto_id() } else { _parent_id.with(label) };
-		let parent_id = if _parent_id == egui::Id::NULL { egui::Id::NULL } else { id };
-		egui::CollapsingHeader::new(label.to_string().add(format!("[{}]", self.len()).as_str())).id_salt(id.with("collapse")).show(ui, |ui| {
-			let response = egui_dnd::dnd(ui, id.with("dnd"))
-				.with_animation_time(0.0)
-				.show(
-					self
-						.iter_mut()
-						.enumerate()
-						.map(|(i, item)| EnumeratedItem { item, index: i, salt_id:id}),
-					|ui, item, handle, state| {
-						ui.horizontal(|ui| {
-							handle.ui(ui, |ui| {
-								if state.dragged {
-									ui.label("≡");
-								} else {
-									ui.label("☰");
-								}
-							});
-							let index = item.index;
-							item.item.inspect_with_custom_id(parent_id, format!("Item {index}").as_str(), tooltip, read_only, ui);
-						});
-					},
-				);
-				if response.is_drag_finished() {
-					response.update_vec(self);
-				}
-		});
+impl<T> InspectSet for BTreeSet<T>
+where
+	T: EguiInspect + Clone + Ord + Default + Send + Sync + 'static,
+{
+	type Item = T;
+	fn items(&self) -> Vec<&T> {
+		self.iter().collect()
+	}
+	fn contains_item(&self, item: &T) -> bool {
+		self.contains(item)
+	}
+	fn insert_item(&mut self, item: T) {
+		self.insert(item);
+	}
+	fn remove_item(&mut self, item: &T) {
+		self.remove(item);
+	}
+}
 
-		ui.add_enabled_ui(!read_only, |ui| {
-			ui.horizontal_top(|ui| {
-				ui.add_space(ui.available_width() - 50.);
-				if ui.add(egui::Button::new("+").min_size(egui::Vec2::new(20.,20.))).clicked() {
-					self.push(T::default());
-				}
-				if ui.add(egui::Button::new("-").min_size(egui::Vec2::new(20.,20.))).clicked() {
-					self.pop();
-				}
-			});
-		});
+impl<T> crate::EguiInspect for BTreeSet<T>
+where
+	T: EguiInspect + Clone + Ord + Default + Send + Sync + 'static,
+{
+	impl_set_inspect!();
+	fn default_value() -> Option<Self> {
+		Some(Self::default())
 	}
+}
 
+impl<T: EguiDisplay> EguiDisplay for BTreeSet<T> {
+	fn display_with_custom_id(&self, parent_id: egui::Id, label: &str, tooltip: &str, ui: &mut Ui) {
+		display_set(self.iter().collect(), parent_id, label, tooltip, ui)
+	}
 }
 
 impl crate::EguiInspect for Color32 {
-	fn inspect_with_custom_id(&mut self, _parent_id: egui::Id, label: &str, tooltip: &str, read_only: bool, ui: &mut egui::Ui) {
-		crate::add_color(self, label, tooltip, read_only, ui);
+	fn inspect_with_custom_id(&mut self, _parent_id: egui::Id, label: &str, tooltip: &str, read_only: bool, ui: &mut egui::Ui) -> InspectResponse {
+		crate::add_color(self, label, tooltip, read_only, ui)
+	}
+	fn diff(&self, other: &Self, path: &str, diffs: &mut Vec<String>) {
+		diff_by_eq(self, other, path, diffs)
+	}
+	fn collect_changes(&self, other: &Self, path: &str, changes: &mut Vec<FieldChange>) {
+		if self != other {
+			changes.push(FieldChange::set(path, self.to_hex(), other.to_hex()));
+		}
+	}
+	fn get_by_path(&self, path: &str) -> Option<&dyn Any> {
+		get_value(self, path)
+	}
+	fn set_by_path_from_str(&mut self, path: &str, value: &str) -> Result<(), String> {
+		if !path.is_empty() {
+			return Err(no_field(path));
+		}
+		*self = Color32::from_hex(value).map_err(|e| format!("{e:?}"))?;
+		Ok(())
+	}
+	fn copy_field_from(&mut self, other: &Self, path: &str) -> Result<(), String> {
+		copy_value(self, other, path)
+	}
+	fn default_value() -> Option<Self> {
+		Some(Self::default())
 	}
 }
 
 impl crate::EguiInspect for std::path::PathBuf {
-	fn inspect_with_custom_id(&mut self, _parent_id: egui::Id, label: &str, tooltip: &str, read_only: bool, ui: &mut egui::Ui) {
-		crate::add_path(self, label, tooltip, read_only, vec![], ui);
+	fn inspect_with_custom_id(&mut self, _parent_id: egui::Id, label: &str, tooltip: &str, read_only: bool, ui: &mut egui::Ui) -> InspectResponse {
+		crate::add_path(self, label, tooltip, read_only, vec![], ui)
+	}
+	fn diff(&self, other: &Self, path: &str, diffs: &mut Vec<String>) {
+		diff_by_eq(self, other, path, diffs)
+	}
+	fn collect_changes(&self, other: &Self, path: &str, changes: &mut Vec<FieldChange>) {
+		change_by_eq(&*self.to_string_lossy(), &*other.to_string_lossy(), path, changes)
+	}
+	fn get_by_path(&self, path: &str) -> Option<&dyn Any> {
+		get_value(self, path)
+	}
+	fn set_by_path_from_str(&mut self, path: &str, value: &str) -> Result<(), String> {
+		set_from_str(self, path, value)
+	}
+	fn copy_field_from(&mut self, other: &Self, path: &str) -> Result<(), String> {
+		copy_value(self, other, path)
+	}
+	fn default_value() -> Option<Self> {
+		Some(Self::default())
 	}
 }
 
-impl<T : EguiInspect> crate::EguiInspect for Option<T>
-	where T : Default+PartialEq {
-	fn inspect_with_custom_id(&mut self, _parent_id: egui::Id, label: &str, tooltip: &str, read_only: bool, ui: &mut egui::Ui) {
+impl crate::EguiDisplay for std::path::PathBuf {
+	fn display_with_custom_id(&self, _parent_id: egui::Id, label: &str, tooltip: &str, ui: &mut egui::Ui) {
+		crate::add_path(&mut self.clone(), label, tooltip, true, vec![], ui);
+	}
+}
+
+/// Error of an edit of `None` whose type has no default value, see [`EguiInspect::default_value`].
+const NO_DEFAULT: &str = "The value is `None` and its type has no default value";
+/// Error of a copy of the other variant of a [`Result`] whose type has no default value.
+const NO_VARIANT_DEFAULT: &str = "The type of the copied variant has no default value";
+
+/// `true` if `T` has a default value (see [`EguiInspect::default_value`]), kept in the egui
+/// memory under `id` so that no value is built each frame.
+fn has_default_value<T: EguiInspect>(id: egui::Id, ui: &Ui) -> bool {
+	let id = id.with("egui_field_editor::has_default");
+	ui.data_mut(|d| *d.get_temp_mut_or_insert_with(id, || T::default_value().is_some()))
+}
+
+impl<T : EguiInspect> crate::EguiInspect for Option<T> {
+	fn inspect_with_custom_id(&mut self, _parent_id: egui::Id, label: &str, tooltip: &str, read_only: bool, ui: &mut egui::Ui) -> InspectResponse {
+		if !is_field_visible(ui) {
+			return InspectResponse::default();
+		}
 		let id = if _parent_id == egui::Id::NULL {
 			ui.next_auto_id()
 		} else {
@@ -272,16 +1717,12 @@ impl<T : EguiInspect> crate::EguiInspect for Option<T>
 		let parent_id = if _parent_id == egui::Id::NULL { egui::Id::NULL } else { id };
 		let available_width = ui.available_width();
 		let label_width = available_width * 0.4;
-		let field_width = 100.0f32.max(available_width * 0.6 - 15.0);
+		let field_width = 100.0f32.max(available_width * 0.6 - 15.0 - reset_width(ui));
+		let mut response = InspectResponse::default();
 
 		ui.horizontal(|ui| {
-			let r = ui.add_sized(
-				[label_width, 0.0],
-				egui::Label::new(label)
-					.truncate()
-					.show_tooltip_when_elided(true)
-					.halign(egui::Align::LEFT),
-			);
+			let r = ui.add_sized([label_width, 0.0], field_label(label, ui));
+			add_field_menu(&r, read_only, ui);
 
 			if !tooltip.is_empty() {
 				r.on_hover_text(tooltip).on_disabled_hover_text(tooltip);
@@ -290,6 +1731,7 @@ impl<T : EguiInspect> crate::EguiInspect for Option<T>
 				egui::ComboBox::from_id_salt(id)
 					.selected_text(
 						match self {
+							_ if is_mixed(ui) => MIXED,
 							None => "None",
 							Some(_) => "Some"
 						},
@@ -298,63 +1740,537 @@ impl<T : EguiInspect> crate::EguiInspect for Option<T>
 					.show_ui(
 						ui,
 						|ui| {
-							if ui.selectable_value(self, None, "None").changed() {
+							// the default value is only built when `Some` is picked
+							if ui.selectable_label(self.is_none(), "None").clicked() && self.is_some() {
 								*self = None;
+								response.mark_changed();
 							}
-							if ui
-								.selectable_value(
-									self,
-									Some(Default::default()),
-									"Some",
-								)
-								.changed()
-							{
-								*self = Some(Default::default());
+							let some = ui.add_enabled_ui(self.is_some() || has_default_value::<T>(id, ui), |ui| {
+								ui.selectable_label(self.is_some(), "Some")
+									.on_disabled_hover_text("This type has no default value")
+							}).inner;
+							if some.clicked() && self.is_none() {
+								*self = T::default_value();
+								response.mark_changed();
 							}
 						},
 					);
 			});
+			reset_button(read_only, ui);
 		});
 		match self {
 			None => {}
 			Some(field0) => {
-				ui.indent(id, |ui| {
+				response |= ui.indent(id, |ui| {
 					field0.inspect_with_custom_id(
 						parent_id,
 						"",
 						"",
 						read_only,
 						ui,
-					);
+					)
+				}).inner;
+			}
+		}
+		response
+	}
+	fn validate(&self, path: &str, errors: &mut Vec<ValidationError>) {
+		if let Some(inner) = self {
+			inner.validate(path, errors)
+		}
+	}
+	fn diff(&self, other: &Self, path: &str, diffs: &mut Vec<String>) {
+		match (self, other) {
+			(Some(inner), Some(other)) => inner.diff(other, path, diffs),
+			(None, None) => {}
+			_ => diffs.push(path.to_owned()),
+		}
+	}
+	fn collect_changes(&self, other: &Self, path: &str, changes: &mut Vec<FieldChange>) {
+		match (self, other) {
+			(Some(inner), Some(other)) => inner.collect_changes(other, path, changes),
+			(None, Some(other)) => inserted(other, path, changes),
+			(Some(_), None) => changes.push(FieldChange::new(path, ChangeKind::Remove)),
+			(None, None) => {}
+		}
+	}
+	fn collect_field_paths(&self, path: &str, fields: &mut Vec<FieldInfo>) {
+		if let Some(inner) = self {
+			inner.collect_field_paths(path, fields)
+		}
+	}
+	fn get_by_path(&self, path: &str) -> Option<&dyn Any> {
+		self.as_ref()?.get_by_path(path)
+	}
+	/// Setting a field of `None` sets it in a default value, which becomes `Some` on success.
+	fn set_by_path_from_str(&mut self, path: &str, value: &str) -> Result<(), String> {
+		match self {
+			Some(inner) => inner.set_by_path_from_str(path, value),
+			None => {
+				let mut inner = T::default_value().ok_or_else(|| NO_DEFAULT.to_owned())?;
+				inner.set_by_path_from_str(path, value)?;
+				*self = Some(inner);
+				Ok(())
+			}
+		}
+	}
+	fn copy_field_from(&mut self, other: &Self, path: &str) -> Result<(), String> {
+		match (self.as_mut(), other) {
+			(Some(inner), Some(other)) => inner.copy_field_from(other, path),
+			(_, None) if path.is_empty() => {
+				*self = None;
+				Ok(())
+			}
+			(None, Some(other)) if path.is_empty() => {
+				let mut inner = T::default_value().ok_or_else(|| NO_DEFAULT.to_owned())?;
+				inner.copy_field_from(other, path)?;
+				*self = Some(inner);
+				Ok(())
+			}
+			_ => Err(no_field(path)),
+		}
+	}
+	fn default_value() -> Option<Self> {
+		Some(Self::default())
+	}
+}
+
+impl<T : EguiDisplay> crate::EguiDisplay for Option<T> {
+	fn display_with_custom_id(&self, _parent_id: egui::Id, label: &str, tooltip: &str, ui: &mut egui::Ui) {
+		let id = if _parent_id == egui::Id::NULL {
+			ui.next_auto_id()
+		} else {
+			_parent_id.with(label)
+		};
+		let parent_id = if _parent_id == egui::Id::NULL { egui::Id::NULL } else { id };
+		let variant = match self {
+			None => "None",
+			Some(_) => "Some"
+		};
+		crate::add_custom_ui(label, tooltip, true, ui, |ui, field_width| {
+			egui::ComboBox::from_id_salt(id)
+				.selected_text(variant)
+				.width(field_width)
+				.show_ui(ui, |_ui| {});
+		});
+		if let Some(field0) = self {
+			ui.indent(id, |ui| {
+				field0.display_with_custom_id(parent_id, "", "", ui)
+			});
+		}
+	}
+}
+
+impl<T: EguiInspect, E: EguiInspect> crate::EguiInspect for Result<T, E> {
+	fn inspect_with_custom_id(&mut self, _parent_id: egui::Id, label: &str, tooltip: &str, read_only: bool, ui: &mut egui::Ui) -> InspectResponse {
+		if !is_field_visible(ui) {
+			return InspectResponse::default();
+		}
+		let id = if _parent_id == egui::Id::NULL {
+			ui.next_auto_id()
+		} else {
+			_parent_id.with(label)
+		};
+		let parent_id = if _parent_id == egui::Id::NULL { egui::Id::NULL } else { id };
+		let mut response = crate::add_custom_ui(label, tooltip, read_only, ui, |ui, field_width| {
+			let mut response = InspectResponse::default();
+			egui::ComboBox::from_id_salt(id)
+				.selected_text(
+					match self {
+						_ if is_mixed(ui) => MIXED,
+						Ok(_) => "Ok",
+						Err(_) => "Err"
+					},
+				)
+				.width(field_width)
+				.show_ui(ui, |ui| {
+					// the default values are only built when the other variant is picked
+					let ok = ui.add_enabled_ui(self.is_ok() || has_default_value::<T>(id.with("ok"), ui), |ui| {
+						ui.selectable_label(self.is_ok(), "Ok")
+							.on_disabled_hover_text("This type has no default value")
+					}).inner;
+					if ok.clicked() && self.is_err() && let Some(value) = T::default_value() {
+						*self = Ok(value);
+						response.mark_changed();
+					}
+					let err = ui.add_enabled_ui(self.is_err() || has_default_value::<E>(id.with("err"), ui), |ui| {
+						ui.selectable_label(self.is_err(), "Err")
+							.on_disabled_hover_text("This type has no default value")
+					}).inner;
+					if err.clicked() && self.is_ok() && let Some(error) = E::default_value() {
+						*self = Err(error);
+						response.mark_changed();
+					}
+				});
+			response
+		});
+		response |= ui.indent(id, |ui| {
+			match self {
+				Ok(value) => value.inspect_with_custom_id(parent_id, "", "", read_only, ui),
+				Err(error) => error.inspect_with_custom_id(parent_id, "", "", read_only, ui),
+			}
+		}).inner;
+		response
+	}
+	fn validate(&self, path: &str, errors: &mut Vec<ValidationError>) {
+		match self {
+			Ok(value) => value.validate(path, errors),
+			Err(error) => error.validate(path, errors),
+		}
+	}
+	fn diff(&self, other: &Self, path: &str, diffs: &mut Vec<String>) {
+		match (self, other) {
+			(Ok(value), Ok(other)) => value.diff(other, path, diffs),
+			(Err(error), Err(other)) => error.diff(other, path, diffs),
+			_ => diffs.push(path.to_owned()),
+		}
+	}
+	/// A change of variant is reported as a [`ChangeKind::Set`] of the variant names, followed by
+	/// the differences of the new value with the default one.
+	fn collect_changes(&self, other: &Self, path: &str, changes: &mut Vec<FieldChange>) {
+		match (self, other) {
+			(Ok(value), Ok(other)) => value.collect_changes(other, path, changes),
+			(Err(error), Err(other)) => error.collect_changes(other, path, changes),
+			(Err(_), Ok(other)) => {
+				changes.push(FieldChange::set(path, "Err", "Ok"));
+				if let Some(default) = T::default_value() {
+					default.collect_changes(other, path, changes);
+				}
+			}
+			(Ok(_), Err(other)) => {
+				changes.push(FieldChange::set(path, "Ok", "Err"));
+				if let Some(default) = E::default_value() {
+					default.collect_changes(other, path, changes);
+				}
+			}
+		}
+	}
+	fn collect_field_paths(&self, path: &str, fields: &mut Vec<FieldInfo>) {
+		match self {
+			Ok(value) => value.collect_field_paths(path, fields),
+			Err(error) => error.collect_field_paths(path, fields),
+		}
+	}
+	fn get_by_path(&self, path: &str) -> Option<&dyn Any> {
+		match self {
+			Ok(value) => value.get_by_path(path),
+			Err(error) => error.get_by_path(path),
+		}
+	}
+	fn set_by_path_from_str(&mut self, path: &str, value: &str) -> Result<(), String> {
+		match self {
+			Ok(inner) => inner.set_by_path_from_str(path, value),
+			Err(error) => error.set_by_path_from_str(path, value),
+		}
+	}
+	fn copy_field_from(&mut self, other: &Self, path: &str) -> Result<(), String> {
+		match (self.as_mut(), other) {
+			(Ok(value), Ok(other)) => value.copy_field_from(other, path),
+			(Err(error), Err(other)) => error.copy_field_from(other, path),
+			(Err(_), Ok(other)) if path.is_empty() => {
+				let mut value = T::default_value().ok_or_else(|| NO_VARIANT_DEFAULT.to_owned())?;
+				value.copy_field_from(other, path)?;
+				*self = Ok(value);
+				Ok(())
+			}
+			(Ok(_), Err(other)) if path.is_empty() => {
+				let mut error = E::default_value().ok_or_else(|| NO_VARIANT_DEFAULT.to_owned())?;
+				error.copy_field_from(other, path)?;
+				*self = Err(error);
+				Ok(())
+			}
+			_ => Err(no_field(path)),
+		}
+	}
+	fn default_value() -> Option<Self> {
+		T::default_value().map(Ok)
+	}
+}
+
+impl<T: EguiDisplay, E: EguiDisplay> crate::EguiDisplay for Result<T, E> {
+	fn display_with_custom_id(&self, _parent_id: egui::Id, label: &str, tooltip: &str, ui: &mut egui::Ui) {
+		let id = if _parent_id == egui::Id::NULL {
+			ui.next_auto_id()
+		} else {
+			_parent_id.with(label)
+		};
+		let parent_id = if _parent_id == egui::Id::NULL { egui::Id::NULL } else { id };
+		let variant = match self {
+			Ok(_) => "Ok",
+			Err(_) => "Err"
+		};
+		crate::add_custom_ui(label, tooltip, true, ui, |ui, field_width| {
+			egui::ComboBox::from_id_salt(id)
+				.selected_text(variant)
+				.width(field_width)
+				.show_ui(ui, |_ui| {});
+		});
+		ui.indent(id, |ui| {
+			match self {
+				Ok(value) => value.display_with_custom_id(parent_id, "", "", ui),
+				Err(error) => error.display_with_custom_id(parent_id, "", "", ui),
+			}
+		});
+	}
+}
+
+/// Shows the elements of a tuple under a collapsing header labelled `label` (or directly if
+/// `label` is empty), like the fields of a derived tuple struct.
+///
+/// `add_contents` is given the parent id of the elements.
+fn tuple_header<R: Into<InspectResponse>>(_parent_id: egui::Id, label: &str, read_only: bool, ui: &mut Ui, add_contents: impl FnOnce(egui::Id, &mut Ui) -> R) -> InspectResponse {
+	if !is_field_visible(ui) {
+		return InspectResponse::default();
+	}
+	let id = if _parent_id == egui::Id::NULL {
+		ui.next_auto_id()
+	} else {
+		_parent_id.with(label)
+	};
+	let parent_id = if _parent_id == egui::Id::NULL { egui::Id::NULL } else { id };
+	if label.is_empty() {
+		return add_contents(parent_id, ui).into();
+	}
+	let collapsing = egui::CollapsingHeader::new(label_text(label, ui))
+		.id_salt(id)
+		.open(header_open(ui))
+		.show(ui, |ui| add_contents(parent_id, ui).into());
+	add_field_menu(&collapsing.header_response, read_only, ui);
+	collapsing.body_returned.unwrap_or_default()
+}
+
+/// Describes the element `index` of a tuple in [`EguiInspect::collect_field_paths`].
+fn collect_element<T: EguiInspect>(element: &T, index: &str, path: &str, fields: &mut Vec<FieldInfo>) {
+	let element_path = crate::derive_support::join_path(path, index);
+	fields.push(FieldInfo {
+		path: element_path.clone(),
+		label: format!("Field {index}"),
+		tooltip: String::new(),
+		type_name: std::any::type_name::<T>(),
+		read_only: false,
+		attributes: &[],
+	});
+	element.collect_field_paths(&element_path, fields);
+}
+
+/// Implements [`EguiInspect`] and [`EguiDisplay`] for the tuples of the given element types,
+/// each type being preceded by the index of its element.
+///
+/// The elements are shown like the fields of a derived tuple struct: labelled `Field 0`,
+/// `Field 1`… and named `0`, `1`… in the paths.
+macro_rules! impl_tuple_inspect {
+	($($index:tt $T:ident),+) => {
+		impl<$($T: EguiInspect),+> crate::EguiInspect for ($($T,)+) {
+			fn inspect_with_custom_id(&mut self, _parent_id: egui::Id, label: &str, tooltip: &str, read_only: bool, ui: &mut egui::Ui) -> InspectResponse {
+				tuple_header(_parent_id, label, read_only, ui, |parent_id, ui| {
+					let mut response = InspectResponse::default();
+					$({
+						let _scope = FieldScope::new(ui, stringify!($index));
+						if is_field_visible(ui) {
+							response |= self.$index.inspect_with_custom_id(parent_id, concat!("Field ", stringify!($index)), tooltip, read_only, ui);
+						}
+					})+
+					response
+				})
+			}
+			fn validate(&self, path: &str, errors: &mut Vec<ValidationError>) {
+				$( self.$index.validate(&crate::derive_support::join_path(path, stringify!($index)), errors); )+
+			}
+			fn diff(&self, other: &Self, path: &str, diffs: &mut Vec<String>) {
+				$( self.$index.diff(&other.$index, &crate::derive_support::join_path(path, stringify!($index)), diffs); )+
+			}
+			fn collect_changes(&self, other: &Self, path: &str, changes: &mut Vec<FieldChange>) {
+				$( self.$index.collect_changes(&other.$index, &crate::derive_support::join_path(path, stringify!($index)), changes); )+
+			}
+			fn collect_field_paths(&self, path: &str, fields: &mut Vec<FieldInfo>) {
+				$( collect_element(&self.$index, stringify!($index), path, fields); )+
+			}
+			fn get_by_path(&self, path: &str) -> Option<&dyn Any> {
+				let (index, rest) = split_field(path)?;
+				match index {
+					$( stringify!($index) => self.$index.get_by_path(rest), )+
+					_ => None,
+				}
+			}
+			fn set_by_path_from_str(&mut self, path: &str, value: &str) -> Result<(), String> {
+				let (index, rest) = split_field(path).ok_or_else(|| no_field(path))?;
+				match index {
+					$( stringify!($index) => self.$index.set_by_path_from_str(rest, value), )+
+					_ => Err(no_field(path)),
+				}
+			}
+			fn copy_field_from(&mut self, other: &Self, path: &str) -> Result<(), String> {
+				if path.is_empty() {
+					$( self.$index.copy_field_from(&other.$index, "")?; )+
+					return Ok(());
+				}
+				let (index, rest) = split_field(path).ok_or_else(|| no_field(path))?;
+				match index {
+					$( stringify!($index) => self.$index.copy_field_from(&other.$index, rest), )+
+					_ => Err(no_field(path)),
+				}
+			}
+			fn default_value() -> Option<Self> {
+				Some(($($T::default_value()?,)+))
+			}
+		}
+
+		impl<$($T: EguiDisplay),+> crate::EguiDisplay for ($($T,)+) {
+			fn display_with_custom_id(&self, _parent_id: egui::Id, label: &str, tooltip: &str, ui: &mut egui::Ui) {
+				tuple_header(_parent_id, label, true, ui, |parent_id, ui| {
+					$({
+						let _scope = FieldScope::new(ui, stringify!($index));
+						if is_field_visible(ui) {
+							self.$index.display_with_custom_id(parent_id, concat!("Field ", stringify!($index)), tooltip, ui);
+						}
+					})+
 				});
 			}
 		}
 	}
 }
 
+impl_tuple_inspect!(0 A);
+impl_tuple_inspect!(0 A, 1 B);
+impl_tuple_inspect!(0 A, 1 B, 2 C);
+impl_tuple_inspect!(0 A, 1 B, 2 C, 3 D);
+impl_tuple_inspect!(0 A, 1 B, 2 C, 3 D, 4 E);
+impl_tuple_inspect!(0 A, 1 B, 2 C, 3 D, 4 E, 5 F);
+impl_tuple_inspect!(0 A, 1 B, 2 C, 3 D, 4 E, 5 F, 6 G);
+impl_tuple_inspect!(0 A, 1 B, 2 C, 3 D, 4 E, 5 F, 6 G, 7 H);
+impl_tuple_inspect!(0 A, 1 B, 2 C, 3 D, 4 E, 5 F, 6 G, 7 H, 8 I);
+impl_tuple_inspect!(0 A, 1 B, 2 C, 3 D, 4 E, 5 F, 6 G, 7 H, 8 I, 9 J);
+impl_tuple_inspect!(0 A, 1 B, 2 C, 3 D, 4 E, 5 F, 6 G, 7 H, 8 I, 9 J, 10 K);
+impl_tuple_inspect!(0 A, 1 B, 2 C, 3 D, 4 E, 5 F, 6 G, 7 H, 8 I, 9 J, 10 K, 11 L);
+
+/// Implements [`EguiInspect`] and [`EguiDisplay`] for a range of numbers, shown by
+/// [`crate::add_range`]. The bounds are named `start` and `end` in the paths.
+macro_rules! impl_range_inspect {
+	($Range:ident, $op:tt) => {
+		impl<T: Numeric + EguiInspect> crate::EguiInspect for std::ops::$Range<T> {
+			fn inspect_with_custom_id(&mut self, _parent_id: egui::Id, label: &str, tooltip: &str, read_only: bool, ui: &mut egui::Ui) -> InspectResponse {
+				crate::add_range(self, label, tooltip, read_only, None, ui)
+			}
+			fn diff(&self, other: &Self, path: &str, diffs: &mut Vec<String>) {
+				diff_by_eq(self, other, path, diffs)
+			}
+			fn collect_changes(&self, other: &Self, path: &str, changes: &mut Vec<FieldChange>) {
+				let ((start, end), (other_start, other_end)) = (self.bounds(), other.bounds());
+				start.collect_changes(&other_start, &crate::derive_support::join_path(path, "start"), changes);
+				end.collect_changes(&other_end, &crate::derive_support::join_path(path, "end"), changes);
+			}
+			fn get_by_path(&self, path: &str) -> Option<&dyn Any> {
+				get_value(self, path)
+			}
+			fn set_by_path_from_str(&mut self, path: &str, value: &str) -> Result<(), String> {
+				let (mut start, mut end) = self.bounds();
+				match path {
+					"start" => start.set_by_path_from_str("", value)?,
+					"end" => end.set_by_path_from_str("", value)?,
+					_ => return Err(no_field(path)),
+				}
+				self.set_bounds(start, end);
+				Ok(())
+			}
+			fn copy_field_from(&mut self, other: &Self, path: &str) -> Result<(), String> {
+				copy_value(self, other, path)
+			}
+			fn default_value() -> Option<Self> {
+				let bound = T::from_f64(0.);
+				Some(bound $op bound)
+			}
+		}
+
+		impl<T: Numeric + EguiInspect> crate::EguiDisplay for std::ops::$Range<T> {
+			fn display_with_custom_id(&self, parent_id: egui::Id, label: &str, tooltip: &str, ui: &mut egui::Ui) {
+				let mut value = self.clone();
+				crate::EguiInspect::inspect_with_custom_id(&mut value, parent_id, label, tooltip, true, ui);
+			}
+		}
+	}
+}
+
+impl_range_inspect!(Range, ..);
+impl_range_inspect!(RangeInclusive, ..=);
+
+/// Nothing to show.
+impl<T: ?Sized> crate::EguiInspect for PhantomData<T> {
+	fn inspect_with_custom_id(&mut self, _parent_id: egui::Id, _label: &str, _tooltip: &str, _read_only: bool, _ui: &mut egui::Ui) -> InspectResponse {
+		InspectResponse::default()
+	}
+	fn copy_field_from(&mut self, other: &Self, path: &str) -> Result<(), String> {
+		copy_value(self, other, path)
+	}
+	fn default_value() -> Option<Self> {
+		Some(PhantomData)
+	}
+}
+
+impl<T: ?Sized> crate::EguiDisplay for PhantomData<T> {
+	fn display_with_custom_id(&self, _parent_id: egui::Id, _label: &str, _tooltip: &str, _ui: &mut egui::Ui) { }
+}
+
+/// Nothing to show.
+impl crate::EguiInspect for () {
+	fn inspect_with_custom_id(&mut self, _parent_id: egui::Id, _label: &str, _tooltip: &str, _read_only: bool, _ui: &mut egui::Ui) -> InspectResponse {
+		InspectResponse::default()
+	}
+	fn get_by_path(&self, path: &str) -> Option<&dyn Any> {
+		get_value(self, path)
+	}
+	fn copy_field_from(&mut self, other: &Self, path: &str) -> Result<(), String> {
+		copy_value(self, other, path)
+	}
+	fn default_value() -> Option<Self> {
+		Some(())
+	}
+}
+
+impl crate::EguiDisplay for () {
+	fn display_with_custom_id(&self, _parent_id: egui::Id, _label: &str, _tooltip: &str, _ui: &mut egui::Ui) { }
+}
 
 
 #[cfg(feature = "nalgebra_glm")]
 mod nalgebra_ui {
 	use egui::Color32;
 	use nalgebra_glm::*;
-	use crate::EguiInspect;
+	use crate::{EguiInspect, InspectResponse};
 	use crate::Color32Wrapper;
 
 	macro_rules! impl_only_numbers_struct_inspect {
 		($Type:ident, [$($field:ident),+]) => {
 			impl EguiInspect for $Type {
-				fn inspect_with_custom_id(&mut self, _parent_id: egui::Id, label: &str, tooltip: &str, read_only: bool, ui: &mut egui::Ui) {
+				fn inspect_with_custom_id(&mut self, _parent_id: egui::Id, label: &str, tooltip: &str, read_only: bool, ui: &mut egui::Ui) -> InspectResponse {
 					crate::add_custom_ui(label, tooltip, read_only, ui, |ui, _field_size| {
 						ui.group(|ui| {
 							ui.horizontal(|ui| {
+							let mut response = InspectResponse::default();
 							$(
 								ui.label(stringify!($field));
-								ui.add(egui::DragValue::new(&mut self.$field).speed(0.1));
+								response |= ui.add(egui::DragValue::new(&mut self.$field).speed(0.1)).into();
 							)+
-							});
-						});
-					});
+							response
+							}).inner
+						}).inner
+					})
+				}
+				fn diff(&self, other: &Self, path: &str, diffs: &mut Vec<String>) {
+					super::diff_by_eq(self, other, path, diffs)
+				}
+				fn collect_changes(&self, other: &Self, path: &str, changes: &mut Vec<crate::FieldChange>) {
+					if self != other {
+						changes.push(crate::FieldChange::set(path, format!("{self:?}"), format!("{other:?}")));
+					}
+				}
+				fn get_by_path(&self, path: &str) -> Option<&dyn std::any::Any> {
+					super::get_value(self, path)
+				}
+				fn copy_field_from(&mut self, other: &Self, path: &str) -> Result<(), String> {
+					super::copy_value(self, other, path)
+				}
+				fn default_value() -> Option<Self> {
+					Some(Self::default())
 				}
 			}
 		};
@@ -369,21 +2285,40 @@ mod nalgebra_ui {
 					tooltip: &str,
 					read_only: bool,
 					ui: &mut egui::Ui,
-				) {
+				) -> InspectResponse {
 					crate::add_custom_ui(label, tooltip, read_only, ui, |ui, _field_size| {
 						ui.vertical(|ui| {
 							ui.group(|ui| {
+								let mut response = InspectResponse::default();
 								$(
 									ui.horizontal(|ui| {
 										$(
 											ui.label(stringify!($field));
-											ui.add(egui::DragValue::new(&mut self.$field).speed(0.1));
+											response |= ui.add(egui::DragValue::new(&mut self.$field).speed(0.1)).into();
 										)+
 									});
 								)+
-							});
-						});
-					});
+								response
+							}).inner
+						}).inner
+					})
+				}
+				fn diff(&self, other: &Self, path: &str, diffs: &mut Vec<String>) {
+					super::diff_by_eq(self, other, path, diffs)
+				}
+				fn collect_changes(&self, other: &Self, path: &str, changes: &mut Vec<crate::FieldChange>) {
+					if self != other {
+						changes.push(crate::FieldChange::set(path, format!("{self:?}"), format!("{other:?}")));
+					}
+				}
+				fn get_by_path(&self, path: &str) -> Option<&dyn std::any::Any> {
+					super::get_value(self, path)
+				}
+				fn copy_field_from(&mut self, other: &Self, path: &str) -> Result<(), String> {
+					super::copy_value(self, other, path)
+				}
+				fn default_value() -> Option<Self> {
+					Some(Self::default())
 				}
 			}
 		};
@@ -431,6 +2366,13 @@ mod nalgebra_ui {
 	impl_mat_inspect!(Mat4x2, [[m11, m12], [m21, m22], [m31, m32], [m41, m42]]);
 	impl_mat_inspect!(Mat4x3, [[m11, m12, m13], [m21, m22, m23], [m31, m32, m33], [m41, m42, m43]]);
 	impl_mat_inspect!(Mat4x4, [[m11, m12, m13, m14], [m21, m22, m23, m24], [m31, m32, m33, m34], [m41, m42, m43, m44]]);
+	impl_display_by_copy!(Vec2, Vec3, Vec4, DVec2, DVec3, DVec4);
+	impl_display_by_copy!(U8Vec2, U8Vec3, U8Vec4, I8Vec2, I8Vec3, I8Vec4);
+	impl_display_by_copy!(U16Vec2, U16Vec3, U16Vec4, I16Vec2, I16Vec3, I16Vec4);
+	impl_display_by_copy!(U32Vec2, U32Vec3, U32Vec4, I32Vec2, I32Vec3, I32Vec4);
+	impl_display_by_copy!(U64Vec2, U64Vec3, U64Vec4, I64Vec2, I64Vec3, I64Vec4);
+	impl_display_by_copy!(Quat, DQuat);
+	impl_display_by_copy!(Mat2x2, Mat2x3, Mat2x4, Mat3x2, Mat3x3, Mat3x4, Mat4x2, Mat4x3, Mat4x4);
 	
 	impl From<Color32Wrapper> for Vec3 {
 		fn from(value: Color32Wrapper) -> Self {
@@ -481,21 +2423,108 @@ mod nalgebra_ui {
 mod datepicker {
 	use std::hash::{Hash, Hasher};
 
-	use crate::EguiInspect;
+	use crate::{EguiInspect, InspectResponse};
 	use chrono::prelude::*;
 	use egui_extras::DatePickerButton;
 	impl EguiInspect for NaiveDate {
-		fn inspect_with_custom_id(&mut self, parent_id: egui::Id, label: &str, tooltip: &str, read_only: bool, ui: &mut egui::Ui) {
+		fn inspect_with_custom_id(&mut self, parent_id: egui::Id, label: &str, tooltip: &str, read_only: bool, ui: &mut egui::Ui) -> InspectResponse {
 			let id = if parent_id == egui::Id::NULL { egui::Id::NULL } else { parent_id.with(label) };
 			let widget = DatePickerButton::new(self);
 			if id != egui::Id::NULL {
 				// Ugly hack because DatePickerButton::id_salt() needs a &str
 				let mut hasher = std::hash::DefaultHasher::new();
 				id.hash(&mut hasher);
-				crate::add_widget(label, widget.id_salt(format!("{}", hasher.finish()).as_str()), tooltip, read_only, ui);
+				crate::add_widget(label, widget.id_salt(format!("{}", hasher.finish()).as_str()), tooltip, read_only, ui)
 			} else {
-				crate::add_widget(label, widget, tooltip, read_only, ui);
+				crate::add_widget(label, widget, tooltip, read_only, ui)
+			}
+		}
+		fn diff(&self, other: &Self, path: &str, diffs: &mut Vec<String>) {
+			super::diff_by_eq(self, other, path, diffs)
+		}
+		fn collect_changes(&self, other: &Self, path: &str, changes: &mut Vec<crate::FieldChange>) {
+			super::change_by_eq(self, other, path, changes)
+		}
+		fn get_by_path(&self, path: &str) -> Option<&dyn std::any::Any> {
+			super::get_value(self, path)
+		}
+		fn set_by_path_from_str(&mut self, path: &str, value: &str) -> Result<(), String> {
+			crate::derive_support::set_from_str(self, path, value)
+		}
+		fn copy_field_from(&mut self, other: &Self, path: &str) -> Result<(), String> {
+			super::copy_value(self, other, path)
+		}
+		fn default_value() -> Option<Self> {
+			Some(Self::default())
+		}
+	}
+	impl_display_by_copy!(NaiveDate);
+}
+#[cfg(feature = "indexmap")]
+mod indexmap_ui {
+	use std::hash::{BuildHasher, Hash};
+
+	use indexmap::IndexMap;
+	use super::{display_map, InspectMap};
+	use crate::{EguiDisplay, EguiInspect};
+
+	impl<K, V, S> InspectMap for IndexMap<K, V, S>
+	where
+		K: EguiInspect + Clone + Hash + Eq + Default + Send + Sync + 'static,
+		V: EguiInspect + Default,
+		S: BuildHasher,
+	{
+		type Key = K;
+		type Value = V;
+		const REORDERABLE: bool = true;
+		fn entry_count(&self) -> usize {
+			self.len()
+		}
+		fn entries(&self) -> Vec<(&K, &V)> {
+			self.iter().collect()
+		}
+		fn entries_mut(&mut self) -> Vec<(&K, &mut V)> {
+			self.iter_mut().collect()
+		}
+		fn value(&self, key: &K) -> Option<&V> {
+			self.get(key)
+		}
+		fn insert_entry(&mut self, key: K, value: V) {
+			self.insert(key, value);
+		}
+		fn remove_entry(&mut self, key: &K) -> Option<V> {
+			self.shift_remove(key)
+		}
+		fn clear_entries(&mut self) {
+			self.clear()
+		}
+		fn rename(&mut self, key: &K, new_key: K) {
+			// keeps the position of the entry
+			if let Some((index, _, value)) = self.shift_remove_full(key) {
+				self.shift_insert(index, new_key, value);
 			}
 		}
+		fn move_entry(&mut self, from: usize, to: usize) {
+			// `to` is the index of the entry the moved one is inserted before
+			self.move_index(from, if from < to { to - 1 } else { to });
+		}
+	}
+
+	impl<K, V, S> EguiInspect for IndexMap<K, V, S>
+	where
+		K: EguiInspect + Clone + Hash + Eq + Default + Send + Sync + 'static,
+		V: EguiInspect + Default,
+		S: BuildHasher + Default,
+	{
+		impl_map_inspect!();
+		fn default_value() -> Option<Self> {
+			Some(Self::default())
+		}
 	}
-}
\ No newline at end of file
+
+	impl<K: EguiDisplay, V: EguiDisplay, S> EguiDisplay for IndexMap<K, V, S> {
+		fn display_with_custom_id(&self, parent_id: egui::Id, label: &str, tooltip: &str, ui: &mut egui::Ui) {
+			display_map(self.iter().collect(), parent_id, label, tooltip, ui)
+		}
+	}
+}
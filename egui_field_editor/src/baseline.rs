@@ -0,0 +1,101 @@
+use std::{collections::BTreeSet, sync::Arc};
+
+use egui::Ui;
+
+use crate::{context::InspectContext, diff_paths, EguiInspect, InspectResponse};
+
+/// Width of the reset button shown at the end of the field rows.
+const RESET_WIDTH: f32 = 20.;
+
+/// Object the inspected fields are compared to, see
+/// [`EguiInspector::with_baseline`](crate::EguiInspector::with_baseline).
+pub(crate) struct Baseline<'a, T> {
+    source: Source<'a, T>,
+    diff: fn(&T, &T) -> Vec<String>,
+    copy: fn(&mut T, &T, &str) -> Result<(), String>,
+}
+
+enum Source<'a, T> {
+    Object(&'a T),
+    Default(fn() -> T),
+}
+
+impl<'a, T: EguiInspect> Baseline<'a, T> {
+    pub(crate) fn new(object: &'a T) -> Self {
+        Self::with_source(Source::Object(object))
+    }
+    pub(crate) fn from_default() -> Self
+    where
+        T: Default,
+    {
+        Self::with_source(Source::Default(T::default))
+    }
+    fn with_source(source: Source<'a, T>) -> Self {
+        Self {
+            source,
+            diff: diff_paths::<T>,
+            copy: T::copy_field_from,
+        }
+    }
+}
+
+/// Inspects `obj` with `add_contents`, showing the fields differing from `baseline` (if any),
+/// and resets the field whose reset button was clicked.
+pub(crate) fn scope<T>(
+    baseline: Option<&Baseline<'_, T>>,
+    obj: &mut T,
+    ui: &mut Ui,
+    add_contents: impl FnOnce(&mut T, &mut Ui) -> InspectResponse,
+) -> InspectResponse {
+    let Some(baseline) = baseline else {
+        return add_contents(obj, ui);
+    };
+    let default;
+    let base = match &baseline.source {
+        Source::Object(object) => *object,
+        Source::Default(default_fn) => {
+            default = default_fn();
+            &default
+        }
+    };
+    let diffs: BTreeSet<String> = (baseline.diff)(base, obj).into_iter().collect();
+    InspectContext::with(ui.ctx(), |context| context.baseline = Some(Arc::new(diffs)));
+    let mut response = add_contents(obj, ui);
+    if let Some(path) = InspectContext::with(ui.ctx(), |context| context.reset.take()) {
+        // fields which can't be copied from the baseline are left unchanged
+        if (baseline.copy)(obj, base, &path).is_ok() {
+            response.mark_changed();
+        }
+    }
+    response
+}
+
+/// `true` if the field being rendered, or one of its children, differs from the baseline of
+/// the [`EguiInspector`](crate::EguiInspector).
+pub fn differs_from_baseline(ui: &Ui) -> bool {
+    InspectContext::with(ui.ctx(), |context| context.differs_from_baseline())
+}
+
+/// Width taken by [`reset_button`] at the end of the field rows.
+pub fn reset_width(ui: &Ui) -> f32 {
+    if InspectContext::with(ui.ctx(), |context| context.baseline.is_some()) {
+        RESET_WIDTH + ui.spacing().item_spacing.x
+    } else {
+        0.
+    }
+}
+
+/// Adds a button resetting the field being rendered to its value in the baseline of the
+/// [`EguiInspector`](crate::EguiInspector), at the end of the row, if it differs from it.
+pub fn reset_button(read_only: bool, ui: &mut Ui) {
+    let differs = InspectContext::with(ui.ctx(), |context| context.differs_from_baseline());
+    if read_only || !differs {
+        return;
+    }
+    ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+        let button = egui::Button::new("↺").small();
+        if ui.add_sized([RESET_WIDTH, 0.], button).on_hover_text("Reset this field").clicked() {
+            InspectContext::with(ui.ctx(), |context| context.reset = Some(context.path().to_owned()));
+        }
+    });
+}
@@ -0,0 +1,187 @@
+use std::sync::{Arc, Mutex};
+
+use egui::{Id, Ui};
+
+use crate::{history, EguiInspect, InspectResponse};
+
+/// A committed edit, see [`EguiInspector::record_changes`](crate::EguiInspector::record_changes)
+/// and [`EguiInspect::collect_changes`].
+///
+/// The values are written the way [`EguiInspect::set_by_path_from_str`] parses them, so that
+/// the edits can be replayed on another copy of the object.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FieldChange {
+    /// Path of the modified field, with the syntax of [`ValidationError::path`](crate::ValidationError::path).
+    pub path: String,
+    /// What happened to the field.
+    pub kind: ChangeKind,
+    /// Value before the edit, empty if it is unknown (e.g. a struct) or if the field was inserted.
+    pub old_value: String,
+    /// Value after the edit, empty if it is unknown or if the field was removed.
+    pub new_value: String,
+}
+
+/// What happened to the field of a [`FieldChange`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ChangeKind {
+    /// The value of the field changed. For an enum, the values are the names of the variants
+    /// when the variant changed: setting the name switches a derived enum to the variant (see
+    /// [`EguiInspect::set_by_path_from_str`](crate::EguiInspect::set_by_path_from_str)), the
+    /// fields of the new variant differing from their default value are reported by the
+    /// following changes.
+    Set,
+    /// An item was inserted at `path` (e.g. `hosts[2]`), or an [`Option`] became `Some`.
+    ///
+    /// The inserted value is a default one: the fields differing from it are reported by the
+    /// following changes.
+    Insert,
+    /// The item at `path` was removed, or an [`Option`] became `None`.
+    Remove,
+    /// The item at index `from` of the list at `path` was moved to index `to`.
+    Move {
+        /// Index of the item before the move.
+        from: usize,
+        /// Index of the item after the move.
+        to: usize,
+    },
+    /// The field changed but its values can't be written: its type compares with [`PartialEq`]
+    /// but its [`EguiInspect::collect_changes`] reports nothing (e.g. a manual implementation
+    /// not overriding it). The whole field has to be copied, e.g. with
+    /// [`EguiInspect::copy_field_from`].
+    Opaque,
+}
+
+impl FieldChange {
+    /// A [`ChangeKind::Set`] change.
+    pub fn set(path: &str, old_value: impl ToString, new_value: impl ToString) -> Self {
+        Self {
+            path: path.to_owned(),
+            kind: ChangeKind::Set,
+            old_value: old_value.to_string(),
+            new_value: new_value.to_string(),
+        }
+    }
+    /// A change without values: [`ChangeKind::Insert`], [`ChangeKind::Remove`], [`ChangeKind::Move`]
+    /// or [`ChangeKind::Opaque`].
+    pub fn new(path: &str, kind: ChangeKind) -> Self {
+        Self {
+            path: path.to_owned(),
+            kind,
+            old_value: String::new(),
+            new_value: String::new(),
+        }
+    }
+}
+
+/// Returns the edits turning `old` into `new`, see [`EguiInspect::collect_changes`].
+///
+/// # Examples
+///
+/// ```rust
+/// use egui_field_editor::{field_changes, ChangeKind, EguiInspect, FieldChange};
+/// #[derive(EguiInspect, Clone, Default)]
+/// struct Server {
+///     host: String,
+///     ports: Vec<u16>,
+/// }
+/// let old = Server { host: "localhost".to_owned(), ports: vec![80, 443] };
+/// let mut new = old.clone();
+/// new.host = "example.com".to_owned();
+/// new.ports.insert(0, 8080);
+/// assert_eq!(field_changes(&old, &new), [
+///     FieldChange::set("host", "localhost", "example.com"),
+///     FieldChange::new("ports[0]", ChangeKind::Insert),
+///     FieldChange::set("ports[0]", 0, 8080),
+/// ]);
+/// ```
+///
+/// The changes of variant of an enum are followed by the fields of the new variant, so that the
+/// edits can be replayed with [`EguiInspect::set_by_path_from_str`]:
+///
+/// ```rust
+/// use egui_field_editor::{field_changes, EguiInspect, FieldChange};
+/// #[derive(EguiInspect, Clone, Debug, Default, PartialEq)]
+/// enum Shape {
+///     #[default]
+///     Empty,
+///     Rect { width: u32, title: String },
+/// }
+/// let old = Shape::Empty;
+/// let new = Shape::Rect { width: 3, title: "door".to_owned() };
+/// let changes = field_changes(&old, &new);
+/// assert_eq!(changes, [
+///     FieldChange::set("", "Empty", "Rect"),
+///     FieldChange::set("width", 0, 3),
+///     FieldChange::set("title", "", "door"),
+/// ]);
+/// let mut replica = old.clone();
+/// for change in &changes {
+///     replica.set_by_path_from_str(&change.path, &change.new_value).unwrap();
+/// }
+/// assert_eq!(replica, new);
+/// assert!(replica.set_by_path_from_str("", "Circle").is_err());
+/// ```
+pub fn field_changes<T: EguiInspect + ?Sized>(old: &T, new: &T) -> Vec<FieldChange> {
+    let mut changes = Vec::new();
+    old.collect_changes(new, "", &mut changes);
+    changes
+}
+
+/// Records the edits made through an inspector, see
+/// [`EguiInspector::record_changes`](crate::EguiInspector::record_changes).
+pub(crate) struct Recorder<'a, T> {
+    pub(crate) changes: &'a mut Vec<FieldChange>,
+    /// Called before rendering the object.
+    pub(crate) begin: fn(&T, Id, &Ui),
+    /// Called after rendering the object, with the response of every edit.
+    pub(crate) end: fn(&T, InspectResponse, Id, &mut Vec<FieldChange>, &Ui),
+}
+
+/// State of a [`Recorder`], kept in `egui` memory between frames.
+struct RecorderState<T> {
+    /// Value of the object when the current gesture started.
+    before: T,
+    /// `true` if the object was edited during the current gesture.
+    pending: bool,
+}
+
+impl<'a, T: EguiInspect + Clone + Send + 'static> Recorder<'a, T> {
+    pub(crate) fn new(changes: &'a mut Vec<FieldChange>) -> Self {
+        Self {
+            changes,
+            begin: Self::begin,
+            end: Self::end,
+        }
+    }
+    fn state(obj: &T, key: Id, ui: &Ui) -> Arc<Mutex<RecorderState<T>>> {
+        ui.data_mut(|d| {
+            d.get_temp_mut_or_insert_with(key, || {
+                Arc::new(Mutex::new(RecorderState {
+                    before: obj.clone(),
+                    pending: false,
+                }))
+            })
+            .clone()
+        })
+    }
+    fn begin(obj: &T, key: Id, ui: &Ui) {
+        let state = Self::state(obj, key, ui);
+        let mut state = state.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        if !state.pending {
+            // the object may have been modified outside of the inspector
+            state.before = obj.clone();
+        }
+    }
+    fn end(obj: &T, response: InspectResponse, key: Id, changes: &mut Vec<FieldChange>, ui: &Ui) {
+        let state = Self::state(obj, key, ui);
+        let mut state = state.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        state.pending |= response.changed();
+        if state.pending && history::is_gesture_over(response, ui) {
+            state.before.collect_changes(obj, "", changes);
+            state.before = obj.clone();
+            state.pending = false;
+        }
+    }
+}
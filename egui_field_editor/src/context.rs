@@ -0,0 +1,247 @@
+use std::{collections::BTreeSet, ops::Bound, sync::Arc};
+
+use egui::{Context, Id, Ui};
+
+use crate::{
+    diff_view::{self, DiffColumn},
+    field_menu::{MenuAction, MenuEntry},
+    list::{ItemKeys, ListLimits},
+    search::{self, Search},
+};
+
+/// State shared by the inspected fields while an [`EguiInspector`](crate::EguiInspector) is rendered.
+#[derive(Clone, Default)]
+pub(crate) struct InspectContext {
+    /// Path of the field being rendered, e.g. `network.hosts[2].port`.
+    path: String,
+    /// State before each [`FieldScope`] was entered.
+    scopes: Vec<SavedScope>,
+    /// Paths of the fields to highlight as modified.
+    modified: Option<Arc<BTreeSet<String>>>,
+    /// Context menu of the labels of the field being rendered.
+    menu: Option<MenuEntry>,
+    /// Token given to the next registered menu.
+    next_token: u64,
+    /// Action selected in a context menu, with the token of the menu.
+    pending_action: Option<(u64, MenuAction)>,
+    /// Search filtering the fields, see [`search`].
+    pub(crate) search: Option<Search>,
+    /// Column of a diff view being rendered, see [`crate::EguiDiffInspector`].
+    pub(crate) diff: Option<DiffColumn>,
+    /// Paths of the fields differing from the baseline, see [`crate::baseline`].
+    pub(crate) baseline: Option<Arc<BTreeSet<String>>>,
+    /// Path of the field whose reset button was clicked.
+    pub(crate) reset: Option<String>,
+    /// Number of items per page of the lists, see [`crate::pages`].
+    pub(crate) page_size: Option<usize>,
+    /// Limits of the list of the field being rendered, see [`crate::list`].
+    pub(crate) list_limits: Option<ListLimits>,
+    /// Keys of the items of the list of the field being rendered, see [`crate::list`].
+    pub(crate) item_keys: Option<ItemKeys>,
+    /// Paths of the fields differing between the edited objects, see [`crate::multi`].
+    pub(crate) mixed: Option<Arc<BTreeSet<String>>>,
+}
+
+/// State of the parent field saved by a [`FieldScope`].
+#[derive(Clone)]
+struct SavedScope {
+    path_len: usize,
+    menu: Option<MenuEntry>,
+    list_limits: Option<ListLimits>,
+    item_keys: Option<ItemKeys>,
+}
+
+impl InspectContext {
+    fn id() -> Id {
+        Id::new("egui_field_editor::InspectContext")
+    }
+    pub(crate) fn with<R>(ctx: &Context, f: impl FnOnce(&mut Self) -> R) -> R {
+        ctx.data_mut(|d| f(d.get_temp_mut_or_default::<Self>(Self::id())))
+    }
+    /// Renders `add_contents` with a new root path and the given modified fields, then restores
+    /// the previous state (so inspectors can be nested).
+    pub(crate) fn scope<R>(
+        ui: &mut Ui,
+        modified: Option<BTreeSet<String>>,
+        add_contents: impl FnOnce(&mut Ui) -> R,
+    ) -> R {
+        let new_context = Self {
+            modified: modified.map(Arc::new),
+            page_size: Self::with(ui.ctx(), |context| context.page_size),
+            ..Default::default()
+        };
+        let previous = Self::with(ui.ctx(), |context| std::mem::replace(context, new_context));
+        let result = add_contents(ui);
+        Self::with(ui.ctx(), |context| *context = previous);
+        result
+    }
+    /// `true` if the field being rendered, or one of its children, is modified.
+    pub(crate) fn is_path_modified(&self) -> bool {
+        self.modified.as_ref().is_some_and(|modified| contains(modified, &self.path))
+    }
+    /// `true` if the field being rendered, or one of its children, differs from the baseline.
+    pub(crate) fn differs_from_baseline(&self) -> bool {
+        self.baseline.as_ref().is_some_and(|baseline| contains(baseline, &self.path))
+    }
+    /// Makes a context menu available for the labels of the field being rendered.
+    ///
+    /// Returns the token identifying the menu.
+    pub(crate) fn register_menu(&mut self, mut entry: MenuEntry) -> u64 {
+        self.next_token += 1;
+        entry.token = self.next_token;
+        self.menu = Some(entry);
+        entry.token
+    }
+    /// Context menu of the field being rendered, if any.
+    pub(crate) fn menu(&self) -> Option<MenuEntry> {
+        self.menu
+    }
+    pub(crate) fn path(&self) -> &str {
+        &self.path
+    }
+    pub(crate) fn set_pending_action(&mut self, token: u64, action: MenuAction) {
+        self.pending_action = Some((token, action));
+    }
+    /// Takes the action selected in the menu identified by `token`, if any.
+    pub(crate) fn take_pending_action(&mut self, token: u64) -> Option<MenuAction> {
+        match self.pending_action {
+            Some((pending_token, action)) if pending_token == token => {
+                self.pending_action = None;
+                Some(action)
+            }
+            _ => None,
+        }
+    }
+}
+
+/// `true` if `paths` contains `path` or one of its children.
+fn contains(paths: &BTreeSet<String>, path: &str) -> bool {
+    paths
+        .range::<str, _>((Bound::Included(path), Bound::Unbounded))
+        .map_while(|other| other.strip_prefix(path))
+        .any(|rest| rest.is_empty() || path.is_empty() || rest.starts_with(['.', '[']))
+}
+
+/// Path of the field being rendered, e.g. `network.hosts[2].port`.
+///
+/// Paths have the same syntax as [`ValidationError::path`](crate::ValidationError::path).
+/// They are tracked by the code generated by `#[derive(EguiInspect)]` and by containers, and are
+/// relative to the object given to the [`EguiInspector`](crate::EguiInspector).
+pub fn current_field_path(ui: &Ui) -> String {
+    InspectContext::with(ui.ctx(), |context| context.path.clone())
+}
+
+/// Appends a field to the [current path](current_field_path) until dropped.
+///
+/// Manual implementations of [`EguiInspect`](crate::EguiInspect) can use it so that their
+/// fields are highlighted when they are modified:
+///
+/// ```rust
+/// # struct MyStruct { count: u32 }
+/// # impl MyStruct {
+/// fn inspect_fields(&mut self, read_only: bool, ui: &mut egui::Ui) -> egui_field_editor::InspectResponse {
+///     let _scope = egui_field_editor::FieldScope::new(ui, "count");
+///     egui_field_editor::add_number(&mut self.count, "Count", "", read_only, None, ui)
+/// }
+/// # }
+/// ```
+pub struct FieldScope {
+    ctx: Context,
+}
+
+impl FieldScope {
+    /// Enters the field `name` of the current object.
+    pub fn new(ui: &Ui, name: &str) -> Self {
+        Self::push(ui, |path| {
+            if !path.is_empty() {
+                path.push('.');
+            }
+            path.push_str(name);
+        })
+    }
+    /// Enters the item `index` of the current container.
+    pub fn index(ui: &Ui, index: usize) -> Self {
+        Self::push(ui, |path| {
+            path.push_str(&format!("[{index}]"));
+        })
+    }
+    fn push(ui: &Ui, f: impl FnOnce(&mut String)) -> Self {
+        InspectContext::with(ui.ctx(), |context| {
+            context.scopes.push(SavedScope {
+                path_len: context.path.len(),
+                menu: context.menu.take(),
+                list_limits: context.list_limits.take(),
+                item_keys: context.item_keys.take(),
+            });
+            f(&mut context.path);
+        });
+        Self {
+            ctx: ui.ctx().clone(),
+        }
+    }
+}
+
+impl Drop for FieldScope {
+    fn drop(&mut self) {
+        InspectContext::with(&self.ctx, |context| {
+            if let Some(saved) = context.scopes.pop() {
+                context.path.truncate(saved.path_len);
+                context.menu = saved.menu;
+                context.list_limits = saved.list_limits;
+                context.item_keys = saved.item_keys;
+            }
+        });
+    }
+}
+
+/// Label of a field, highlighted if the field is modified, and clickable if it has a context
+/// menu (see [`crate::field_menu::add_field_menu`]).
+pub fn field_label(label: &str, ui: &Ui) -> egui::Label {
+    let has_menu = InspectContext::with(ui.ctx(), |context| context.menu.is_some());
+    let label = egui::Label::new(label_text(label, ui))
+        .truncate()
+        .show_tooltip_when_elided(true)
+        .halign(egui::Align::LEFT);
+    if has_menu {
+        label.sense(egui::Sense::click())
+    } else {
+        label
+    }
+}
+
+/// Text of a field label, highlighted if the field is modified or if it matches the search,
+/// and bold if it differs from the baseline of the [`EguiInspector`](crate::EguiInspector).
+///
+/// The position of the label is recorded to show its copy button in a [`crate::EguiDiffInspector`].
+pub fn label_text(label: &str, ui: &Ui) -> egui::WidgetText {
+    diff_view::record_label(ui);
+    let (modified, differs) = InspectContext::with(ui.ctx(), |context| {
+        (context.is_path_modified(), context.differs_from_baseline())
+    });
+    let shown = if differs {
+        std::borrow::Cow::Owned(format!("• {label}"))
+    } else {
+        std::borrow::Cow::Borrowed(label)
+    };
+    if let Some(query) = search::match_label(label, ui) {
+        let color = if modified {
+            ui.visuals().warn_fg_color
+        } else if differs {
+            ui.visuals().strong_text_color()
+        } else {
+            ui.visuals().text_color()
+        };
+        search::highlight(&shown, &query, color, ui)
+    } else if modified || differs {
+        let mut text = egui::RichText::new(shown);
+        if modified {
+            text = text.color(ui.visuals().warn_fg_color);
+        }
+        if differs {
+            text = text.strong();
+        }
+        text.into()
+    } else {
+        label.into()
+    }
+}
@@ -0,0 +1,668 @@
+//! Helpers used by the code generated by `#[derive(EguiInspect)]`. Not part of the public API.
+//!
+//! The derived [`EguiDisplay`] implementations need to display fields whose type may not
+//! implement [`EguiDisplay`] (types with a manual [`EguiInspect`] implementation, fields using
+//! `custom_fn`...). [`DisplayField`] picks the best available option at compile time using
+//! autoref-based specialization: the generated code calls the methods on `&&&DisplayField`
+//! and the first trait whose bounds are satisfied is used.
+//! [`ValidateField`] works the same way to only validate fields implementing [`EguiInspect`],
+//! [`DiffField`] to compare fields with [`EguiInspect::diff`] or [`PartialEq`], [`ChangeField`]
+//! to report their changes with [`EguiInspect::collect_changes`] or [`Display`],
+//! [`ParseError`] to display parse errors when possible, [`MenuField`] to offer the
+//! actions of the context menu supported by a field, [`PathField`] to reach the fields
+//! of [`EguiInspect::get_by_path`] and [`EguiInspect::set_by_path_from_str`], and [`CopyField`]
+//! to copy them with [`EguiInspect::copy_field_from`].
+
+use std::{any::Any, fmt::Display, marker::PhantomData, str::FromStr};
+
+use egui::Ui;
+
+use crate::{ChangeKind, EguiDisplay, EguiInspect, FieldChange, FieldInfo, InspectResponse, ValidationError};
+
+pub use crate::baseline::{reset_button, reset_width};
+pub use crate::context::{field_label, label_text};
+pub use crate::enum_style::{variant_item, variant_selector, EnumStyle};
+pub use crate::list::{ItemKeys, ListLimits};
+pub use crate::field_menu::{add_field_menu, CopyFn, FieldMenu, PasteFn};
+pub use crate::multi::{is_mixed, MIXED};
+pub use crate::search::{header_open, is_field_visible};
+
+/// Wraps a field borrowed by a derived [`EguiDisplay`] implementation.
+pub struct DisplayField<'a, T: ?Sized>(pub &'a T);
+
+/// Displays fields implementing [`EguiDisplay`].
+pub trait ViaDisplay {
+    fn display_field(&self, parent_id: egui::Id, label: &str, tooltip: &str, ui: &mut Ui) -> InspectResponse;
+}
+impl<T: EguiDisplay + ?Sized> ViaDisplay for &&DisplayField<'_, T> {
+    fn display_field(&self, parent_id: egui::Id, label: &str, tooltip: &str, ui: &mut Ui) -> InspectResponse {
+        self.0.display_with_custom_id(parent_id, label, tooltip, ui);
+        InspectResponse::default()
+    }
+}
+
+/// Displays fields only implementing [`EguiInspect`] by inspecting a read-only clone.
+pub trait ViaInspect {
+    fn display_field(&self, parent_id: egui::Id, label: &str, tooltip: &str, ui: &mut Ui) -> InspectResponse;
+}
+impl<T: EguiInspect + Clone> ViaInspect for &DisplayField<'_, T> {
+    fn display_field(&self, parent_id: egui::Id, label: &str, tooltip: &str, ui: &mut Ui) -> InspectResponse {
+        let mut value = self.0.clone();
+        value.inspect_with_custom_id(parent_id, label, tooltip, true, ui);
+        InspectResponse::default()
+    }
+}
+
+/// Fallback for fields which cannot be displayed.
+pub trait ViaPlaceholder {
+    fn display_field(&self, parent_id: egui::Id, label: &str, tooltip: &str, ui: &mut Ui) -> InspectResponse;
+}
+impl<T: ?Sized> ViaPlaceholder for DisplayField<'_, T> {
+    fn display_field(&self, _parent_id: egui::Id, label: &str, tooltip: &str, ui: &mut Ui) -> InspectResponse {
+        placeholder(label, tooltip, ui)
+    }
+}
+
+/// Displays fields rendered by an `add_*` helper or a `custom_fn` through a read-only clone.
+pub trait ViaClone {
+    type Value;
+    fn display_cloned<F>(&self, label: &str, tooltip: &str, ui: &mut Ui, field_renderer: F) -> InspectResponse
+    where
+        F: FnOnce(&mut Self::Value, &mut Ui) -> InspectResponse;
+}
+impl<T: Clone> ViaClone for &DisplayField<'_, T> {
+    type Value = T;
+    fn display_cloned<F>(&self, _label: &str, _tooltip: &str, ui: &mut Ui, field_renderer: F) -> InspectResponse
+    where
+        F: FnOnce(&mut T, &mut Ui) -> InspectResponse,
+    {
+        let mut value = self.0.clone();
+        field_renderer(&mut value, ui);
+        InspectResponse::default()
+    }
+}
+
+/// Fallback for fields rendered by an `add_*` helper or a `custom_fn` which cannot be cloned.
+pub trait ViaClonePlaceholder {
+    type Value;
+    fn display_cloned<F>(&self, label: &str, tooltip: &str, ui: &mut Ui, field_renderer: F) -> InspectResponse
+    where
+        F: FnOnce(&mut Self::Value, &mut Ui) -> InspectResponse;
+}
+impl<T> ViaClonePlaceholder for DisplayField<'_, T> {
+    type Value = T;
+    fn display_cloned<F>(&self, label: &str, tooltip: &str, ui: &mut Ui, _field_renderer: F) -> InspectResponse
+    where
+        F: FnOnce(&mut T, &mut Ui) -> InspectResponse,
+    {
+        placeholder(label, tooltip, ui)
+    }
+}
+
+fn placeholder(label: &str, tooltip: &str, ui: &mut Ui) -> InspectResponse {
+    crate::add_custom_ui(label, tooltip, true, ui, |ui, _field_width| {
+        ui.label("…").on_disabled_hover_text("This field cannot be displayed read-only");
+    })
+}
+
+/// Wraps a field validated by a derived [`EguiInspect::validate`] implementation.
+pub struct ValidateField<'a, T: ?Sized>(pub &'a T);
+
+/// Validates fields implementing [`EguiInspect`].
+pub trait ValidateWithEguiInspect {
+    fn validate_field(&self, path: &str, errors: &mut Vec<ValidationError>);
+}
+impl<T: EguiInspect + ?Sized> ValidateWithEguiInspect for &ValidateField<'_, T> {
+    fn validate_field(&self, path: &str, errors: &mut Vec<ValidationError>) {
+        self.0.validate(path, errors);
+    }
+}
+
+/// Fallback for fields which are not inspected through [`EguiInspect`] (`from_string`, `custom_fn`...).
+pub trait ValidateNothing {
+    fn validate_field(&self, path: &str, errors: &mut Vec<ValidationError>);
+}
+impl<T: ?Sized> ValidateNothing for ValidateField<'_, T> {
+    fn validate_field(&self, _path: &str, _errors: &mut Vec<ValidationError>) {}
+}
+
+/// Wraps the fields of two objects compared by a derived [`EguiInspect::diff`] implementation.
+pub struct DiffField<'a, T: ?Sized>(pub &'a T, pub &'a T);
+
+/// Compares fields implementing [`EguiInspect`] and [`PartialEq`]. The field itself is reported
+/// when they differ but [`EguiInspect::diff`] reports nothing (e.g. default implementation).
+pub trait DiffWithEguiInspectEq {
+    fn diff_field(&self, path: &str, diffs: &mut Vec<String>);
+}
+impl<T: EguiInspect + PartialEq + ?Sized> DiffWithEguiInspectEq for &&&DiffField<'_, T> {
+    fn diff_field(&self, path: &str, diffs: &mut Vec<String>) {
+        if self.0 != self.1 {
+            let len = diffs.len();
+            self.0.diff(self.1, path, diffs);
+            if diffs.len() == len {
+                diffs.push(path.to_owned());
+            }
+        }
+    }
+}
+
+/// Compares fields implementing [`EguiInspect`].
+pub trait DiffWithEguiInspect {
+    fn diff_field(&self, path: &str, diffs: &mut Vec<String>);
+}
+impl<T: EguiInspect + ?Sized> DiffWithEguiInspect for &&DiffField<'_, T> {
+    fn diff_field(&self, path: &str, diffs: &mut Vec<String>) {
+        self.0.diff(self.1, path, diffs);
+    }
+}
+
+/// Compares fields only implementing [`PartialEq`] (`custom_fn`, `from_string`...).
+pub trait DiffWithEq {
+    fn diff_field(&self, path: &str, diffs: &mut Vec<String>);
+}
+impl<T: PartialEq + ?Sized> DiffWithEq for &DiffField<'_, T> {
+    fn diff_field(&self, path: &str, diffs: &mut Vec<String>) {
+        if self.0 != self.1 {
+            diffs.push(path.to_owned());
+        }
+    }
+}
+
+/// Fallback for fields which can't be compared.
+pub trait DiffNothing {
+    fn diff_field(&self, path: &str, diffs: &mut Vec<String>);
+}
+impl<T: ?Sized> DiffNothing for DiffField<'_, T> {
+    fn diff_field(&self, _path: &str, _diffs: &mut Vec<String>) {}
+}
+
+/// Wraps the fields of two objects compared by a derived [`EguiInspect::collect_changes`] implementation.
+pub struct ChangeField<'a, T: ?Sized>(pub &'a T, pub &'a T);
+
+/// Compares fields implementing [`EguiInspect`] and [`PartialEq`]. The field itself is reported
+/// as a [`ChangeKind::Opaque`] change when they differ but [`EguiInspect::collect_changes`]
+/// reports nothing.
+pub trait ChangeWithEguiInspectEq {
+    fn collect_field_changes(&self, path: &str, changes: &mut Vec<FieldChange>);
+}
+impl<T: EguiInspect + PartialEq + ?Sized> ChangeWithEguiInspectEq for &&&ChangeField<'_, T> {
+    fn collect_field_changes(&self, path: &str, changes: &mut Vec<FieldChange>) {
+        if self.0 != self.1 {
+            let len = changes.len();
+            self.0.collect_changes(self.1, path, changes);
+            if changes.len() == len {
+                changes.push(FieldChange::new(path, ChangeKind::Opaque));
+            }
+        }
+    }
+}
+
+/// Compares fields implementing [`EguiInspect`].
+pub trait ChangeWithEguiInspect {
+    fn collect_field_changes(&self, path: &str, changes: &mut Vec<FieldChange>);
+}
+impl<T: EguiInspect + ?Sized> ChangeWithEguiInspect for &&ChangeField<'_, T> {
+    fn collect_field_changes(&self, path: &str, changes: &mut Vec<FieldChange>) {
+        self.0.collect_changes(self.1, path, changes);
+    }
+}
+
+/// Compares fields only implementing [`PartialEq`] and [`Display`] (`custom_fn`, `from_string`...).
+pub trait ChangeWithDisplayEq {
+    fn collect_field_changes(&self, path: &str, changes: &mut Vec<FieldChange>);
+}
+impl<T: PartialEq + Display + ?Sized> ChangeWithDisplayEq for &ChangeField<'_, T> {
+    fn collect_field_changes(&self, path: &str, changes: &mut Vec<FieldChange>) {
+        if self.0 != self.1 {
+            changes.push(FieldChange::set(path, self.0, self.1));
+        }
+    }
+}
+
+/// Fallback for fields which can't be compared.
+pub trait ChangeNothing {
+    fn collect_field_changes(&self, path: &str, changes: &mut Vec<FieldChange>);
+}
+impl<T: ?Sized> ChangeNothing for ChangeField<'_, T> {
+    fn collect_field_changes(&self, _path: &str, _changes: &mut Vec<FieldChange>) {}
+}
+
+/// Wraps a field reached by the derived reflection methods ([`EguiInspect::field_paths`]...).
+pub struct PathField<T>(pub T);
+
+/// Reaches the fields of fields implementing [`EguiInspect`].
+pub trait PathWithEguiInspect<'a> {
+    fn collect_field_paths(&self, path: &str, fields: &mut Vec<FieldInfo>);
+    fn get_field(&self, path: &str) -> Option<&'a dyn Any>;
+}
+impl<'a, T: EguiInspect + ?Sized> PathWithEguiInspect<'a> for &&PathField<&'a T> {
+    fn collect_field_paths(&self, path: &str, fields: &mut Vec<FieldInfo>) {
+        self.0.collect_field_paths(path, fields);
+    }
+    fn get_field(&self, path: &str) -> Option<&'a dyn Any> {
+        self.0.get_by_path(path)
+    }
+}
+
+/// Returns the fields not implementing [`EguiInspect`] (`custom_fn`, `from_string`...) for an
+/// empty path.
+pub trait PathWithAny<'a> {
+    fn collect_field_paths(&self, path: &str, fields: &mut Vec<FieldInfo>);
+    fn get_field(&self, path: &str) -> Option<&'a dyn Any>;
+}
+impl<'a, T: Any> PathWithAny<'a> for &PathField<&'a T> {
+    fn collect_field_paths(&self, _path: &str, _fields: &mut Vec<FieldInfo>) {}
+    fn get_field(&self, path: &str) -> Option<&'a dyn Any> {
+        path.is_empty().then_some(self.0 as &dyn Any)
+    }
+}
+
+/// Fallback for fields which can't be reached.
+pub trait PathNothing<'a> {
+    fn collect_field_paths(&self, path: &str, fields: &mut Vec<FieldInfo>);
+    fn get_field(&self, path: &str) -> Option<&'a dyn Any>;
+}
+impl<'a, T: ?Sized> PathNothing<'a> for PathField<&'a T> {
+    fn collect_field_paths(&self, _path: &str, _fields: &mut Vec<FieldInfo>) {}
+    fn get_field(&self, _path: &str) -> Option<&'a dyn Any> {
+        None
+    }
+}
+
+/// Gives the function setting a field from a string, see [`EguiInspect::set_by_path_from_str`].
+pub struct SetField<T>(PhantomData<T>);
+impl<T> SetField<T> {
+    pub fn of(_value: &T) -> Self {
+        Self(PhantomData)
+    }
+}
+
+/// Sets a field from a string: `(field, path, value)`.
+pub type SetFn<T> = fn(&mut T, &str, &str) -> Result<(), String>;
+
+/// Sets fields implementing [`EguiInspect`] (which parse the value themselves).
+pub trait SetWithEguiInspect {
+    type Value;
+    fn setter(&self) -> SetFn<Self::Value>;
+}
+impl<T: EguiInspect> SetWithEguiInspect for &&SetField<T> {
+    type Value = T;
+    fn setter(&self) -> SetFn<T> {
+        |field, path, value| field.set_by_path_from_str(path, value)
+    }
+}
+
+/// Sets fields implementing [`FromStr`] (`custom_fn`, `from_string`...).
+pub trait SetWithFromStr {
+    type Value;
+    fn setter(&self) -> SetFn<Self::Value>;
+}
+impl<T: FromStr> SetWithFromStr for &SetField<T>
+where
+    T::Err: Display,
+{
+    type Value = T;
+    fn setter(&self) -> SetFn<T> {
+        set_from_str
+    }
+}
+
+/// Fallback for fields which can't be set from a string.
+pub trait SetNothing {
+    type Value;
+    fn setter(&self) -> SetFn<Self::Value>;
+}
+impl<T> SetNothing for SetField<T> {
+    type Value = T;
+    fn setter(&self) -> SetFn<T> {
+        |_field, path, _value| {
+            if path.is_empty() {
+                Err("This field can't be set from a string".to_owned())
+            } else {
+                Err(no_field(path))
+            }
+        }
+    }
+}
+
+/// Gives the function copying a field from another object, see [`EguiInspect::copy_field_from`].
+pub struct CopyField<T>(PhantomData<T>);
+impl<T> CopyField<T> {
+    pub fn of(_value: &T) -> Self {
+        Self(PhantomData)
+    }
+}
+
+/// Copies a field from another object: `(field, other_field, path)`.
+pub type CopyFieldFn<T> = fn(&mut T, &T, &str) -> Result<(), String>;
+
+/// Clones the fields implementing [`EguiInspect`] and [`Clone`] when they are copied as a whole.
+pub trait CopyWithEguiInspectClone {
+    type Value;
+    fn copier(&self) -> CopyFieldFn<Self::Value>;
+}
+impl<T: EguiInspect + Clone> CopyWithEguiInspectClone for &&&CopyField<T> {
+    type Value = T;
+    fn copier(&self) -> CopyFieldFn<T> {
+        |field, other, path| {
+            if path.is_empty() {
+                field.clone_from(other);
+                Ok(())
+            } else {
+                field.copy_field_from(other, path)
+            }
+        }
+    }
+}
+
+/// Copies fields implementing [`EguiInspect`].
+pub trait CopyWithEguiInspect {
+    type Value;
+    fn copier(&self) -> CopyFieldFn<Self::Value>;
+}
+impl<T: EguiInspect> CopyWithEguiInspect for &&CopyField<T> {
+    type Value = T;
+    fn copier(&self) -> CopyFieldFn<T> {
+        |field, other, path| field.copy_field_from(other, path)
+    }
+}
+
+/// Clones fields only implementing [`Clone`] (`custom_fn`, `from_string`...).
+pub trait CopyWithClone {
+    type Value;
+    fn copier(&self) -> CopyFieldFn<Self::Value>;
+}
+impl<T: Clone> CopyWithClone for &CopyField<T> {
+    type Value = T;
+    fn copier(&self) -> CopyFieldFn<T> {
+        |field, other, path| {
+            if !path.is_empty() {
+                return Err(no_field(path));
+            }
+            field.clone_from(other);
+            Ok(())
+        }
+    }
+}
+
+/// Fallback for fields which can't be copied.
+pub trait CopyNothing {
+    type Value;
+    fn copier(&self) -> CopyFieldFn<Self::Value>;
+}
+impl<T> CopyNothing for CopyField<T> {
+    type Value = T;
+    fn copier(&self) -> CopyFieldFn<T> {
+        |_field, _other, path| Err(not_copyable(path))
+    }
+}
+
+/// Gives the [`Clone`] implementation of a derived type, used by [`EguiInspect::duplicate`]
+/// and by [`EguiInspect::copy_field_from`] when the variants of an enum differ.
+pub struct CloneValue<T>(PhantomData<T>);
+impl<T> CloneValue<T> {
+    pub fn of(_value: &T) -> Self {
+        Self(PhantomData)
+    }
+}
+
+/// Clones a value.
+pub type CloneFn<T> = fn(&T) -> T;
+
+/// Clones values implementing [`Clone`].
+pub trait CloneWithClone {
+    type Value;
+    fn cloner(&self) -> Option<CloneFn<Self::Value>>;
+}
+impl<T: Clone> CloneWithClone for &CloneValue<T> {
+    type Value = T;
+    fn cloner(&self) -> Option<CloneFn<T>> {
+        Some(T::clone)
+    }
+}
+
+/// Fallback for values which can't be cloned.
+pub trait CloneNothing {
+    type Value;
+    fn cloner(&self) -> Option<CloneFn<Self::Value>>;
+}
+impl<T> CloneNothing for CloneValue<T> {
+    type Value = T;
+    fn cloner(&self) -> Option<CloneFn<T>> {
+        None
+    }
+}
+
+/// Returns `self` from the derived [`EguiInspect::get_by_path`] if it is `'static`.
+pub struct AnyValue<'a, T: ?Sized>(pub &'a T);
+
+/// Returns values which are `'static`.
+pub trait AnyValueStatic<'a> {
+    fn as_any(&self) -> Option<&'a dyn Any>;
+}
+impl<'a, T: Any> AnyValueStatic<'a> for &AnyValue<'a, T> {
+    fn as_any(&self) -> Option<&'a dyn Any> {
+        Some(self.0)
+    }
+}
+
+/// Fallback for values with a lifetime.
+pub trait AnyValueNothing<'a> {
+    fn as_any(&self) -> Option<&'a dyn Any>;
+}
+impl<'a, T: ?Sized> AnyValueNothing<'a> for AnyValue<'a, T> {
+    fn as_any(&self) -> Option<&'a dyn Any> {
+        None
+    }
+}
+
+/// Parses `value` into `field` if `path` is empty.
+pub fn set_from_str<T: FromStr>(field: &mut T, path: &str, value: &str) -> Result<(), String>
+where
+    T::Err: Display,
+{
+    if !path.is_empty() {
+        return Err(no_field(path));
+    }
+    *field = value.parse().map_err(|e: T::Err| e.to_string())?;
+    Ok(())
+}
+
+/// Id of the values remembered for the variants of the enum shown with `id`.
+fn remembered_variants_id(id: egui::Id) -> egui::Id {
+    id.with("egui_field_editor::remembered_variants")
+}
+
+/// Removes and returns the value remembered by [`remember_variant`] for the variant matching
+/// `is_variant` of the enum shown with `id`, see `#[inspect(remember_variants)]`.
+pub fn take_remembered_variant<T: Clone + Send + Sync + 'static>(
+    ui: &Ui,
+    id: egui::Id,
+    is_variant: impl Fn(&T) -> bool,
+) -> Option<T> {
+    ui.data_mut(|d| {
+        let values = d.get_temp_mut_or_default::<Vec<T>>(remembered_variants_id(id));
+        let index = values.iter().position(is_variant)?;
+        Some(values.remove(index))
+    })
+}
+
+/// Remembers `value`, the previous value of the enum shown with `id`, replacing the value
+/// remembered for the same variant.
+pub fn remember_variant<T: Clone + Send + Sync + 'static>(ui: &Ui, id: egui::Id, value: T) {
+    ui.data_mut(|d| {
+        let values = d.get_temp_mut_or_default::<Vec<T>>(remembered_variants_id(id));
+        values.retain(|remembered| std::mem::discriminant(remembered) != std::mem::discriminant(&value));
+        values.push(value);
+    })
+}
+
+/// Error returned when no field matches `path`.
+pub fn no_field(path: &str) -> String {
+    format!("No field `{path}`")
+}
+
+/// Error returned when an enum has no variant named `name`.
+pub fn no_variant(name: &str) -> String {
+    format!("No variant `{name}`")
+}
+
+/// Error returned by [`EguiInspect::copy_field_from`] for the types which can't be copied.
+pub fn not_copyable(path: &str) -> String {
+    if path.is_empty() {
+        "This field can't be copied".to_owned()
+    } else {
+        no_field(path)
+    }
+}
+
+/// Splits the first field name from `path`, e.g. `hosts[2].port` gives `hosts` and `[2].port`.
+pub fn split_field(path: &str) -> Option<(&str, &str)> {
+    let end = path.find(['.', '[']).unwrap_or(path.len());
+    if end == 0 {
+        return None;
+    }
+    let rest = &path[end..];
+    Some((&path[..end], rest.strip_prefix('.').unwrap_or(rest)))
+}
+
+/// Splits the first index from `path`, e.g. `[2].port` gives `2` and `port`.
+pub fn split_index(path: &str) -> Option<(usize, &str)> {
+    let (index, rest) = path.strip_prefix('[')?.split_once(']')?;
+    Some((index.trim().parse().ok()?, rest.strip_prefix('.').unwrap_or(rest)))
+}
+
+/// Path of the field `field` of the object at `path`.
+pub fn join_path(path: &str, field: &str) -> String {
+    if path.is_empty() {
+        field.to_owned()
+    } else {
+        format!("{path}.{field}")
+    }
+}
+
+/// Gives the error formatter of `from_string` fields: the [`Display`](std::fmt::Display)
+/// implementation of [`FromStr::Err`](std::str::FromStr::Err) if any.
+pub struct ParseError<T>(PhantomData<T>);
+impl<T> ParseError<T> {
+    pub fn of(_value: &T) -> Self {
+        Self(PhantomData)
+    }
+}
+
+/// Formats errors implementing [`Display`](std::fmt::Display).
+pub trait ParseErrorDisplay {
+    type Err;
+    fn formatter(&self) -> fn(&Self::Err) -> String;
+}
+impl<T: FromStr> ParseErrorDisplay for &ParseError<T>
+where
+    T::Err: Display,
+{
+    type Err = T::Err;
+    fn formatter(&self) -> fn(&T::Err) -> String {
+        |e| e.to_string()
+    }
+}
+
+/// Fallback for errors which can't be displayed.
+pub trait ParseErrorGeneric {
+    type Err;
+    fn formatter(&self) -> fn(&Self::Err) -> String;
+}
+impl<T: FromStr> ParseErrorGeneric for ParseError<T> {
+    type Err = T::Err;
+    fn formatter(&self) -> fn(&T::Err) -> String {
+        crate::invalid_format
+    }
+}
+
+/// Gives the functions of the [`FieldMenu`] of a field.
+pub struct MenuField<T>(PhantomData<T>);
+impl<T> MenuField<T> {
+    pub fn of(_value: &T) -> Self {
+        Self(PhantomData)
+    }
+    /// Used by the derived [`EguiInspect::default_value`], without a value of the type.
+    pub fn of_type() -> Self {
+        Self(PhantomData)
+    }
+}
+
+/// Copies values implementing `serde::Serialize`, as RON.
+pub trait MenuSerialize {
+    type Value;
+    fn serializer(&self) -> Option<CopyFn<Self::Value>>;
+}
+#[cfg(feature = "serde")]
+impl<T: serde::Serialize> MenuSerialize for &MenuField<T> {
+    type Value = T;
+    fn serializer(&self) -> Option<CopyFn<T>> {
+        Some(|value| crate::Format::Ron.serialize(value))
+    }
+}
+
+/// Fallback for values which can't be copied.
+pub trait MenuSerializeNothing {
+    type Value;
+    fn serializer(&self) -> Option<CopyFn<Self::Value>>;
+}
+impl<T> MenuSerializeNothing for MenuField<T> {
+    type Value = T;
+    fn serializer(&self) -> Option<CopyFn<T>> {
+        None
+    }
+}
+
+/// Pastes values implementing `serde::de::DeserializeOwned`, from RON or JSON.
+pub trait MenuDeserialize {
+    type Value;
+    fn deserializer(&self) -> Option<PasteFn<Self::Value>>;
+}
+#[cfg(feature = "serde")]
+impl<T: serde::de::DeserializeOwned> MenuDeserialize for &MenuField<T> {
+    type Value = T;
+    fn deserializer(&self) -> Option<PasteFn<T>> {
+        Some(|text| {
+            crate::Format::Ron
+                .deserialize(text)
+                .or_else(|e| crate::Format::Json.deserialize(text).map_err(|_| e))
+        })
+    }
+}
+
+/// Fallback for values which can't be pasted.
+pub trait MenuDeserializeNothing {
+    type Value;
+    fn deserializer(&self) -> Option<PasteFn<Self::Value>>;
+}
+impl<T> MenuDeserializeNothing for MenuField<T> {
+    type Value = T;
+    fn deserializer(&self) -> Option<PasteFn<T>> {
+        None
+    }
+}
+
+/// Resets values implementing [`Default`].
+pub trait MenuDefault {
+    type Value;
+    fn default_fn(&self) -> Option<fn() -> Self::Value>;
+}
+impl<T: Default> MenuDefault for &MenuField<T> {
+    type Value = T;
+    fn default_fn(&self) -> Option<fn() -> T> {
+        Some(T::default)
+    }
+}
+
+/// Fallback for values without default.
+pub trait MenuDefaultNothing {
+    type Value;
+    fn default_fn(&self) -> Option<fn() -> Self::Value>;
+}
+impl<T> MenuDefaultNothing for MenuField<T> {
+    type Value = T;
+    fn default_fn(&self) -> Option<fn() -> T> {
+        None
+    }
+}
@@ -0,0 +1,197 @@
+use std::collections::BTreeSet;
+
+use egui::{Id, Response, Ui, Widget};
+
+use crate::{context::InspectContext, diff_paths, EguiDisplay, EguiInspect, InspectResponse};
+
+/// Width of the column of the copy buttons, between the two objects.
+const GUTTER_WIDTH: f32 = 24.;
+
+/// State of a column of an [`EguiDiffInspector`] while it is rendered.
+#[derive(Clone, Default)]
+pub(crate) struct DiffColumn {
+    /// `true` to collapse the headers of the fields which are identical in both objects.
+    pub(crate) collapse: bool,
+    /// Path and top of the labels of the differing fields, if the column has copy buttons.
+    rows: Option<Vec<(String, f32)>>,
+}
+
+/// A widget comparing two objects side by side, e.g. a saved preset and the live values.
+///
+/// Both objects are shown read-only with the layout of an [`EguiInspector`](crate::EguiInspector),
+/// the fields which differ (see [`EguiInspect::diff`]) are highlighted and the headers of the
+/// identical fields are collapsed when the differences change. A button next to each differing
+/// field copies it from the left object to the right one with [`EguiInspect::copy_field_from`].
+///
+/// # Examples
+///
+/// ```rust
+/// use egui_field_editor::{EguiDiffInspector, EguiInspect};
+/// #[derive(EguiInspect, Clone, Default)]
+/// struct Tuning {
+///     gain: f32,
+///     filter: bool,
+/// }
+/// fn compare_ui(preset: &Tuning, live: &mut Tuning, ui: &mut egui::Ui) {
+///     if ui.add(EguiDiffInspector::new(preset, live).with_titles("Preset", "Live")).changed() {
+///         // a field of the preset was copied to the live values
+///     }
+/// }
+/// ```
+pub struct EguiDiffInspector<'a, T> {
+    left: &'a T,
+    right: &'a mut T,
+    titles: Option<(String, String)>,
+    id_salt: Option<Id>,
+}
+
+impl<'a, T: EguiInspect + EguiDisplay> EguiDiffInspector<'a, T> {
+    /// Creates a widget comparing `left` and `right`, whose fields can be copied from `left`.
+    pub fn new(left: &'a T, right: &'a mut T) -> Self {
+        Self {
+            left,
+            right,
+            titles: None,
+            id_salt: None,
+        }
+    }
+    /// A source for the unique [`egui::Id`], e.g. `.id_salt("diff")` or `.id_salt(loop_index)`.
+    #[inline]
+    pub fn id_salt(mut self, id_salt: impl std::hash::Hash) -> Self {
+        self.id_salt = Some(Id::new(id_salt));
+        self
+    }
+    /// Set the titles shown above the two objects.
+    #[inline]
+    pub fn with_titles(mut self, left: &str, right: &str) -> Self {
+        self.titles = Some((left.to_owned(), right.to_owned()));
+        self
+    }
+
+    /// Renders the two objects and returns an [`InspectResponse`] marked as changed when a
+    /// field was copied to the right object.
+    pub fn show(self, ui: &mut Ui) -> InspectResponse {
+        let id = self.id_salt.unwrap_or_else(|| Id::new("egui_field_editor::diff"));
+        let key = ui.make_persistent_id(("egui_field_editor::diff", self.id_salt));
+        let error_key = key.with("error");
+        let diffs: BTreeSet<String> = diff_paths(self.left, &*self.right).into_iter().collect();
+        // the headers can be expanded by the user until the differences change
+        let diffs_id = Id::new(&diffs);
+        let collapse = ui.data_mut(|d| {
+            let changed = d.get_temp::<Id>(key) != Some(diffs_id);
+            d.insert_temp(key, diffs_id);
+            changed
+        });
+        let (left_title, right_title) = match &self.titles {
+            Some((left, right)) => (Some(left.as_str()), Some(right.as_str())),
+            None => (None, None),
+        };
+        let copy_text = match &self.titles {
+            Some((left, right)) => format!("Copy {left} → {right}"),
+            None => "Copy left → right".to_owned(),
+        };
+
+        if let Some(error) = ui.data(|d| d.get_temp::<String>(error_key)) {
+            ui.colored_label(ui.visuals().error_fg_color, format!("❌ {error}"));
+        }
+        let mut copied = None;
+        egui::ScrollArea::vertical().id_salt(id).show(ui, |ui| {
+            let column_width =
+                100.0f32.max((ui.available_width() - GUTTER_WIDTH) / 2. - ui.spacing().item_spacing.x);
+            ui.horizontal_top(|ui| {
+                let rows = column(ui, column_width, left_title, &diffs, collapse, true, |ui| {
+                    self.left.display_with_custom_id(id.with("left"), "", "", ui)
+                });
+                let gutter = ui.allocate_space(egui::vec2(GUTTER_WIDTH, 0.)).1;
+                column(ui, column_width, right_title, &diffs, collapse, false, |ui| {
+                    self.right.display_with_custom_id(id.with("right"), "", "", ui)
+                });
+                for (path, top) in rows {
+                    let rect = egui::Rect::from_min_size(
+                        egui::pos2(gutter.left(), top),
+                        egui::vec2(GUTTER_WIDTH, ui.spacing().interact_size.y),
+                    );
+                    if ui.put(rect, egui::Button::new("➡").small()).on_hover_text(&copy_text).clicked() {
+                        copied = Some(path);
+                    }
+                }
+            });
+        });
+
+        let mut response = InspectResponse::default();
+        if let Some(path) = copied {
+            match self.right.copy_field_from(self.left, &path) {
+                Ok(()) => {
+                    ui.data_mut(|d| d.remove::<String>(error_key));
+                    response.mark_changed();
+                }
+                Err(error) => {
+                    ui.data_mut(|d| d.insert_temp(error_key, error));
+                }
+            }
+            ui.ctx().request_repaint();
+        }
+        response
+    }
+}
+
+impl<'a, T: EguiInspect + EguiDisplay> Widget for EguiDiffInspector<'a, T> {
+    /// The returned [`Response::changed`] is `true` when a field was copied. Its rect is the one taken by the widget.
+    fn ui(self, ui: &mut Ui) -> Response {
+        let scope = ui.scope(|ui| self.show(ui));
+        let mut response = scope.response;
+        if scope.inner.changed() {
+            response.mark_changed();
+        }
+        response
+    }
+}
+
+/// Renders a column of an [`EguiDiffInspector`] with `add_contents`, highlighting the `diffs`.
+///
+/// Returns the path and top of the labels of the differing fields if `copy_buttons` is `true`.
+fn column(
+    ui: &mut Ui,
+    width: f32,
+    title: Option<&str>,
+    diffs: &BTreeSet<String>,
+    collapse: bool,
+    copy_buttons: bool,
+    add_contents: impl FnOnce(&mut Ui),
+) -> Vec<(String, f32)> {
+    let layout = egui::Layout::top_down(egui::Align::Min);
+    ui.allocate_ui_with_layout(egui::vec2(width, 0.), layout, |ui| {
+        ui.set_width(width);
+        if let Some(title) = title {
+            ui.strong(title);
+        }
+        InspectContext::scope(ui, Some(diffs.clone()), |ui| {
+            InspectContext::with(ui.ctx(), |context| {
+                context.diff = Some(DiffColumn {
+                    collapse,
+                    rows: copy_buttons.then(Vec::new),
+                });
+            });
+            add_contents(ui);
+            InspectContext::with(ui.ctx(), |context| context.diff.take().and_then(|diff| diff.rows))
+        })
+        .unwrap_or_default()
+    })
+    .inner
+}
+
+/// Records the position of the label of the field being rendered, if it differs and a copy
+/// button is shown for it.
+pub(crate) fn record_label(ui: &Ui) {
+    let top = ui.cursor().top();
+    InspectContext::with(ui.ctx(), |context| {
+        let modified = context.is_path_modified();
+        let path = context.path().to_owned();
+        let Some(rows) = context.diff.as_mut().and_then(|diff| diff.rows.as_mut()) else {
+            return;
+        };
+        if modified && !path.is_empty() && !rows.iter().any(|(row_path, _)| *row_path == path) {
+            rows.push((path, top));
+        }
+    });
+}
@@ -0,0 +1,106 @@
+use std::sync::{Arc, Mutex};
+
+use egui::{Id, Ui};
+
+use crate::{search, EguiInspect, InspectResponse};
+
+/// Renders a draft, see [`EguiInspector::draft`](crate::EguiInspector::draft).
+pub(crate) type ShowDraft<T> = fn(&mut T, DraftOptions<T>, &mut Ui) -> InspectResponse;
+
+/// Settings of the [`EguiInspector`](crate::EguiInspector) used by a draft.
+pub(crate) struct DraftOptions<T> {
+    pub(crate) id_salt: Option<Id>,
+    pub(crate) default: Option<fn() -> T>,
+    pub(crate) read_only: bool,
+    pub(crate) min_width: f32,
+    /// Query of the search field, see [`crate::search`].
+    pub(crate) query: String,
+}
+
+/// State of a draft, kept in `egui` memory between frames.
+struct DraftState<T> {
+    /// Value of the edited object when the draft was last synchronized with it.
+    committed: T,
+    /// The copy being edited.
+    draft: T,
+}
+
+/// Shows the Apply/Revert/Reset buttons and inspects the draft of `obj`.
+///
+/// Returns an [`InspectResponse`] marked as changed when the draft is applied to `obj`. `T` is
+/// `Send + 'static` to store the [`DraftState`] in `egui` memory.
+pub(crate) fn show<T>(obj: &mut T, options: DraftOptions<T>, ui: &mut Ui) -> InspectResponse
+where
+    T: EguiInspect + Clone + PartialEq + Send + 'static,
+{
+    let key = ui.make_persistent_id(("egui_field_editor::draft", options.id_salt));
+    let state = ui.data_mut(|d| {
+        d.get_temp_mut_or_insert_with(key, || {
+            Arc::new(Mutex::new(DraftState {
+                committed: obj.clone(),
+                draft: obj.clone(),
+            }))
+        })
+        .clone()
+    });
+    let mut state = state.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+    if state.committed != *obj {
+        // Modified outside of the draft (or undone): follow it unless there are pending edits
+        if state.draft == state.committed {
+            state.draft = obj.clone();
+        }
+        state.committed = obj.clone();
+    }
+
+    let mut response = InspectResponse::default();
+    let modified = state.draft != *obj;
+    let (apply, revert, reset) = ui
+        .add_enabled_ui(!options.read_only, |ui| {
+            ui.horizontal(|ui| {
+                let apply = ui
+                    .add_enabled(modified, egui::Button::new("✔ Apply"))
+                    .clicked();
+                let revert = ui
+                    .add_enabled(modified, egui::Button::new("↩ Revert"))
+                    .on_hover_text("Discard the pending changes")
+                    .clicked();
+                let reset = options.default.is_some() && ui.button("Reset to default").clicked();
+                (apply, revert, reset)
+            })
+            .inner
+        })
+        .inner;
+    if let (true, Some(default)) = (reset, options.default) {
+        state.draft = default();
+    }
+    if revert {
+        state.draft = obj.clone();
+    }
+    if apply {
+        *obj = state.draft.clone();
+        state.committed = obj.clone();
+        response.mark_changed();
+    }
+
+    let modified_fields = crate::diff_paths(&*obj, &state.draft).into_iter().collect();
+    let id = options.id_salt.unwrap_or(Id::NULL);
+    let mut fields_response = egui::ScrollArea::vertical()
+        .show(ui, |ui| {
+            ui.set_min_width(options.min_width);
+            search::scope(ui, id, Some(modified_fields), &options.query, |ui, id| {
+                if id == Id::NULL {
+                    state.draft.inspect("", "", options.read_only, ui)
+                } else {
+                    state.draft.inspect_with_custom_id(id, "", "", options.read_only, ui)
+                }
+            })
+        })
+        .inner;
+    if fields_response.changed() {
+        // the highlighted fields are computed before rendering
+        ui.ctx().request_repaint();
+    }
+    // editing the draft doesn't modify the object
+    fields_response.changed = false;
+    response | fields_response
+}
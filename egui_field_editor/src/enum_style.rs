@@ -0,0 +1,83 @@
+//! Presentations of the variant selector of the derived enums, see the `style` attribute.
+
+use egui::{Id, Ui};
+
+use crate::multi::is_mixed;
+
+/// How the variant of a derived enum is picked (`#[inspect(style = "...")]`).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum EnumStyle {
+    /// A combo box listing the variants.
+    #[default]
+    Combo,
+    /// A radio button per variant.
+    Radio,
+    /// A row of buttons, the button of the current variant being selected.
+    Toggle,
+    /// A tab strip below the label, above the fields of the current variant.
+    Tabs,
+}
+
+/// Shows the variant selector of an enum. `add_items` adds the item of each variant with
+/// [`variant_item`].
+///
+/// `selected_text` is the label of the current variant, shown by the combo box.
+pub fn variant_selector(
+    style: EnumStyle,
+    id: Id,
+    selected_text: &str,
+    field_width: f32,
+    ui: &mut Ui,
+    add_items: impl FnOnce(&mut Ui),
+) {
+    match style {
+        EnumStyle::Combo => {
+            egui::ComboBox::from_id_salt(id)
+                .width(field_width)
+                .selected_text(selected_text)
+                .show_ui(ui, add_items);
+        }
+        EnumStyle::Radio => {
+            ui.horizontal_wrapped(|ui| {
+                ui.set_max_width(field_width);
+                add_items(ui);
+            });
+        }
+        EnumStyle::Toggle => {
+            ui.horizontal(|ui| {
+                // the buttons are joined into a segmented control
+                ui.spacing_mut().item_spacing.x = 1.;
+                add_items(ui);
+            });
+        }
+        EnumStyle::Tabs => {
+            ui.horizontal_wrapped(add_items);
+            ui.separator();
+        }
+    }
+}
+
+/// Adds the item of a variant to a [`variant_selector`], returns `true` if it was clicked.
+///
+/// No item is shown selected if the edited objects have different variants (see [`is_mixed`]).
+/// `tooltip` (if not empty) is shown when the item is hovered.
+pub fn variant_item(style: EnumStyle, selected: bool, label: &str, tooltip: &str, ui: &mut Ui) -> bool {
+    let selected = selected && (style == EnumStyle::Combo || !is_mixed(ui));
+    let response = match style {
+        EnumStyle::Combo => ui.selectable_label(selected, label),
+        EnumStyle::Radio => ui.radio(selected, label),
+        EnumStyle::Toggle => ui.add(egui::Button::new(label).selected(selected)),
+        EnumStyle::Tabs => {
+            let response = ui.selectable_label(selected, label);
+            if selected {
+                // underlines the current tab
+                let rect = response.rect;
+                let stroke = egui::Stroke::new(2., ui.visuals().selection.stroke.color);
+                ui.painter().hline(rect.x_range(), rect.bottom(), stroke);
+            }
+            response
+        }
+    };
+    let response = if tooltip.is_empty() { response } else { response.on_hover_text(tooltip) };
+    response.clicked()
+}
@@ -0,0 +1,195 @@
+//! Context menu of the field labels: "Copy value", "Paste value" and "Reset to default".
+//!
+//! The code generated by `#[derive(EguiInspect)]` knows the type of each field: it registers a
+//! [`FieldMenu`] before rendering a field, the labels drawn for this field show the menu (see
+//! [`add_field_menu`]), and the selected action is applied by [`FieldMenu::apply`] once the
+//! field is rendered.
+//!
+//! `egui` can write the system clipboard but not read it: the text pasted with `Ctrl+V` (an
+//! [`egui::Event::Paste`]) is kept as the clipboard content, along with the text of the last
+//! "Copy value". Pressing `Ctrl+V` while the menu of a field is open pastes into this field.
+
+use egui::{Id, Response, Ui};
+
+use crate::{context::InspectContext, InspectResponse};
+
+/// Action selected in the context menu of a field.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum MenuAction {
+    Copy,
+    Paste,
+    Reset,
+}
+
+/// Actions available in the context menu of a field.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct MenuEntry {
+    /// Identifies the [`FieldMenu`] applying the selected action.
+    pub(crate) token: u64,
+    copy: bool,
+    paste: bool,
+    reset: bool,
+}
+
+/// How long a paste error stays visible, in seconds.
+const ERROR_DURATION: f64 = 3.0;
+
+fn clipboard_id() -> Id {
+    Id::new("egui_field_editor::clipboard")
+}
+
+fn error_id(path: &str) -> Id {
+    Id::new(("egui_field_editor::paste_error", path))
+}
+
+/// Serializes a value for "Copy value".
+pub type CopyFn<T> = fn(&T) -> Result<String, String>;
+/// Deserializes a value for "Paste value".
+pub type PasteFn<T> = fn(&str) -> Result<T, String>;
+
+/// Functions used by the context menu of a field of type `T`.
+///
+/// Each action is only offered when the corresponding function is available: copying needs
+/// `serde::Serialize`, pasting `serde::de::DeserializeOwned` (both with the `serde` feature)
+/// and resetting [`Default`].
+pub struct FieldMenu<T> {
+    serialize: Option<CopyFn<T>>,
+    deserialize: Option<PasteFn<T>>,
+    default: Option<fn() -> T>,
+    token: Option<u64>,
+}
+
+impl<T> FieldMenu<T> {
+    /// Menu offering the actions whose function is given.
+    pub fn new(serialize: Option<CopyFn<T>>, deserialize: Option<PasteFn<T>>, default: Option<fn() -> T>) -> Self {
+        Self {
+            serialize,
+            deserialize,
+            default,
+            token: None,
+        }
+    }
+    /// Makes the menu available for the labels of the field being rendered.
+    pub fn register(mut self, ui: &Ui) -> Self {
+        let entry = MenuEntry {
+            token: 0,
+            copy: self.serialize.is_some(),
+            paste: self.deserialize.is_some(),
+            reset: self.default.is_some(),
+        };
+        if entry.copy || entry.paste || entry.reset {
+            self.token = Some(InspectContext::with(ui.ctx(), |context| context.register_menu(entry)));
+        }
+        self
+    }
+    /// Applies the action selected in the menu of this field, if any.
+    ///
+    /// Returns an [`InspectResponse`] marked as changed if `value` was pasted or reset.
+    pub fn apply(&self, value: &mut T, ui: &Ui) -> InspectResponse {
+        let mut response = InspectResponse::default();
+        let Some(token) = self.token else {
+            return response;
+        };
+        let Some(action) = InspectContext::with(ui.ctx(), |context| context.take_pending_action(token)) else {
+            return response;
+        };
+        match (action, self.serialize, self.deserialize, self.default) {
+            (MenuAction::Copy, Some(serialize), _, _) => match serialize(value) {
+                Ok(text) => {
+                    ui.ctx().copy_text(text.clone());
+                    ui.data_mut(|d| d.insert_temp(clipboard_id(), text));
+                }
+                Err(e) => set_error(e, ui),
+            },
+            (MenuAction::Paste, _, Some(deserialize), _) => {
+                let text = clipboard_text(ui).unwrap_or_default();
+                match deserialize(&text) {
+                    Ok(pasted) => {
+                        *value = pasted;
+                        response.mark_changed();
+                    }
+                    Err(e) => set_error(e, ui),
+                }
+            }
+            (MenuAction::Reset, _, _, Some(default)) => {
+                *value = default();
+                response.mark_changed();
+            }
+            _ => {}
+        }
+        response
+    }
+}
+
+/// Last text copied with "Copy value" or pasted from the system clipboard, see [`add_field_menu`].
+fn clipboard_text(ui: &Ui) -> Option<String> {
+    ui.data(|d| d.get_temp::<String>(clipboard_id()))
+}
+
+/// Text pasted from the system clipboard during this frame, if any.
+fn pasted_text(ui: &Ui) -> Option<String> {
+    ui.input(|i| {
+        i.events.iter().rev().find_map(|event| match event {
+            egui::Event::Paste(text) => Some(text.clone()),
+            _ => None,
+        })
+    })
+}
+
+/// Shows `error` in a tooltip on the labels of the field being rendered.
+fn set_error(error: String, ui: &Ui) {
+    let id = InspectContext::with(ui.ctx(), |context| error_id(context.path()));
+    let until = ui.input(|i| i.time) + ERROR_DURATION;
+    ui.data_mut(|d| d.insert_temp(id, (error, until)));
+}
+
+/// Adds the context menu of the field being rendered to `response`, if a [`FieldMenu`] was
+/// registered for it. Only "Copy value" is offered if `read_only` is `true`.
+///
+/// "Paste value" uses the text pasted from the system clipboard with `Ctrl+V` (e.g. copied in
+/// another application), or else the last value copied with "Copy value".
+pub fn add_field_menu(response: &Response, read_only: bool, ui: &Ui) {
+    let pasted = pasted_text(ui);
+    if let Some(text) = &pasted {
+        ui.data_mut(|d| d.insert_temp(clipboard_id(), text.clone()));
+    }
+    let (menu, error_id) = InspectContext::with(ui.ctx(), |context| (context.menu(), error_id(context.path())));
+    // no menu for the invisible pass of a search
+    let Some(menu) = menu.filter(|_| ui.is_visible()) else {
+        return;
+    };
+    if let Some((error, until)) = ui.data(|d| d.get_temp::<(String, f64)>(error_id)) {
+        let now = ui.input(|i| i.time);
+        if now < until {
+            response.show_tooltip_text(
+                egui::RichText::new(format!("❌ {error}")).color(ui.visuals().error_fg_color),
+            );
+            ui.ctx().request_repaint_after_secs((until - now) as f32);
+        } else {
+            ui.data_mut(|d| d.remove::<(String, f64)>(error_id));
+        }
+    }
+    let can_paste = clipboard_text(ui).is_some();
+    let mut action = None;
+    response.context_menu(|ui| {
+        if menu.copy && ui.button("Copy value").clicked() {
+            action = Some(MenuAction::Copy);
+        }
+        if menu.paste && !read_only {
+            let paste = ui
+                .add_enabled(can_paste, egui::Button::new("Paste value"))
+                .on_hover_text("Ctrl+V pastes the content of the clipboard")
+                .on_disabled_hover_text("Press Ctrl+V to paste the content of the clipboard");
+            if paste.clicked() || pasted.is_some() {
+                action = Some(MenuAction::Paste);
+                ui.close();
+            }
+        }
+        if menu.reset && !read_only && ui.button("Reset to default").clicked() {
+            action = Some(MenuAction::Reset);
+        }
+    });
+    if let Some(action) = action {
+        InspectContext::with(ui.ctx(), |context| context.set_pending_action(menu.token, action));
+    }
+}
@@ -0,0 +1,240 @@
+use std::collections::VecDeque;
+
+use egui::{Key, KeyboardShortcut, Modifiers, Ui};
+
+use crate::InspectResponse;
+
+/// Undo/redo stack for the edits made through an [`EguiInspector`](crate::EguiInspector).
+///
+/// The history stores snapshots of the whole inspected object. A snapshot is recorded once an
+/// edit gesture is over (slider or drag value released, text field losing focus, list item
+/// dropped...) so that a whole gesture is a single undo step.
+///
+/// The history must outlive the inspector, so keep it next to the inspected data:
+///
+/// ```rust
+/// use egui_field_editor::{EguiInspect, EguiInspector, InspectorHistory};
+/// #[derive(EguiInspect, Clone, Default)]
+/// struct Settings {
+///     volume: f32,
+///     names: Vec<String>,
+/// }
+/// struct App {
+///     settings: Settings,
+///     history: InspectorHistory<Settings>,
+/// }
+/// impl App {
+///     fn ui(&mut self, ui: &mut egui::Ui) {
+///         ui.add(EguiInspector::new(&mut self.settings).with_history(&mut self.history));
+///     }
+/// }
+/// ```
+///
+/// Undo and redo are available through the buttons shown above the inspected fields or with
+/// `Ctrl+Z` and `Ctrl+Shift+Z`/`Ctrl+Y` while no text field has the keyboard focus.
+///
+/// If the object is modified outside of the inspector, call [`Self::clear`] so that the
+/// history does not bring back a stale state.
+#[derive(Clone, Debug)]
+pub struct InspectorHistory<T> {
+    undo: VecDeque<T>,
+    redo: Vec<T>,
+    /// Last recorded state of the object, i.e. the state an undo goes back to.
+    current: Option<T>,
+    max_depth: usize,
+    /// `true` if the object was edited since `current` was recorded.
+    pending: bool,
+}
+
+impl<T> Default for InspectorHistory<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> InspectorHistory<T> {
+    /// Default value of [`Self::max_depth`].
+    pub const DEFAULT_MAX_DEPTH: usize = 100;
+
+    /// Creates an empty history keeping at most [`Self::DEFAULT_MAX_DEPTH`] undo steps.
+    pub fn new() -> Self {
+        Self::with_max_depth(Self::DEFAULT_MAX_DEPTH)
+    }
+    /// Creates an empty history keeping at most `max_depth` undo steps.
+    pub fn with_max_depth(max_depth: usize) -> Self {
+        Self {
+            undo: VecDeque::new(),
+            redo: Vec::new(),
+            current: None,
+            max_depth,
+            pending: false,
+        }
+    }
+    /// Maximum number of undo steps kept in memory.
+    #[inline]
+    pub fn max_depth(&self) -> usize {
+        self.max_depth
+    }
+    /// Changes the maximum number of undo steps, dropping the oldest ones if needed.
+    pub fn set_max_depth(&mut self, max_depth: usize) {
+        self.max_depth = max_depth;
+        self.undo.truncate(max_depth);
+    }
+    /// Number of steps that can be undone.
+    #[inline]
+    pub fn undo_len(&self) -> usize {
+        self.undo.len()
+    }
+    /// Number of steps that can be redone.
+    #[inline]
+    pub fn redo_len(&self) -> usize {
+        self.redo.len()
+    }
+    /// `true` if there is something to undo.
+    #[inline]
+    pub fn can_undo(&self) -> bool {
+        !self.undo.is_empty() || self.pending
+    }
+    /// `true` if there is something to redo.
+    #[inline]
+    pub fn can_redo(&self) -> bool {
+        !self.redo.is_empty()
+    }
+    /// Forgets every recorded step.
+    ///
+    /// The current state of the object will be recorded as the new starting point the next
+    /// time the inspector is shown.
+    pub fn clear(&mut self) {
+        self.undo.clear();
+        self.redo.clear();
+        self.current = None;
+        self.pending = false;
+    }
+}
+
+impl<T: Clone> InspectorHistory<T> {
+    /// Restores the state preceding the last recorded edit.
+    ///
+    /// Returns `false` if there was nothing to undo.
+    pub fn undo(&mut self, obj: &mut T) -> bool {
+        self.commit(obj);
+        let Some(previous) = self.undo.pop_front() else {
+            return false;
+        };
+        if let Some(current) = self.current.replace(previous.clone()) {
+            self.redo.push(current);
+        }
+        *obj = previous;
+        true
+    }
+    /// Restores the state undone by the last call to [`Self::undo`].
+    ///
+    /// Returns `false` if there was nothing to redo.
+    pub fn redo(&mut self, obj: &mut T) -> bool {
+        self.commit(obj);
+        let Some(next) = self.redo.pop() else {
+            return false;
+        };
+        if let Some(current) = self.current.replace(next.clone()) {
+            self.undo.push_front(current);
+        }
+        *obj = next;
+        true
+    }
+
+    /// Records the edits reported by `response` once the current gesture is over.
+    pub(crate) fn record(&mut self, obj: &T, response: InspectResponse, ui: &Ui) {
+        if response.changed() {
+            self.pending = true;
+        }
+        if is_gesture_over(response, ui) {
+            self.commit(obj);
+        }
+    }
+
+    /// Pushes the last recorded state on the undo stack if the object was edited since.
+    fn commit(&mut self, obj: &T) {
+        if !self.pending {
+            return;
+        }
+        self.pending = false;
+        if let Some(previous) = self.current.replace(obj.clone()) {
+            self.undo.push_front(previous);
+            self.undo.truncate(self.max_depth);
+        }
+        self.redo.clear();
+    }
+
+    /// Shows the undo/redo buttons and handles the keyboard shortcuts.
+    ///
+    /// Must be called before inspecting `obj`. Returns an [`InspectResponse`] marked as
+    /// changed if `obj` was modified.
+    pub(crate) fn toolbar(&mut self, obj: &mut T, read_only: bool, ui: &mut Ui) -> InspectResponse {
+        if self.current.is_none() {
+            self.current = Some(obj.clone());
+        }
+        let mut response = InspectResponse::default();
+        let (mut undo, mut redo) = ui
+            .add_enabled_ui(!read_only, |ui| {
+                ui.horizontal(|ui| {
+                    let undo = ui
+                        .add_enabled(self.can_undo(), egui::Button::new("⟲ Undo"))
+                        .on_hover_text("Ctrl+Z")
+                        .clicked();
+                    let redo = ui
+                        .add_enabled(self.can_redo(), egui::Button::new("⟳ Redo"))
+                        .on_hover_text("Ctrl+Shift+Z / Ctrl+Y")
+                        .clicked();
+                    (undo, redo)
+                })
+                .inner
+            })
+            .inner;
+        if !read_only && !has_keyboard_focus(ui) {
+            ui.input_mut(|i| {
+                // Most specific shortcut first: Ctrl+Z would also match Ctrl+Shift+Z
+                redo |= i.consume_shortcut(&KeyboardShortcut::new(Modifiers::COMMAND | Modifiers::SHIFT, Key::Z))
+                    || i.consume_shortcut(&KeyboardShortcut::new(Modifiers::COMMAND, Key::Y));
+                undo |= i.consume_shortcut(&KeyboardShortcut::new(Modifiers::COMMAND, Key::Z));
+            });
+        }
+        if undo && self.undo(obj) {
+            response.mark_changed();
+        }
+        if redo && self.redo(obj) {
+            response.mark_changed();
+        }
+        response
+    }
+}
+
+/// `true` once the edit gesture of `response` is over (slider released, text field losing
+/// focus...), or if there is none.
+pub(crate) fn is_gesture_over(response: InspectResponse, ui: &Ui) -> bool {
+    // Popups (color picker...) do not report their drags, so rely on the pointer state
+    // rather than on `drag_stopped` to detect the end of a gesture.
+    let in_gesture = ui.input(|i| i.pointer.any_down())
+        || (has_keyboard_focus(ui) && !response.lost_focus());
+    !in_gesture
+}
+
+/// `true` if a widget (typically a text field) has the keyboard focus.
+fn has_keyboard_focus(ui: &Ui) -> bool {
+    ui.memory(|m| m.focused().is_some())
+}
+
+/// Type erased access to an [`InspectorHistory`], so that [`EguiInspector`](crate::EguiInspector)
+/// does not require `T: Clone` when no history is used.
+pub(crate) trait History<T> {
+    fn toolbar(&mut self, obj: &mut T, read_only: bool, ui: &mut Ui) -> InspectResponse;
+    fn record(&mut self, obj: &T, response: InspectResponse, ui: &Ui);
+}
+
+impl<T: Clone> History<T> for InspectorHistory<T> {
+    fn toolbar(&mut self, obj: &mut T, read_only: bool, ui: &mut Ui) -> InspectResponse {
+        InspectorHistory::toolbar(self, obj, read_only, ui)
+    }
+    fn record(&mut self, obj: &T, response: InspectResponse, ui: &Ui) {
+        InspectorHistory::record(self, obj, response, ui)
+    }
+}
@@ -60,15 +60,29 @@
 //! - `name` *(String)*: Use custom label for the given field instead of the internal field name
 //! - `hidden` *(bool)*: If true, doesn't generate code for the given field
 //! - `read_only` *(bool)*: If true, the field is not editable (and color is grayed)
-//! - `slider` *(min=f32, max=f32)*: If present, use a slider when inspecting numbers
-//! - `range` *(min=f32, max=f32)*: Min/Max value for inspecting numbers
+//! - `slider` *(min=f32, max=f32)*: If present, use a slider when inspecting numbers (a slider with two handles for `Range` and `RangeInclusive`)
+//! - `range` *(min=f32, max=f32)*: Min/Max value for inspecting numbers (and the bounds of `Range` and `RangeInclusive`)
 //! - `multiline` *(optional u8)*: If set, display the text on multiple lines. If affected to a u8, it defines the number of rows to display
-//! - `tooltip` *(String)*: Tooltip to display when cursor is hover
+//! - `tooltip` *(String)*: Tooltip to display when cursor is hover (the doc comment of the field or variant by default)
 //! - `color` *(bool)*: Display the field has a color (field type needs to implement [`From<Color32Wrapper>`]/[`Into<Color32Wrapper>`] - see [`Color32Wrapper`])
-//! - `custom_fn` *(String)*: Use a custom function instead of calling [`EguiInspect::inspect_with_custom_id`]
+//! - `custom_fn` *(String)*: Use a custom function instead of calling [`EguiInspect::inspect_with_custom_id`].
+//!   The function can return an [`InspectResponse`] (or `()` if it does not report edits)
+//! - `display_fn` *(String)*: Use a custom function instead of calling [`EguiDisplay::display_with_custom_id`]
+//!   in the derived [`EguiDisplay`] implementation. Its signature is `fn(&T, &str, &str, &mut egui::Ui)`
+//! - `validate` *(String)*: Function validating the field (`fn(&T) -> Result<(), String>`).
+//!   Errors are shown next to the field and reported by [`validation_errors`]
 //! - `from_string`: *(bool)*: Force edition from string conversion (needs type to implement [`FromStr`] and [`Display`])
 //!
-//!    Compatible with `multiline`.`
+//!   Compatible with `multiline`. The typed text is kept while it can't be parsed, and the error
+//!   of [`FromStr`] is displayed if it implements [`Display`].
+//! - `commit` *("on_valid"|"on_enter")*: When a `from_string` field is updated from the typed text, see [`CommitMode`]
+//! - `min_len` *(usize)*, `max_len` *(usize)*, `fixed_len` *(bool)*: Limits of the length of a `Vec`, the buttons
+//!   adding or removing items beyond them are disabled. Only allowed on a field whose type is written as a `Vec`
+//! - `key` *(optional String)*: Stable key of the items of a `Vec` or array, used for their labels and ids:
+//!   a field of the items, a function path (containing `::`) `fn(&T) -> impl ToString`, or [`InspectKey`] if no value is given.
+//!   Only allowed on a field whose type is written as a `Vec`, an array or a slice
+//! - `default` *(String)*: On an enum variant, expression building the variant when it is picked in the combo box,
+//!   for the variants whose fields don't implement [`Default`] (or shouldn't start from their default value)
 //! - `date` *(DatePickerParams)*: Parameters to customize the Date Picker widget:
 //!   - ```combo_boxes```: *(optional ```bool```)*
 //!     Show combo boxes in date picker popup. (Default: true).
@@ -95,6 +109,60 @@
 //!
 //!     For example, if you want to provide the range of years from 2000 to 2035, you can use: `start_end_years(min=2000, max=2035)`.
 //!
+//! Attributes can also be added to the structure itself:
+//!
+//! - `execute_btn` *(fn_name=String, is_method=bool, label=String, tooltip=String)*: Adds a button calling the given method or function
+//! - `validate_struct` *(String)*: Function validating the whole object (`fn(&Self) -> Result<(), String>`), for cross-field rules.
+//!   Can be repeated.
+//! - `remember_variants` *(bool)*: On an enum, a variant picked again in the combo box gets back the value it had
+//!   instead of a default one (the enum must implement `Clone + Send + Sync + 'static`, the values are kept in the egui memory)
+//! - `style` *("combo"|"radio"|"toggle"|"tabs")*: On an enum, how the variant is picked: a combo box (default), a radio button
+//!   per variant, a row of toggle buttons, or a tab strip above the fields of the variant
+//! - `no_doc_tooltip` *(bool)*: Don't use the doc comments of the type (shown on its header), of its fields and of its variants
+//!   as their default tooltip
+//! - `rename_all` *("Title Case"|"lowercase"|"SCREAMING_SNAKE"|"as-is")*: How the names of the fields and variants without a `name`
+//!   attribute are turned into labels. By default, the fields are in Title Case (`max_speed` gives `Max Speed`) and the variants
+//!   are kept as is. The fields of tuple structs and variants are named `field_0`, `field_1`... (`Field 0` in Title Case),
+//!   and `0`, `1`... with `"as-is"`
+//!
+//! An unknown value of these attributes is a compile error:
+//! ```compile_fail
+//! use egui_field_editor::EguiInspect;
+//! #[derive(EguiInspect)]
+//! #[inspect(style = "dropdown")]
+//! enum Mode {
+//!     Auto,
+//!     Manual,
+//! }
+//! ```
+//! ```compile_fail
+//! use egui_field_editor::EguiInspect;
+//! #[derive(EguiInspect)]
+//! #[inspect(rename_all = "camelCase")]
+//! struct Engine {
+//!     max_speed: u8,
+//! }
+//! ```
+//!
+//! So are the list attributes put on a field which isn't a list, or on a variant:
+//! ```compile_fail
+//! use egui_field_editor::EguiInspect;
+//! #[derive(EguiInspect)]
+//! struct Engine {
+//!     #[inspect(max_len = 4)]
+//!     name: String,
+//! }
+//! ```
+//! ```compile_fail
+//! use egui_field_editor::EguiInspect;
+//! #[derive(EguiInspect)]
+//! enum Gears {
+//!     #[inspect(fixed_len)]
+//!     Manual(Vec<u8>),
+//!     Auto,
+//! }
+//! ```
+//!
 //! # Feature Flags
 //! This crate provides optional features to extend functionality with external libraries. You can enable them selectively to reduce compile time and dependency footprint.
 //! - `nalgebra_glm`: Enables support for inspecting nalgebra-glm types like Vec3, Vec4, etc.
@@ -103,6 +171,18 @@
 //! - `datepicker`: Enables date picker UI using chrono and egui_extras.
 //!
 //!   This adds a dependency to [egui_extras](https://docs.rs/egui_extras/latest/egui_extras/index.html) datepicker feature and to [chrono](https://docs.rs/chrono/latest/chrono/).
+//! - `filepicker`: Adds a button opening a file dialog next to [`std::path::PathBuf`] fields (and a "Save As" button, see `serde`).
+//!
+//!   This adds a dependency to [rfd](https://docs.rs/rfd/latest/rfd/).
+//! - `serde`: Enables [`EguiInspector::with_persistence`] to save and load the inspected object in JSON, RON or TOML (see [`Format`]),
+//!   the "Copy value"/"Paste value" actions of the context menu of the derived fields (fields borrowing data, e.g. `&'static str`,
+//!   can only be copied), and the serialization of [`FieldChange`].
+//!
+//!   This adds a dependency to [serde](https://docs.rs/serde/latest/serde/), [serde_json](https://docs.rs/serde_json/latest/serde_json/),
+//!   [ron](https://docs.rs/ron/latest/ron/) and [toml](https://docs.rs/toml/latest/toml/).
+//! - `indexmap`: Enables support for inspecting `IndexMap`, whose entries can be reordered by drag and drop.
+//!
+//!   This adds a dependency to [indexmap](https://docs.rs/indexmap/latest/indexmap/).
 //! - `all`: A shortcut to activate all features.
 //!
 //!
@@ -115,6 +195,7 @@
 #[cfg(feature = "datepicker")]
 use chrono::NaiveDate;
 use egui::{Color32, Response, Ui, Widget};
+use context::InspectContext;
 #[cfg(feature = "nalgebra_glm")]
 use nalgebra_glm::*;
 use std::{
@@ -130,6 +211,16 @@ use std::{
 
 /// See also [EguiInspect]
 pub use egui_field_editor_derive::*;
+pub use baseline::differs_from_baseline;
+pub use changes::{field_changes, ChangeKind, FieldChange};
+pub use context::{current_field_path, FieldScope};
+pub use diff_view::EguiDiffInspector;
+pub use history::InspectorHistory;
+pub use list::InspectKey;
+pub use multi::is_mixed;
+#[cfg(feature = "serde")]
+pub use persistence::Format;
+pub use range::NumericRange;
 
 /// A wrapper widget that renders an object implementing [`EguiInspect`] inside an `egui` UI.
 ///
@@ -137,9 +228,13 @@ pub use egui_field_editor_derive::*;
 /// implements the [`EguiInspect`] trait. It supports toggling read-only mode and integrates
 /// seamlessly with `egui`'s layout system.
 ///
+/// Objects only available through a shared reference can be shown with [`Self::view`] if
+/// they implement [`EguiDisplay`]. Objects which should only be updated once the user is done
+/// editing them can be shown with [`Self::draft`].
+///
 /// # Type Parameters
 ///
-/// - `T`: The type to inspect, which must implement [`EguiInspect`].
+/// - `T`: The type to inspect, which must implement [`EguiInspect`] (or [`EguiDisplay`] for [`Self::view`]).
 ///
 /// # Examples
 ///
@@ -156,39 +251,270 @@ pub use egui_field_editor_derive::*;
 /// let mut config = MyConfig::default();
 /// let inspector = EguiInspector::new(&mut config);
 /// //ui.add(inspector);
+/// let shared = std::sync::Arc::new(MyConfig::ById(12));
+/// let viewer = EguiInspector::view(&*shared);
+/// //ui.add(viewer);
 /// ```
 ///
 /// # See Also
 ///
 /// - [`EguiInspect`]
+/// - [`EguiDisplay`]
 /// - [`egui::Widget`]
-pub struct EguiInspector<'a, T: EguiInspect> {
-    obj: &'a mut T,
+pub struct EguiInspector<'a, T> {
+    // `T` has no bounds here so that `show` works with any mode: the modes and options needing
+    // bounds (drafts, baselines, persistence...) store function pointers built by the methods
+    // enabling them, where the bounds are known.
+    target: Target<'a, T>,
     title: Option<String>,
     read_only: bool,
     id_salt: Option<egui::Id>,
+    history: Option<&'a mut dyn history::History<T>>,
+    changes: Option<changes::Recorder<'a, T>>,
+    baseline: Option<baseline::Baseline<'a, T>>,
+    default: Option<fn() -> T>,
+    search: bool,
+    page_size: Option<usize>,
+    #[cfg(feature = "serde")]
+    persistence: Option<persistence::Persistence<T>>,
+}
+/// Object rendered by an [`EguiInspector`], with the function used to render it.
+enum Target<'a, T> {
+    Edit(&'a mut T, fn(&mut T, egui::Id, bool, &mut Ui) -> InspectResponse),
+    View(&'a T, fn(&T, egui::Id, &mut Ui)),
+    Draft(&'a mut T, draft::ShowDraft<T>),
+    Multi(Vec<&'a mut T>, multi::ShowMulti<T>),
 }
 impl<'a, T: EguiInspect> EguiInspector<'a, T> {
     /// Creates a new inspector widget for the given object.
     ///
     /// - `obj`: The object to inspect.
     pub fn new(obj: &'a mut T) -> Self {
-        Self {
-            obj,
-            title: None,
-            read_only: false,
-            id_salt: None,
-        }
+        Self::with_target(Target::Edit(obj, |obj, id, read_only, ui| {
+            if id == egui::Id::NULL {
+                obj.inspect("", "", read_only, ui)
+            } else {
+                obj.inspect_with_custom_id(id, "", "", read_only, ui)
+            }
+        }))
     }
     /// Creates a new read only inspector widget for the given object.
     ///
     /// - `obj`: The object to inspect.
+    ///
+    /// See also [`Self::view`] which only needs a shared reference.
     pub fn new_read_only(obj: &'a mut T) -> Self {
+        Self::new(obj).read_only()
+    }
+}
+impl<'a, T: EguiInspect + Clone + PartialEq + Send + 'static> EguiInspector<'a, T> {
+    /// Creates an inspector editing a copy of the given object, which is only written back
+    /// to `obj` when the user clicks "Apply".
+    ///
+    /// The fields differing from `obj` are highlighted. "Revert" discards the pending edits and
+    /// "Reset to default" (see [`Self::with_default`]) loads the default value in the copy.
+    ///
+    /// Unlike [`Self::new`], `T` must be `Send + 'static`: the copy is kept in `egui` memory
+    /// between frames, which only stores such types. Types borrowing data (e.g. a field of type
+    /// `&'a str`) can't be drafted. `Clone` makes the copy and `PartialEq` tells the modified
+    /// fields apart.
+    ///
+    /// Use [`Self::id_salt`] to tell the drafts apart when several are shown in the same [`Ui`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use egui_field_editor::{EguiInspect, EguiInspector};
+    /// #[derive(EguiInspect, Clone, PartialEq, Default)]
+    /// struct RendererSettings {
+    ///     msaa: u8,
+    ///     vsync: bool,
+    /// }
+    /// fn settings_ui(settings: &mut RendererSettings, ui: &mut egui::Ui) {
+    ///     if ui.add(EguiInspector::draft(settings).with_default(RendererSettings::default)).changed() {
+    ///         // restart the renderer
+    ///     }
+    /// }
+    /// ```
+    pub fn draft(obj: &'a mut T) -> Self {
+        Self::with_target(Target::Draft(obj, draft::show::<T>))
+    }
+}
+impl<'a, T: EguiInspect + Clone> EguiInspector<'a, T> {
+    /// Creates an inspector editing several objects at once.
+    ///
+    /// The fields are shown with the values of the first object, the fields whose value differs
+    /// between the objects being shown as mixed ("—", see [`is_mixed`]). Editing a field writes
+    /// its new value to every object with [`EguiInspect::copy_field_from`], leaving their other
+    /// fields untouched.
+    ///
+    /// History, change recording and persistence are ignored by this inspector.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use egui_field_editor::{EguiInspect, EguiInspector};
+    /// #[derive(EguiInspect, Clone, Default)]
+    /// struct Light {
+    ///     name: String,
+    ///     intensity: f32,
+    ///     enabled: bool,
+    /// }
+    /// fn selection_ui(lights: &mut [Light], ui: &mut egui::Ui) {
+    ///     let mut selection: Vec<&mut Light> = lights.iter_mut().collect();
+    ///     ui.add(EguiInspector::multi(&mut selection));
+    /// }
+    /// ```
+    pub fn multi(objs: &'a mut [&mut T]) -> Self {
+        let objs = objs.iter_mut().map(|obj| &mut **obj).collect();
+        Self::with_target(Target::Multi(objs, multi::show::<T>))
+    }
+}
+impl<'a, T: EguiDisplay> EguiInspector<'a, T> {
+    /// Creates a new read only inspector widget for an object only borrowed immutably.
+    ///
+    /// - `obj`: The object to display.
+    pub fn view(obj: &'a T) -> Self {
+        Self::with_target(Target::View(obj, |obj, id, ui| {
+            if id == egui::Id::NULL {
+                obj.display("", "", ui)
+            } else {
+                obj.display_with_custom_id(id, "", "", ui)
+            }
+        }))
+        .read_only()
+    }
+}
+impl<'a, T: EguiInspect + Clone + Send + 'static> EguiInspector<'a, T> {
+    /// Appends the edits made through the inspector to `changes`, see [`FieldChange`].
+    ///
+    /// The edits are reported once the edit gesture is over (slider released, text field
+    /// losing focus...), as found by [`EguiInspect::collect_changes`] between the value of the
+    /// object before and after the gesture. The value before is kept in `egui` memory (hence
+    /// the `Send + 'static` bounds), use [`Self::id_salt`] to tell the inspectors apart.
+    /// Undo/redo, "Load" and the "Apply" button of a [draft](Self::draft) are reported too.
+    ///
+    /// The edits made inside an `Rc<RefCell<_>>`, `Arc<Mutex<_>>` or `Arc<RwLock<_>>` are never reported: the
+    /// value before the gesture shares the data with the edited object.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use egui_field_editor::{EguiInspect, EguiInspector, FieldChange};
+    /// #[derive(EguiInspect, Clone, Default)]
+    /// struct Light {
+    ///     intensity: f32,
+    ///     color: egui::Color32,
+    /// }
+    /// fn light_ui(light: &mut Light, ui: &mut egui::Ui) -> Vec<FieldChange> {
+    ///     let mut changes = Vec::new();
+    ///     ui.add(EguiInspector::new(light).record_changes(&mut changes));
+    ///     // send `changes` to the other peers, which replay the `ChangeKind::Set` ones with
+    ///     // `EguiInspect::set_by_path_from_str`
+    ///     changes
+    /// }
+    /// ```
+    #[inline]
+    pub fn record_changes(mut self, changes: &'a mut Vec<FieldChange>) -> Self {
+        self.changes = Some(changes::Recorder::new(changes));
+        self
+    }
+}
+#[cfg(feature = "serde")]
+impl<'a, T: serde::Serialize + serde::de::DeserializeOwned> EguiInspector<'a, T> {
+    /// Adds Save/Load buttons writing and reading the inspected object to/from the file at `path`.
+    ///
+    /// A "Save As" button is also shown when the `filepicker` feature is active. Errors (e.g. a
+    /// file which can't be deserialized) are shown below the buttons, and an indicator tells
+    /// whether the object was modified since it was last loaded or saved.
+    /// "Load" is disabled for read-only inspectors.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use egui_field_editor::{EguiInspect, EguiInspector, Format};
+    /// #[derive(EguiInspect, serde::Serialize, serde::Deserialize, Default)]
+    /// struct Config {
+    ///     name: String,
+    ///     port: u16,
+    /// }
+    /// fn config_ui(config: &mut Config, ui: &mut egui::Ui) {
+    ///     ui.add(EguiInspector::new(config).with_persistence("config.toml", Format::Toml));
+    /// }
+    /// ```
+    #[inline]
+    pub fn with_persistence(mut self, path: impl Into<std::path::PathBuf>, format: Format) -> Self {
+        self.persistence = Some(persistence::Persistence::new(path.into(), format));
+        self
+    }
+}
+impl<'a, T: EguiInspect> EguiInspector<'a, T> {
+    /// Shows which fields differ from `baseline`, e.g. a preset the user started from.
+    ///
+    /// The labels of the differing fields are marked with a dot and shown in the strong text
+    /// color, and a ↺ button at the end of their row resets them to their value in `baseline`
+    /// with [`EguiInspect::copy_field_from`]. Fields which can't be copied (e.g. not [`Clone`])
+    /// are left unchanged.
+    /// Only used by inspectors created with [`Self::new`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use egui_field_editor::{EguiInspect, EguiInspector};
+    /// #[derive(EguiInspect, Clone, Default)]
+    /// struct Camera {
+    ///     fov: f32,
+    ///     near: f32,
+    /// }
+    /// fn camera_ui(camera: &mut Camera, preset: &Camera, ui: &mut egui::Ui) {
+    ///     ui.add(EguiInspector::new(camera).with_baseline(preset));
+    /// }
+    /// ```
+    #[inline]
+    pub fn with_baseline(mut self, baseline: &'a T) -> Self {
+        self.baseline = Some(baseline::Baseline::new(baseline));
+        self
+    }
+    /// Shows which fields differ from `T::default()`, see [`Self::with_baseline`].
+    #[inline]
+    pub fn show_modified_from_default(mut self) -> Self
+    where
+        T: Default,
+    {
+        self.baseline = Some(baseline::Baseline::from_default());
+        self
+    }
+}
+impl<'a, T: EguiInspect> EguiInspector<'a, T> {
+    /// Returns every failed validation of the inspected object, see [`EguiInspect::validate`].
+    pub fn validation_errors(&self) -> Vec<ValidationError> {
+        match &self.target {
+            Target::Edit(obj, _) => validation_errors(&**obj),
+            Target::View(obj, _) => validation_errors(*obj),
+            Target::Draft(obj, _) => validation_errors(&**obj),
+            Target::Multi(objs, _) => objs.iter().flat_map(|obj| validation_errors(&**obj)).collect(),
+        }
+    }
+    /// `true` if every validator of the inspected object succeeds.
+    pub fn is_valid(&self) -> bool {
+        self.validation_errors().is_empty()
+    }
+}
+impl<'a, T> EguiInspector<'a, T> {
+    fn with_target(target: Target<'a, T>) -> Self {
         Self {
-            obj,
+            target,
             title: None,
-            read_only: true,
+            read_only: false,
             id_salt: None,
+            history: None,
+            changes: None,
+            baseline: None,
+            default: None,
+            search: false,
+            page_size: None,
+            #[cfg(feature = "serde")]
+            persistence: None,
         }
     }
     /// Set read-only mode.
@@ -209,26 +535,266 @@ impl<'a, T: EguiInspect> EguiInspector<'a, T> {
         self.title = Some(title.to_owned());
         self
     }
-}
+    /// Records the edits in the given [`InspectorHistory`] and shows undo/redo buttons.
+    ///
+    /// Undo and redo are also bound to `Ctrl+Z` and `Ctrl+Shift+Z`/`Ctrl+Y`.
+    /// Ignored by inspectors created with [`Self::view`].
+    ///
+    /// The edits made inside an `Rc<RefCell<_>>`, `Arc<Mutex<_>>` or `Arc<RwLock<_>>` can't be undone: the
+    /// snapshots share the data with the edited object.
+    #[inline]
+    pub fn with_history(mut self, history: &'a mut InspectorHistory<T>) -> Self
+    where
+        T: Clone,
+    {
+        self.history = Some(history);
+        self
+    }
+    /// Function building the value loaded by the "Reset to default" button of a
+    /// [draft](Self::draft), e.g. `.with_default(MyConfig::default)`.
+    ///
+    /// The button is not shown if no default is given.
+    #[inline]
+    pub fn with_default(mut self, default: fn() -> T) -> Self {
+        self.default = Some(default);
+        self
+    }
 
-impl<'a, T: EguiInspect> Widget for EguiInspector<'a, T> {
-    fn ui(self, ui: &mut Ui) -> Response {
+    /// Shows a search field above the inspected fields.
+    ///
+    /// While searching, only the fields whose label contains the query (ignoring case), their
+    /// parents and their children are shown, and the headers containing matches are expanded.
+    /// The query is kept in `egui` memory, use [`Self::id_salt`] to tell the inspectors apart.
+    #[inline]
+    pub fn with_search(mut self) -> Self {
+        self.search = true;
+        self
+    }
+
+    /// Number of items shown per page by the lists (100 by default).
+    ///
+    /// Only the items of the current page of a list are rendered (and can be reordered), a
+    /// page selector with a "Go to" index field is shown above the lists with more items.
+    #[inline]
+    pub fn with_page_size(mut self, page_size: usize) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    /// Renders the inspector and returns the merged [`InspectResponse`] of every inspected field.
+    ///
+    /// Use this instead of [`egui::Ui::add`] when you need more than [`Response::changed`],
+    /// e.g. to know when an edit gesture starts or ends.
+    pub fn show(self, ui: &mut Ui) -> InspectResponse {
         ui.set_min_width(100.);
         let available_width = ui.available_width();
         if let Some(title) = &self.title {
             ui.heading(title);
         }
-        egui::ScrollArea::vertical().show(ui, |ui| {
-            ui.set_min_width(available_width);
-            if let Some(salt) = self.id_salt {
-                self.obj
-                    .inspect_with_custom_id(salt, "", "", self.read_only, ui);
+        let id = self.id_salt.unwrap_or(egui::Id::NULL);
+        let changes_key = ui.make_persistent_id(("egui_field_editor::changes", self.id_salt));
+        let search_bar = |ui: &mut Ui| {
+            if self.search {
+                search::search_bar(self.id_salt, ui)
             } else {
-                self.obj.inspect("", "", self.read_only, ui);
+                String::new()
             }
+        };
+        // the page size is given to the fields (and to the nested inspectors) through the context
+        let previous_page_size = InspectContext::with(ui.ctx(), |context| {
+            let previous = context.page_size;
+            context.page_size = self.page_size.or(previous);
+            previous
         });
+        // the toolbars and the recording of the edits shared by the modes editing one object:
+        // `render` shows the fields, given the search query
+        let edit = |obj: &mut T, ui: &mut Ui, render: &mut dyn FnMut(&mut T, String, &mut Ui) -> InspectResponse| {
+            if let Some(recorder) = &self.changes {
+                (recorder.begin)(obj, changes_key, ui);
+            }
+            // edits made by the toolbars (not by undo/redo) are recorded in the history
+            #[allow(unused_mut)]
+            let mut edits = InspectResponse::default();
+            #[cfg(feature = "serde")]
+            if let Some(persistence) = &self.persistence {
+                edits |= persistence.toolbar(obj, self.read_only, self.id_salt, ui);
+            }
+            let mut response = edits;
+            let mut history = self.history;
+            if let Some(history) = history.as_mut() {
+                response |= history.toolbar(obj, self.read_only, ui);
+            }
+            let query = search_bar(ui);
+            let fields_response = render(obj, query, ui);
+            #[cfg(feature = "serde")]
+            if let Some(persistence) = &self.persistence {
+                persistence.record(obj, response | fields_response, self.id_salt, ui);
+            }
+            if let Some(history) = history {
+                history.record(obj, edits | fields_response, ui);
+            }
+            if let Some(recorder) = self.changes {
+                (recorder.end)(obj, response | fields_response, changes_key, recorder.changes, ui);
+            }
+            response | fields_response
+        };
+        let response = match self.target {
+            Target::Edit(obj, inspect) => edit(obj, ui, &mut |obj, query, ui| {
+                egui::ScrollArea::vertical()
+                    .show(ui, |ui| {
+                        ui.set_min_width(available_width);
+                        search::scope(ui, id, None, &query, |ui, id| {
+                            baseline::scope(self.baseline.as_ref(), obj, ui, |obj, ui| {
+                                inspect(obj, id, self.read_only, ui)
+                            })
+                        })
+                    })
+                    .inner
+            }),
+            Target::View(obj, display) => {
+                #[cfg(feature = "serde")]
+                if let Some(persistence) = &self.persistence {
+                    persistence.toolbar_read_only(obj, self.id_salt, ui);
+                }
+                let query = search_bar(ui);
+                egui::ScrollArea::vertical().show(ui, |ui| {
+                    ui.set_min_width(available_width);
+                    search::scope(ui, id, None, &query, |ui, id| display(obj, id, ui))
+                });
+                InspectResponse::default()
+            }
+            Target::Draft(obj, show_draft) => edit(obj, ui, &mut |obj, query, ui| {
+                let options = draft::DraftOptions {
+                    id_salt: self.id_salt,
+                    default: self.default,
+                    read_only: self.read_only,
+                    min_width: available_width,
+                    query,
+                };
+                show_draft(obj, options, ui)
+            }),
+            Target::Multi(mut objs, show_multi) => {
+                let query = search_bar(ui);
+                egui::ScrollArea::vertical()
+                    .show(ui, |ui| {
+                        ui.set_min_width(available_width);
+                        show_multi(&mut objs, id, &query, self.read_only, ui)
+                    })
+                    .inner
+            }
+        };
+        InspectContext::with(ui.ctx(), |context| context.page_size = previous_page_size);
+        response
+    }
+}
+
+impl<'a, T> Widget for EguiInspector<'a, T> {
+    /// The returned [`Response::changed`] is `true` when any nested field was edited. Its rect is the one taken by the widget.
+    fn ui(self, ui: &mut Ui) -> Response {
+        let scope = ui.scope(|ui| self.show(ui));
+        let mut response = scope.response;
+        if scope.inner.changed() {
+            response.mark_changed();
+        }
+        response
+    }
+}
+
+/// Summary of what happened while inspecting a value during the current frame.
+///
+/// Every [`EguiInspect`] implementation and every `add_*` helper returns one. Responses of
+/// nested fields are merged with `|` so the one returned for a struct reflects any edit made
+/// to one of its fields.
+///
+/// # Examples
+///
+/// ```rust
+/// use egui_field_editor::InspectResponse;
+/// let mut response = InspectResponse::default();
+/// response |= InspectResponse::default();
+/// assert!(!response.changed());
+/// response.mark_changed();
+/// assert!(response.changed());
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct InspectResponse {
+    changed: bool,
+    drag_started: bool,
+    drag_stopped: bool,
+    lost_focus: bool,
+}
+impl InspectResponse {
+    /// `true` if the inspected value was modified.
+    #[inline]
+    pub fn changed(&self) -> bool {
+        self.changed
+    }
+    /// `true` if an edit gesture (e.g. dragging a slider) started.
+    #[inline]
+    pub fn drag_started(&self) -> bool {
+        self.drag_started
+    }
+    /// `true` if an edit gesture (e.g. dragging a slider) was released.
+    #[inline]
+    pub fn drag_stopped(&self) -> bool {
+        self.drag_stopped
+    }
+    /// `true` if a widget (typically a text field) lost the keyboard focus.
+    #[inline]
+    pub fn lost_focus(&self) -> bool {
+        self.lost_focus
+    }
+    /// Flags the inspected value as modified.
+    ///
+    /// Call this from custom implementations when the value is changed by something else than
+    /// an `egui` widget (a button, a file dialog...).
+    #[inline]
+    pub fn mark_changed(&mut self) {
+        self.changed = true;
+    }
+    /// Merges two responses: each flag is set if it is set in any of them.
+    #[inline]
+    pub fn union(self, other: Self) -> Self {
+        Self {
+            changed: self.changed || other.changed,
+            drag_started: self.drag_started || other.drag_started,
+            drag_stopped: self.drag_stopped || other.drag_stopped,
+            lost_focus: self.lost_focus || other.lost_focus,
+        }
+    }
+}
+impl std::ops::BitOr for InspectResponse {
+    type Output = Self;
 
-        ui.response()
+    fn bitor(self, rhs: Self) -> Self::Output {
+        self.union(rhs)
+    }
+}
+impl std::ops::BitOrAssign for InspectResponse {
+    fn bitor_assign(&mut self, rhs: Self) {
+        *self = self.union(rhs);
+    }
+}
+impl From<&Response> for InspectResponse {
+    fn from(response: &Response) -> Self {
+        Self {
+            changed: response.changed(),
+            drag_started: response.drag_started(),
+            drag_stopped: response.drag_stopped(),
+            lost_focus: response.lost_focus(),
+        }
+    }
+}
+impl From<Response> for InspectResponse {
+    fn from(response: Response) -> Self {
+        Self::from(&response)
+    }
+}
+/// Allows closures and custom functions which do not report anything to be used where an
+/// [`InspectResponse`] is expected.
+impl From<()> for InspectResponse {
+    fn from(_: ()) -> Self {
+        Self::default()
     }
 }
 
@@ -248,15 +814,17 @@ macro_rules! impl_only_numbers_struct_inspect {
 		#[doc = " "]
 		#[doc = "# See Also"]
 		#[doc = "- [`egui::DragValue`]"]
-		pub fn $method(data: &mut $Type, label: &str, tooltip: &str, read_only: bool, ui: &mut egui::Ui) {
+		pub fn $method(data: &mut $Type, label: &str, tooltip: &str, read_only: bool, ui: &mut egui::Ui) -> InspectResponse {
 			crate::add_custom_ui(label, tooltip, read_only, ui, |ui, _field_size| {
 				ui.horizontal(|ui| {
+					let mut response = InspectResponse::default();
 					$(
 						ui.label(stringify!($field));
-						ui.add(egui::DragValue::new(&mut data.$field).speed(0.1));
+						response |= ui.add(egui::DragValue::new(&mut data.$field).speed(0.1)).into();
 					)+
-				});
-			});
+					response
+				}).inner
+			})
 		}
 	}
 }
@@ -275,21 +843,23 @@ macro_rules! impl_mat_inspect {
 		#[doc = " "]
 		#[doc = "# See Also"]
 		#[doc = "- [`egui::DragValue`]"]
-		pub fn $method(data: &mut $Type, label: &str, tooltip: &str, read_only: bool, ui: &mut egui::Ui) {
+		pub fn $method(data: &mut $Type, label: &str, tooltip: &str, read_only: bool, ui: &mut egui::Ui) -> InspectResponse {
 				crate::add_custom_ui(label, tooltip, read_only, ui, |ui, _field_size| {
 					ui.vertical(|ui| {
 						ui.group(|ui| {
+							let mut response = InspectResponse::default();
 							$(
 								ui.horizontal(|ui| {
 									$(
 										ui.label(stringify!($field));
-										ui.add(egui::DragValue::new(&mut data.$field).speed(0.1));
+										response |= ui.add(egui::DragValue::new(&mut data.$field).speed(0.1)).into();
 									)+
 								});
 							)+
-						});
-					});
-				});
+							response
+						}).inner
+					}).inner
+				})
 			}
 		}
 	}
@@ -318,22 +888,22 @@ macro_rules! impl_mat_inspect {
 ///     a_second_string:String,
 /// }
 /// impl egui_field_editor::EguiInspect for MyStruct {
-///     fn inspect_with_custom_id(&mut self, _parent_id: egui::Id, label: &str, tooltip: &str, read_only: bool, ui: &mut egui::Ui) {
+///     fn inspect_with_custom_id(&mut self, _parent_id: egui::Id, label: &str, tooltip: &str, read_only: bool, ui: &mut egui::Ui) -> egui_field_editor::InspectResponse {
 ///         let id = if _parent_id == egui::Id::NULL { ui.next_auto_id() } else { _parent_id.with(label) };
 ///         let _parent_id_to_provide_to_children = if _parent_id == egui::Id::NULL { egui::Id::NULL } else { id };
 ///         let mut add_content=|ui:&mut egui::Ui| {
-///             egui_field_editor::add_bool(&mut self.a_bool, "Bool", "Boolean Tooltip", read_only, ui);
-///             egui_field_editor::add_number(&mut self.an_int, "Integer", "Integer Tooltip", read_only, None, ui);
-///             egui_field_editor::add_number(&mut self.an_uint, "Unsigned Integer", "Unsigned Integer Tooltip with min/max", read_only, Some((12, 50000)), ui);
-///             egui_field_editor::add_number_slider(&mut self.a_float, "Float", "Float Slider Tooltip", read_only, -12., 50., ui);
-///             egui_field_editor::add_color(&mut self.a_color, "Color", "", read_only, ui);
-///             egui_field_editor::add_string_singleline(&mut self.a_string, "String", "", read_only, ui);
-///             egui_field_editor::add_string_multiline(&mut self.a_second_string, "Multiline String", "", read_only, 4, ui);
+///             egui_field_editor::add_bool(&mut self.a_bool, "Bool", "Boolean Tooltip", read_only, ui)
+///             | egui_field_editor::add_number(&mut self.an_int, "Integer", "Integer Tooltip", read_only, None, ui)
+///             | egui_field_editor::add_number(&mut self.an_uint, "Unsigned Integer", "Unsigned Integer Tooltip with min/max", read_only, Some((12, 50000)), ui)
+///             | egui_field_editor::add_number_slider(&mut self.a_float, "Float", "Float Slider Tooltip", read_only, -12., 50., ui)
+///             | egui_field_editor::add_color(&mut self.a_color, "Color", "", read_only, ui)
+///             | egui_field_editor::add_string_singleline(&mut self.a_string, "String", "", read_only, ui)
+///             | egui_field_editor::add_string_multiline(&mut self.a_second_string, "Multiline String", "", read_only, 4, ui)
 ///         };
 ///         if !label.is_empty() {
-///             egui::CollapsingHeader::new(label).id_salt(id).show(ui, add_content);
+///             egui::CollapsingHeader::new(label).id_salt(id).show(ui, add_content).body_returned.unwrap_or_default()
 ///         } else {
-///             add_content(ui);
+///             add_content(ui)
 ///         }
 ///     }
 /// }
@@ -347,12 +917,17 @@ pub trait EguiInspect {
     /// - `tooltip`: Tooltip shown when hovering over the label.
     /// - `read_only`: If `true`, disables all interactive widgets.
     /// - `ui`: The `egui::Ui` to render into.
-    fn inspect(&mut self, label: &str, tooltip: &str, read_only: bool, ui: &mut egui::Ui) {
-        self.inspect_with_custom_id(egui::Id::NULL, label, tooltip, read_only, ui);
+    ///
+    /// Returns an [`InspectResponse`] telling whether the object was edited.
+    fn inspect(&mut self, label: &str, tooltip: &str, read_only: bool, ui: &mut egui::Ui) -> InspectResponse {
+        self.inspect_with_custom_id(egui::Id::NULL, label, tooltip, read_only, ui)
     }
     /// Renders the inspector UI with a custom parent ID.
     ///
     /// This allows you to scope widget IDs under a specific parent, useful for avoiding collisions.
+    ///
+    /// Implementations must merge the [`InspectResponse`]s of their fields so that edits
+    /// are propagated to the caller.
     fn inspect_with_custom_id(
         &mut self,
         parent_id: egui::Id,
@@ -360,7 +935,349 @@ pub trait EguiInspect {
         tooltip: &str,
         read_only: bool,
         ui: &mut egui::Ui,
-    );
+    ) -> InspectResponse;
+    /// Checks the object with the validators declared using `#[inspect(validate = "...")]`
+    /// and `#[inspect(validate_struct = "...")]`, recursively.
+    ///
+    /// - `path`: Path of this object, used to build the [`ValidationError::path`] of its fields.
+    /// - `errors`: Receives the failed validations.
+    ///
+    /// The default implementation does nothing. Containers forward the call to their items.
+    /// See also [`validation_errors`].
+    fn validate(&self, path: &str, errors: &mut Vec<ValidationError>) {
+        let _ = (path, errors);
+    }
+    /// Lists the fields which differ between `self` and `other`, recursively.
+    ///
+    /// - `path`: Path of this object, with the syntax of [`ValidationError::path`].
+    /// - `diffs`: Receives the paths of the modified fields.
+    ///
+    /// The default implementation does nothing. Types of this crate compare their value with
+    /// [`PartialEq`], containers forward the call to their items, and `#[derive(EguiInspect)]`
+    /// compares the fields (those which implement neither [`EguiInspect`] nor [`PartialEq`] are
+    /// never reported). See also [`diff_paths`].
+    fn diff(&self, other: &Self, path: &str, diffs: &mut Vec<String>) {
+        let _ = (other, path, diffs);
+    }
+    /// Lists the edits turning `self` into `other`, recursively.
+    ///
+    /// - `path`: Path of this object, with the syntax of [`ValidationError::path`].
+    /// - `changes`: Receives the edits, see [`FieldChange`].
+    ///
+    /// The default implementation does nothing. Types of this crate report a
+    /// [`ChangeKind::Set`] with their value formatted for [`Self::set_by_path_from_str`],
+    /// containers report the inserted, removed and moved items and forward the call to the
+    /// others (for a list, the items are compared with [`Self::diff`]), and
+    /// `#[derive(EguiInspect)]` compares the fields (those implementing [`Display`] and
+    /// [`PartialEq`] but not [`EguiInspect`] are reported as a whole, those implementing
+    /// [`PartialEq`] whose `collect_changes` reports nothing as a [`ChangeKind::Opaque`] change).
+    /// See also [`field_changes`].
+    fn collect_changes(&self, other: &Self, path: &str, changes: &mut Vec<FieldChange>) {
+        let _ = (other, path, changes);
+    }
+    /// Lists the fields of this object with their metadata, recursively.
+    ///
+    /// - `path`: Path of this object, with the syntax of [`ValidationError::path`].
+    /// - `fields`: Receives the description of the fields.
+    ///
+    /// The default implementation does nothing. Containers list their items (e.g. `hosts[2]`)
+    /// and `#[derive(EguiInspect)]` lists the fields which are not `hidden` (and the fields of
+    /// the current variant for enums). See also [`Self::field_paths`].
+    fn collect_field_paths(&self, path: &str, fields: &mut Vec<FieldInfo>) {
+        let _ = (path, fields);
+    }
+    /// Returns the description of every field of this object, see [`Self::collect_field_paths`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use egui_field_editor::EguiInspect;
+    /// #[derive(EguiInspect, Default)]
+    /// struct Host {
+    ///     #[inspect(name = "Host name", tooltip = "DNS name or IP address")]
+    ///     name: String,
+    ///     #[inspect(slider(min = 1., max = 65535.))]
+    ///     port: u16,
+    /// }
+    /// #[derive(EguiInspect, Default)]
+    /// struct Network {
+    ///     hosts: Vec<Host>,
+    /// }
+    /// let network = Network { hosts: vec![Host::default()] };
+    /// let fields = network.field_paths();
+    /// let paths: Vec<_> = fields.iter().map(|field| field.path.as_str()).collect();
+    /// assert_eq!(paths, ["hosts", "hosts[0]", "hosts[0].name", "hosts[0].port"]);
+    /// assert_eq!(fields[2].label, "Host name");
+    /// assert_eq!(fields[2].tooltip, "DNS name or IP address");
+    /// assert_eq!(fields[3].type_name, "u16");
+    /// assert_eq!(fields[3].attributes, [("slider", "min = 1., max = 65535.")]);
+    /// ```
+    fn field_paths(&self) -> Vec<FieldInfo> {
+        let mut fields = Vec::new();
+        self.collect_field_paths("", &mut fields);
+        fields
+    }
+    /// Returns the field at `path` (with the syntax of [`ValidationError::path`]), if it exists
+    /// and is `'static`. Use [`std::any::Any::downcast_ref`] to read it.
+    ///
+    /// The default implementation returns `None`. Types of this crate return themselves for an
+    /// empty path, containers forward the call to their items (the value of an [`Option`] is
+    /// returned for the path of the [`Option`]), and `#[derive(EguiInspect)]` looks for the
+    /// field named by the first part of the path.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use egui_field_editor::EguiInspect;
+    /// #[derive(EguiInspect, Default)]
+    /// struct Host {
+    ///     name: String,
+    ///     port: u16,
+    /// }
+    /// #[derive(EguiInspect, Default)]
+    /// struct Network {
+    ///     hosts: Vec<Host>,
+    /// }
+    /// let mut network = Network { hosts: vec![Host::default()] };
+    /// network.set_by_path_from_str("hosts[0].port", "8080").unwrap();
+    /// let port = network.get_by_path("hosts[0].port").and_then(|port| port.downcast_ref::<u16>());
+    /// assert_eq!(port, Some(&8080));
+    /// assert!(network.set_by_path_from_str("hosts[0].port", "http").is_err());
+    /// assert!(network.set_by_path_from_str("hosts[3].port", "80").is_err());
+    /// ```
+    fn get_by_path(&self, path: &str) -> Option<&dyn std::any::Any> {
+        let _ = path;
+        None
+    }
+    /// Parses `value` into the field at `path` (with the syntax of [`ValidationError::path`]).
+    ///
+    /// Fails if there is no such field, or if it can't be parsed. Fields implementing
+    /// [`std::str::FromStr`] can be set, whether they implement [`EguiInspect`] or not.
+    /// The `read_only` attribute is not checked, see [`FieldInfo::read_only`].
+    ///
+    /// A derived enum is switched to another variant by setting its path to the name of the
+    /// variant, the fields of the variant getting their default value.
+    ///
+    /// The default implementation fails. See [`Self::get_by_path`] for the implementations of
+    /// this crate.
+    fn set_by_path_from_str(&mut self, path: &str, value: &str) -> Result<(), String> {
+        let _ = value;
+        Err(derive_support::no_field(path))
+    }
+    /// Copies the field at `path` (with the syntax of [`ValidationError::path`]) of `other`
+    /// into `self`, or the whole value for an empty path. Used by [`EguiInspector::multi`].
+    ///
+    /// Fails if there is no such field, or if it can't be copied. The default implementation
+    /// fails. Types of this crate are cloned, containers copy their items one by one (lists
+    /// are resized first) and `#[derive(EguiInspect)]` copies the field named by the first
+    /// part of the path. For an empty path, the derived implementation copies every field
+    /// which is not `hidden`, or clones the whole value if the variants of an enum differ
+    /// (which needs [`Clone`]).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use egui_field_editor::EguiInspect;
+    /// #[derive(EguiInspect, Default)]
+    /// struct Light {
+    ///     intensity: f32,
+    ///     tags: Vec<String>,
+    /// }
+    /// let mut light = Light::default();
+    /// let other = Light { intensity: 0.5, tags: vec!["sun".to_owned()] };
+    /// light.copy_field_from(&other, "tags").unwrap();
+    /// assert_eq!(light.tags, other.tags);
+    /// assert_eq!(light.intensity, 0.);
+    /// ```
+    fn copy_field_from(&mut self, other: &Self, path: &str) -> Result<(), String> {
+        let _ = other;
+        Err(derive_support::not_copyable(path))
+    }
+    /// A copy of `self` for the "Duplicate" action of the lists, or `None` if it can't be
+    /// copied this way.
+    ///
+    /// The default implementation returns `None`, the lists then copy every field of a default
+    /// item with [`Self::copy_field_from`]. `#[derive(EguiInspect)]` clones the value if the
+    /// type implements [`Clone`].
+    fn duplicate(&self) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        None
+    }
+    /// A new value of the type, built when `Some` is picked for an `Option`, or `None` if the
+    /// type has no default value (`Some` is then disabled).
+    ///
+    /// The default implementation returns `None`. The types of this crate return their
+    /// [`Default`] value, and `#[derive(EguiInspect)]` returns [`Default::default`] if the type
+    /// implements it. Manual implementations must override it for `Some` to be picked:
+    ///
+    /// ```rust
+    /// use egui_field_editor::{EguiInspect, InspectResponse};
+    /// #[derive(Default)]
+    /// struct Celsius(f32);
+    /// impl EguiInspect for Celsius {
+    ///     fn inspect_with_custom_id(&mut self, _parent_id: egui::Id, label: &str, tooltip: &str, read_only: bool, ui: &mut egui::Ui) -> InspectResponse {
+    ///         egui_field_editor::add_number(&mut self.0, label, tooltip, read_only, None, ui)
+    ///     }
+    ///     fn default_value() -> Option<Self> {
+    ///         Some(Self::default())
+    ///     }
+    /// }
+    /// ```
+    fn default_value() -> Option<Self>
+    where
+        Self: Sized,
+    {
+        None
+    }
+    /// Every value of the type with its label, for the types with a few values known in
+    /// advance, or an empty list.
+    ///
+    /// The default implementation returns an empty list. `#[derive(EguiInspect)]` lists the
+    /// variants of the enums whose variants have no fields (except the `hidden` ones). The sets
+    /// of such types are shown as a checkbox per value.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::collections::BTreeSet;
+    /// use egui_field_editor::EguiInspect;
+    /// #[derive(EguiInspect, Clone, Default, PartialEq, Eq, PartialOrd, Ord)]
+    /// enum Weekday {
+    ///     #[default]
+    ///     Monday,
+    ///     #[inspect(name = "Tue.")]
+    ///     Tuesday,
+    /// }
+    /// #[derive(EguiInspect)]
+    /// struct Alarm {
+    ///     days: BTreeSet<Weekday>, // shown as a checkbox per day
+    /// }
+    /// assert!(Weekday::all_variants() == [(Weekday::Monday, "Monday"), (Weekday::Tuesday, "Tue.")]);
+    /// ```
+    fn all_variants() -> Vec<(Self, &'static str)>
+    where
+        Self: Sized,
+    {
+        Vec::new()
+    }
+}
+
+/// A failed validation reported by [`EguiInspect::validate`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ValidationError {
+    /// Path of the invalid field from the validated object, e.g. `network.hosts[2].port`.
+    ///
+    /// Empty if the validated object itself is invalid (see `validate_struct`).
+    pub path: String,
+    /// Message returned by the validator.
+    pub message: String,
+}
+
+/// Description of a field, see [`EguiInspect::field_paths`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FieldInfo {
+    /// Path of the field from the described object, e.g. `network.hosts[2].port`.
+    pub path: String,
+    /// Label shown by the inspector.
+    pub label: String,
+    /// Tooltip shown by the inspector, empty if none.
+    pub tooltip: String,
+    /// Type of the field, as returned by [`std::any::type_name`].
+    pub type_name: &'static str,
+    /// `true` if the field has the `read_only` attribute.
+    pub read_only: bool,
+    /// The `#[inspect(...)]` attributes of the field, with their value as written in the code
+    /// (empty for flags), e.g. `("slider", "min = 0., max = 10.")` or `("read_only", "")`.
+    pub attributes: &'static [(&'static str, &'static str)],
+}
+
+/// Returns every failed validation of `obj` and its fields.
+///
+/// # Examples
+///
+/// ```rust
+/// use egui_field_editor::{EguiInspect, validation_errors};
+/// fn not_empty(name: &String) -> Result<(), String> {
+///     if name.is_empty() { Err("Name is required".to_owned()) } else { Ok(()) }
+/// }
+/// #[derive(EguiInspect, Default)]
+/// struct User {
+///     #[inspect(validate = "not_empty")]
+///     name: String,
+/// }
+/// let errors = validation_errors(&User::default());
+/// assert_eq!(errors[0].path, "name");
+/// assert_eq!(errors[0].message, "Name is required");
+/// ```
+pub fn validation_errors<T: EguiInspect + ?Sized>(obj: &T) -> Vec<ValidationError> {
+    let mut errors = Vec::new();
+    obj.validate("", &mut errors);
+    errors
+}
+
+/// Returns the paths of the fields which differ between `old` and `new`, see [`EguiInspect::diff`].
+///
+/// # Examples
+///
+/// ```rust
+/// use egui_field_editor::{EguiInspect, diff_paths};
+/// #[derive(EguiInspect, Clone, Default)]
+/// struct Server {
+///     host: String,
+///     ports: Vec<u16>,
+/// }
+/// let old = Server { host: "localhost".to_owned(), ports: vec![80, 443] };
+/// let mut new = old.clone();
+/// new.ports[1] = 8443;
+/// assert_eq!(diff_paths(&old, &new), vec!["ports[1]"]);
+/// ```
+pub fn diff_paths<T: EguiInspect + ?Sized>(old: &T, new: &T) -> Vec<String> {
+    let mut diffs = Vec::new();
+    old.diff(new, "", &mut diffs);
+    diffs
+}
+
+/// Read-only counterpart of [`EguiInspect`], rendering an object only borrowed immutably.
+///
+/// `#[derive(EguiInspect)]` also implements this trait, and it is implemented for every type
+/// supported by [`EguiInspect`] in this crate. It allows showing data behind an `Arc<T>` or
+/// borrowed from somewhere else with [`EguiInspector::view`].
+///
+/// # Example
+///
+/// ```rust
+/// use egui_field_editor::{EguiDisplay, EguiInspect};
+/// #[derive(EguiInspect)]
+/// struct Stats {
+///     frames: u64,
+///     name: String,
+/// }
+/// fn show_stats(stats: &std::sync::Arc<Stats>, ui: &mut egui::Ui) {
+///     stats.display("Stats", "Statistics of the last run", ui);
+/// }
+/// ```
+///
+/// Derived implementations display fields using `custom_fn` (or any attribute needing a
+/// mutable value) by calling it on a clone of the field with `read_only` set. If the field
+/// type does not implement [`Clone`], use `#[inspect(display_fn = "...")]` to provide a
+/// function taking `&T`.
+pub trait EguiDisplay {
+    /// Renders the read-only UI for this object.
+    ///
+    /// This is a convenience method that delegates to [`Self::display_with_custom_id`] using a [NULL Id](egui::Id::NULL).
+    ///
+    /// - `label`: Label displayed above the block.
+    /// - `tooltip`: Tooltip shown when hovering over the label.
+    /// - `ui`: The `egui::Ui` to render into.
+    fn display(&self, label: &str, tooltip: &str, ui: &mut egui::Ui) {
+        self.display_with_custom_id(egui::Id::NULL, label, tooltip, ui)
+    }
+    /// Renders the read-only UI with a custom parent ID.
+    ///
+    /// See [`EguiInspect::inspect_with_custom_id`].
+    fn display_with_custom_id(&self, parent_id: egui::Id, label: &str, tooltip: &str, ui: &mut egui::Ui);
 }
 
 /// Adds a labeled widget to the UI with layout and tooltip support.
@@ -384,11 +1301,11 @@ pub fn add_widget<T: egui::Widget>(
     tooltip: &str,
     read_only: bool,
     ui: &mut egui::Ui,
-) {
+) -> InspectResponse {
     crate::add_custom_ui(label, tooltip, read_only, ui, |ui, field_width| {
         ui.spacing_mut().slider_width = field_width - 50.;
-        ui.add_sized([field_width, 0.], widget);
-    });
+        ui.add_sized([field_width, 0.], widget)
+    })
 }
 /// Adds a custom field with layout and tooltip support.
 ///
@@ -400,28 +1317,29 @@ pub fn add_widget<T: egui::Widget>(
 /// - `read_only`: If `true`, disables the field.
 /// - `ui`: The `egui::Ui` to render into.
 /// - `field_renderer`: A closure that renders the field, receiving the available field width.
-pub fn add_custom_ui<F>(
+///   It can return an [`InspectResponse`], an [`egui::Response`] or `()`.
+pub fn add_custom_ui<F, R>(
     label: &str,
     tooltip: &str,
     read_only: bool,
     ui: &mut egui::Ui,
     field_renderer: F,
-) where
-    F: FnOnce(&mut egui::Ui, f32),
+) -> InspectResponse
+where
+    F: FnOnce(&mut egui::Ui, f32) -> R,
+    R: Into<InspectResponse>,
 {
+    if !search::is_field_visible(ui) {
+        return InspectResponse::default();
+    }
     let available_width = ui.available_width();
     let label_width = available_width * 0.4;
-    let field_width = 100.0f32.max(available_width * 0.6 - 15.0);
+    let field_width = 100.0f32.max(available_width * 0.6 - 15.0 - baseline::reset_width(ui));
 
     ui.horizontal(|ui| {
-        ui.add_enabled_ui(!read_only, |ui| {
-            let r = ui.add_sized(
-                [label_width, 0.0],
-                egui::Label::new(label)
-                    .truncate()
-                    .show_tooltip_when_elided(true)
-                    .halign(egui::Align::LEFT),
-            );
+        let response = ui.add_enabled_ui(!read_only, |ui| {
+            let r = ui.add_sized([label_width, 0.0], context::field_label(label, ui));
+            field_menu::add_field_menu(&r, read_only, ui);
 
             if !tooltip.is_empty() {
                 if !read_only {
@@ -431,9 +1349,30 @@ pub fn add_custom_ui<F>(
                 }
             }
 
-            field_renderer(ui, field_width);
-        });
-    });
+            field_renderer(ui, field_width).into()
+        })
+        .inner;
+        baseline::reset_button(read_only, ui);
+        response
+    })
+    .inner
+}
+
+/// Shows a failed validation: draws a red frame around `rect` (usually the area of the
+/// invalid field) and adds the message below it.
+///
+/// - `rect`: Area to highlight.
+/// - `message`: Message to display.
+/// - `ui`: The `egui::Ui` to render into.
+pub fn add_validation_error(rect: egui::Rect, message: &str, ui: &mut egui::Ui) {
+    let color = ui.visuals().error_fg_color;
+    ui.painter().rect_stroke(
+        rect.expand(1.),
+        2.,
+        egui::Stroke::new(1., color),
+        egui::StrokeKind::Outside,
+    );
+    ui.colored_label(color, format!("❌ {message}"));
 }
 
 /// Adds a numeric slider to the given `egui` UI.
@@ -471,12 +1410,15 @@ pub fn add_number_slider<Num: egui::emath::Numeric>(
     min: Num,
     max: Num,
     ui: &mut egui::Ui,
-) {
-    let editor = egui::Slider::new(data, min..=max);
+) -> InspectResponse {
+    let mut editor = egui::Slider::new(data, min..=max);
+    if multi::is_mixed(ui) {
+        editor = editor.custom_formatter(|_, _| multi::MIXED.to_owned());
+    }
     crate::add_custom_ui(label, tooltip, read_only, ui, |ui, field_width| {
         ui.spacing_mut().slider_width = field_width - 50.;
-        ui.add_sized([field_width, 0.], editor);
-    });
+        ui.add_sized([field_width, 0.], editor)
+    })
 }
 /// Adds a numeric drag field to the UI.
 ///
@@ -500,12 +1442,100 @@ pub fn add_number<Num: egui::emath::Numeric>(
     read_only: bool,
     minmax: Option<(Num, Num)>,
     ui: &mut egui::Ui,
-) {
+) -> InspectResponse {
     let mut editor = egui::DragValue::new(data);
     if let Some(minmax) = minmax {
         editor = editor.range(minmax.0..=minmax.1);
     }
-    crate::add_widget(label, editor, tooltip, read_only, ui);
+    if multi::is_mixed(ui) {
+        editor = editor.custom_formatter(|_, _| multi::MIXED.to_owned());
+    }
+    crate::add_widget(label, editor, tooltip, read_only, ui)
+}
+
+/// Adds a pair of numeric drag fields editing the start and end of a range.
+///
+/// - `data`: Mutable reference to the range, a [`std::ops::Range`] or a [`std::ops::RangeInclusive`].
+/// - `label`: Label shown next to the fields.
+/// - `tooltip`: Tooltip shown when hovering.
+/// - `read_only`: If `true`, disables interaction.
+/// - `minmax`: Optional `(min, max)` limits of the bounds.
+/// - `ui`: The `egui::Ui` to render into.
+///
+/// The start can't be dragged past the end, and conversely.
+///
+/// # See Also
+///
+/// - [`add_range_slider`]
+/// - [`add_number`]
+pub fn add_range<R: NumericRange>(
+    data: &mut R,
+    label: &str,
+    tooltip: &str,
+    read_only: bool,
+    minmax: Option<(R::Num, R::Num)>,
+    ui: &mut egui::Ui,
+) -> InspectResponse {
+    let (mut start, mut end) = data.bounds();
+    let response = crate::add_custom_ui(label, tooltip, read_only, ui, |ui, field_width| {
+        range::range_drag_values(&mut start, &mut end, minmax, field_width, ui)
+    });
+    if response.changed() {
+        data.set_bounds(start, end);
+    }
+    response
+}
+
+/// Adds a slider with two handles editing the start and end of a range, between `min` and
+/// `max`, followed by the drag fields of [`add_range`].
+///
+/// The `slider` attribute of `#[derive(EguiInspect)]` uses it for the [`std::ops::Range`] and
+/// [`std::ops::RangeInclusive`] fields, and the `range` attribute uses [`add_range`].
+///
+/// # Examples
+///
+/// ```rust
+/// use std::ops::{Range, RangeInclusive};
+/// use egui_field_editor::EguiInspect;
+/// #[derive(EguiInspect)]
+/// struct Filter {
+///     #[inspect(slider(min = 0., max = 100.))]
+///     opacity: Range<f32>,
+///     #[inspect(range(min = 1., max = 64.))]
+///     threads: RangeInclusive<u8>,
+///     size: (u32, u32),
+///     last_run: Result<u64, String>,
+/// }
+/// let mut filter = Filter { opacity: 20.0..80.0, threads: 1..=8, size: (640, 360), last_run: Ok(0) };
+/// filter.set_by_path_from_str("opacity.end", "90").unwrap();
+/// filter.set_by_path_from_str("size.1", "480").unwrap();
+/// assert_eq!(filter.opacity, 20.0..90.0);
+/// assert_eq!(filter.size, (640, 480));
+/// ```
+///
+/// # See Also
+///
+/// - [`add_range`]
+/// - [`add_number_slider`]
+pub fn add_range_slider<R: NumericRange>(
+    data: &mut R,
+    label: &str,
+    tooltip: &str,
+    read_only: bool,
+    min: R::Num,
+    max: R::Num,
+    ui: &mut egui::Ui,
+) -> InspectResponse {
+    let (mut start, mut end) = data.bounds();
+    let response = crate::add_custom_ui(label, tooltip, read_only, ui, |ui, field_width| {
+        let slider_width = field_width * 0.5 - ui.spacing().item_spacing.x;
+        let slider = range::range_slider(&mut start, &mut end, min, max, slider_width, ui);
+        slider | range::range_drag_values(&mut start, &mut end, Some((min, max)), field_width * 0.5, ui)
+    });
+    if response.changed() {
+        data.set_bounds(start, end);
+    }
+    response
 }
 
 /// Adds a single-line text field.
@@ -519,7 +1549,18 @@ pub fn add_string_singleline(
     tooltip: &str,
     read_only: bool,
     ui: &mut egui::Ui,
-) {
+) -> InspectResponse {
+    if multi::is_mixed(ui) {
+        return add_mixed_string(data, |text| {
+            crate::add_widget(
+                label,
+                egui::TextEdit::singleline(text).hint_text(multi::MIXED),
+                tooltip,
+                read_only,
+                ui,
+            )
+        });
+    }
     crate::add_widget(
         label,
         egui::TextEdit::singleline(data),
@@ -541,7 +1582,20 @@ pub fn add_string_multiline(
     read_only: bool,
     nb_lines: u8,
     ui: &mut egui::Ui,
-) {
+) -> InspectResponse {
+    if multi::is_mixed(ui) {
+        return add_mixed_string(data, |text| {
+            crate::add_widget(
+                label,
+                egui::TextEdit::multiline(text)
+                    .desired_rows(nb_lines as usize)
+                    .hint_text(multi::MIXED),
+                tooltip,
+                read_only,
+                ui,
+            )
+        });
+    }
     crate::add_widget(
         label,
         egui::TextEdit::multiline(data).desired_rows(nb_lines as usize),
@@ -551,13 +1605,33 @@ pub fn add_string_multiline(
     )
 }
 
+/// Edits an empty text in place of a [mixed](is_mixed) string, replacing `data` once edited.
+fn add_mixed_string(
+    data: &mut dyn egui::TextBuffer,
+    add_editor: impl FnOnce(&mut String) -> InspectResponse,
+) -> InspectResponse {
+    let mut text = String::new();
+    let response = add_editor(&mut text);
+    if response.changed() {
+        data.replace_with(&text);
+    }
+    response
+}
+
 /// Adds a boolean checkbox.
 ///
 /// # See Also
 ///
 /// - [`egui::Checkbox`]
-pub fn add_bool(data: &mut bool, label: &str, tooltip: &str, read_only: bool, ui: &mut egui::Ui) {
-    crate::add_widget(label, egui::Checkbox::new(data, ""), tooltip, read_only, ui);
+pub fn add_bool(
+    data: &mut bool,
+    label: &str,
+    tooltip: &str,
+    read_only: bool,
+    ui: &mut egui::Ui,
+) -> InspectResponse {
+    let checkbox = egui::Checkbox::new(data, "").indeterminate(multi::is_mixed(ui));
+    crate::add_widget(label, checkbox, tooltip, read_only, ui)
 }
 
 /// Adds a color picker for [`egui::Color32`].
@@ -571,19 +1645,17 @@ pub fn add_color32(
     tooltip: &str,
     read_only: bool,
     ui: &mut egui::Ui,
-) {
+) -> InspectResponse {
+    if !search::is_field_visible(ui) {
+        return InspectResponse::default();
+    }
     let available_width = ui.available_width();
     let label_width = available_width * 0.4;
     //let field_width = 100.0f32.max(available_width * 0.6 - 15.0);
     ui.horizontal(|ui| {
         ui.add_enabled_ui(!read_only, |ui| {
-            let r = ui.add_sized(
-                [label_width, 0.],
-                egui::Label::new(label)
-                    .truncate()
-                    .show_tooltip_when_elided(true)
-                    .halign(egui::Align::LEFT),
-            );
+            let r = ui.add_sized([label_width, 0.], context::field_label(label, ui));
+            field_menu::add_field_menu(&r, read_only, ui);
             if !tooltip.is_empty() {
                 if !read_only {
                     r.on_hover_text(tooltip);
@@ -592,8 +1664,14 @@ pub fn add_color32(
                 }
             }
         });
-        ui.color_edit_button_srgba(data);
-    });
+        let response = ui.color_edit_button_srgba(data);
+        if multi::is_mixed(ui) {
+            ui.weak(multi::MIXED);
+        }
+        baseline::reset_button(read_only, ui);
+        response.into()
+    })
+    .inner
 }
 
 /// Adds a color picker for custom color types convertible to/from [`Color32Wrapper`].
@@ -601,7 +1679,13 @@ pub fn add_color32(
 /// # See Also
 ///
 /// - [`egui::Ui::color_edit_button_srgba`]
-pub fn add_color<T>(data: &mut T, label: &str, tooltip: &str, read_only: bool, ui: &mut egui::Ui)
+pub fn add_color<T>(
+    data: &mut T,
+    label: &str,
+    tooltip: &str,
+    read_only: bool,
+    ui: &mut egui::Ui,
+) -> InspectResponse
 where
     Color32Wrapper: From<T>,
     T: From<Color32Wrapper>,
@@ -609,10 +1693,12 @@ where
 {
     crate::add_custom_ui(label, tooltip, read_only, ui, |ui, _field_width| {
         let mut color: Color32Wrapper = data.clone().into();
-        if ui.color_edit_button_srgba(&mut color).changed() {
+        let response = ui.color_edit_button_srgba(&mut color);
+        if response.changed() {
             *data = color.into();
         }
-    });
+        response
+    })
 }
 
 /// Adds a [egui::ComboBox] to modify the index of chosed in the `choices` array.
@@ -630,16 +1716,25 @@ pub fn add_combobox(
     read_only: bool,
     choices: &[String],
     ui: &mut egui::Ui,
-) {
+) -> InspectResponse {
     //TODO: good management of id_salt
     crate::add_custom_ui(label, tooltip, read_only, ui, |ui, field_width| {
         egui::ComboBox::from_id_salt(label)
             .width(field_width)
-            .show_index(ui, current_index, choices.len(), |i| &choices[i]);
-    });
+            .show_index(ui, current_index, choices.len(), |i| &choices[i])
+    })
 }
 /// Add a [egui::Button]
-pub fn add_button<F>(label: &str, tooltip: &str, read_only: bool, ui: &mut egui::Ui, on_click: F)
+///
+/// As `on_click` usually modifies the inspected object, the returned [`InspectResponse`]
+/// is flagged as changed when the button is clicked.
+pub fn add_button<F>(
+    label: &str,
+    tooltip: &str,
+    read_only: bool,
+    ui: &mut egui::Ui,
+    on_click: F,
+) -> InspectResponse
 where
     F: FnOnce(&mut egui::Ui),
 {
@@ -650,51 +1745,169 @@ where
             if !tooltip.is_empty() {
                 r = r.on_hover_text(tooltip);
             }
+            let mut response = InspectResponse::default();
             if r.clicked() {
                 on_click(ui);
+                response.mark_changed();
             }
-        });
-    });
+            response
+        })
+        .inner
+    })
+    .inner
 }
 /// Add a single line text field which use string conversions to edit.
+///
+/// The value is updated as soon as the text can be parsed (see [`CommitMode::OnValid`]).
+/// See [`add_string_convertible_with_options`] for more control.
 pub fn add_string_convertible<T>(
     value: &mut T,
     label: &str,
     tooltip: &str,
     read_only: bool,
     ui: &mut Ui,
-) where
+) -> InspectResponse
+where
     T: FromStr + Display,
 {
-    let mut buffer = value.to_string();
-
-    buffer.inspect_with_custom_id(ui.next_auto_id().with(label), label, tooltip, read_only, ui);
-
-    if let Ok(parsed) = T::from_str(&buffer) {
-        *value = parsed;
-    } else {
-        ui.label("❌ Invalid format");
-    }
+    add_string_convertible_with_options(
+        value,
+        label,
+        tooltip,
+        read_only,
+        None,
+        CommitMode::OnValid,
+        invalid_format,
+        ui,
+    )
 }
 /// Add a multiline line text field which use string conversions to edit.
+///
+/// The value is updated as soon as the text can be parsed (see [`CommitMode::OnValid`]).
+/// See [`add_string_convertible_with_options`] for more control.
 pub fn add_string_convertible_multiline<T>(
     value: &mut T,
     label: &str,
     tooltip: &str,
     read_only: bool,
+    nb_lines: u8,
     ui: &mut Ui,
-) where
+) -> InspectResponse
+where
     T: FromStr + Display,
 {
-    let mut buffer = value.to_string();
+    add_string_convertible_with_options(
+        value,
+        label,
+        tooltip,
+        read_only,
+        Some(nb_lines),
+        CommitMode::OnValid,
+        invalid_format,
+        ui,
+    )
+}
 
-    crate::add_string_multiline(&mut buffer, label, tooltip, read_only, 4, ui);
+/// When a value edited through its string representation is updated from the typed text.
+///
+/// See [`add_string_convertible_with_options`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum CommitMode {
+    /// The value is updated as soon as the text can be parsed.
+    #[default]
+    OnValid,
+    /// The value is updated when Enter is pressed or when the text field loses the focus,
+    /// if the text can be parsed.
+    OnEnterOrFocusLost,
+}
 
-    if let Ok(parsed) = T::from_str(&buffer) {
-        *value = parsed;
+/// Add a text field which use string conversions to edit.
+///
+/// The text typed by the user is kept in `egui` memory while the field has the focus, or while
+/// it can't be parsed, so that intermediate invalid states (e.g. `"192.168."` while typing an
+/// [`std::net::Ipv4Addr`]) are not replaced by the current value. The text is stored for the
+/// [path of the field](current_field_path), so it stays on the field when the widgets before it
+/// change (e.g. an item inserted above it in a list). It is ignored by read-only fields.
+///
+/// - `nb_lines`: If set, use a multiline text field with the given number of rows.
+/// - `commit`: When the value is updated from the text.
+/// - `format_error`: Builds the message displayed when the text can't be parsed.
+///
+/// # Examples
+///
+/// ```rust
+/// use egui_field_editor::{add_string_convertible_with_options, CommitMode};
+/// fn ip_ui(ip: &mut std::net::Ipv4Addr, ui: &mut egui::Ui) {
+///     add_string_convertible_with_options(ip, "IP", "", false, None, CommitMode::OnEnterOrFocusLost,
+///         |e: &std::net::AddrParseError| e.to_string(), ui);
+/// }
+/// ```
+#[allow(clippy::too_many_arguments)]
+pub fn add_string_convertible_with_options<T, F>(
+    value: &mut T,
+    label: &str,
+    tooltip: &str,
+    read_only: bool,
+    nb_lines: Option<u8>,
+    commit: CommitMode,
+    format_error: F,
+    ui: &mut Ui,
+) -> InspectResponse
+where
+    T: FromStr + Display,
+    F: FnOnce(&T::Err) -> String,
+{
+    let id = ui.next_auto_id().with(label).with("from_string");
+    let path = current_field_path(ui);
+    // outside of an inspector, the fields have no path: the widget id is the best option
+    let buffer_id = if path.is_empty() {
+        id
     } else {
-        ui.label("❌ Invalid format");
+        ui.id().with(("egui_field_editor::from_string", path, label))
+    };
+    let mut buffer = ui
+        .data_mut(|d| d.get_temp::<String>(buffer_id))
+        .filter(|_| !read_only)
+        .unwrap_or_else(|| value.to_string());
+    let text_edit = match nb_lines {
+        Some(nb_lines) => egui::TextEdit::multiline(&mut buffer).desired_rows(nb_lines as usize),
+        None => egui::TextEdit::singleline(&mut buffer),
+    };
+    let mut response = crate::add_widget(label, text_edit.id(id), tooltip, read_only, ui);
+    let text_changed = response.changed;
+    response.changed = false;
+
+    let parsed = T::from_str(&buffer);
+    let commit_now = match commit {
+        CommitMode::OnValid => text_changed,
+        CommitMode::OnEnterOrFocusLost => response.lost_focus,
+    };
+    let is_valid = parsed.is_ok();
+    match parsed {
+        Ok(parsed) => {
+            if commit_now {
+                *value = parsed;
+                response.mark_changed();
+            }
+        }
+        Err(e) => {
+            ui.label(format!("❌ {}", format_error(&e)));
+        }
+    }
+    let has_focus = ui.memory(|m| m.has_focus(id));
+    // a read-only field keeps the text typed in another field with the same path (e.g. in a diff view)
+    if !read_only {
+        if has_focus || !is_valid {
+            ui.data_mut(|d| d.insert_temp(buffer_id, buffer));
+        } else {
+            ui.data_mut(|d| d.remove::<String>(buffer_id));
+        }
     }
+    response
+}
+/// Default error message of [`add_string_convertible`].
+pub(crate) fn invalid_format<E>(_: &E) -> String {
+    "Invalid format".to_owned()
 }
 /// Adds a date picker for date types.
 ///
@@ -732,7 +1945,7 @@ pub fn add_date(
     highlight_weekends: bool,
     start_end_years: Option<RangeInclusive<i32>>,
     ui: &mut egui::Ui,
-) {
+) -> InspectResponse {
     let id = if parent_id == egui::Id::NULL {
         egui::Id::NULL
     } else {
@@ -759,9 +1972,9 @@ pub fn add_date(
             tooltip,
             read_only,
             ui,
-        );
+        )
     } else {
-        crate::add_widget(label, widget, tooltip, read_only, ui);
+        crate::add_widget(label, widget, tooltip, read_only, ui)
     }
 }
 
@@ -773,8 +1986,9 @@ pub fn add_path(
     read_only: bool,
     _filters: Vec<&str>,
     ui: &mut egui::Ui,
-) {
+) -> InspectResponse {
     add_custom_ui(label, tooltip, read_only, ui, |ui, field_width| {
+        let mut response = InspectResponse::default();
         if let Some(path) = data.to_str() {
             let mut path = path.to_string();
             #[cfg(all(feature = "filepicker", not(target_arch = "wasm32")))]
@@ -784,10 +1998,12 @@ pub fn add_path(
                 field_width
             };
 
-            ui.add_enabled(
-                !read_only,
-                egui::TextEdit::singleline(&mut path).desired_width(field_width),
-            );
+            response = ui
+                .add_enabled(
+                    !read_only,
+                    egui::TextEdit::singleline(&mut path).desired_width(field_width),
+                )
+                .into();
             *data = path.into();
             #[cfg(all(feature = "filepicker", not(target_arch = "wasm32")))]
             if !read_only && ui.button("...").clicked() {
@@ -801,10 +2017,12 @@ pub fn add_path(
                 let filepath = fd.pick_file();
                 if let Some(filepath) = filepath {
                     *data = filepath;
+                    response.mark_changed();
                 }
             }
         }
-    });
+        response
+    })
 }
 
 /// An utility wrapper around [`egui::Color32`].
@@ -976,3 +2194,20 @@ impl_mat_inspect!(
 );
 
 mod base_type_inspect;
+mod baseline;
+mod changes;
+mod context;
+#[doc(hidden)]
+pub mod derive_support;
+mod diff_view;
+mod draft;
+mod enum_style;
+mod field_menu;
+mod history;
+mod list;
+mod multi;
+mod pages;
+#[cfg(feature = "serde")]
+mod persistence;
+mod range;
+mod search;
@@ -0,0 +1,256 @@
+//! Editing actions of the lists: the context menu of the items ("Insert above", "Duplicate",
+//! "Move to top"...), the Clear button and the limits of their length.
+//!
+//! The code generated by `#[derive(EguiInspect)]` registers the [`ListLimits`] given by the
+//! `min_len`, `max_len` and `fixed_len` attributes, and the [`ItemKeys`] given by the `key`
+//! attribute, before rendering a field, and the list rendered for this field takes them (see
+//! [`ListLimits::take`]).
+
+use std::collections::HashSet;
+
+use egui::{Id, Response, Ui};
+
+use crate::{context::InspectContext, InspectResponse};
+
+/// Limits of the length of a list, disabling the buttons which would break them.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ListLimits {
+    /// Items can't be removed below this length.
+    pub min_len: usize,
+    /// Items can't be added above this length.
+    pub max_len: Option<usize>,
+    /// Items can't be added nor removed, only reordered.
+    pub fixed_len: bool,
+}
+
+impl ListLimits {
+    /// Limits of a list whose length can't change, e.g. an array.
+    pub const FIXED: Self = Self {
+        min_len: 0,
+        max_len: None,
+        fixed_len: true,
+    };
+
+    /// Makes the limits available to the list of the field being rendered.
+    pub fn register(self, ui: &Ui) {
+        InspectContext::with(ui.ctx(), |context| context.list_limits = Some(self));
+    }
+    /// Takes the limits registered for the field being rendered, if any.
+    pub(crate) fn take(ui: &Ui) -> Self {
+        InspectContext::with(ui.ctx(), |context| context.list_limits.take()).unwrap_or_default()
+    }
+    /// `true` if an item can be added to a list of `len` items.
+    pub(crate) fn can_insert(&self, len: usize) -> bool {
+        !self.fixed_len && self.max_len.is_none_or(|max_len| len < max_len)
+    }
+    /// `true` if an item can be removed from a list of `len` items.
+    pub(crate) fn can_remove(&self, len: usize) -> bool {
+        !self.fixed_len && len > self.min_len
+    }
+}
+
+/// Stable key of the items of a list, see the `key` attribute of `#[derive(EguiInspect)]`.
+///
+/// The key is shown as the label of the item instead of `Item {index}`, and the [`egui::Id`]s
+/// of the item are built from it, so that its collapsed headers and text cursors follow it when
+/// the list is reordered. It should be unique in the list.
+///
+/// ```rust
+/// use egui_field_editor::{EguiInspect, InspectKey};
+/// #[derive(EguiInspect, Default)]
+/// struct Layer {
+///     name: String,
+///     opacity: f32,
+/// }
+/// impl InspectKey for Layer {
+///     fn inspect_key(&self) -> String {
+///         self.name.clone()
+///     }
+/// }
+/// #[derive(EguiInspect)]
+/// struct Image {
+///     #[inspect(key)]
+///     layers: Vec<Layer>,
+/// }
+/// ```
+pub trait InspectKey {
+    /// Key of the item, unique in its list.
+    fn inspect_key(&self) -> String;
+}
+
+/// Keys of the items of the list of the field being rendered, see [`InspectKey`].
+#[derive(Clone, Debug, Default)]
+pub struct ItemKeys {
+    /// Label of each item: its key, with its index if it is empty or already used.
+    labels: Vec<String>,
+}
+
+impl ItemKeys {
+    /// Computes the keys of `items` with `key`.
+    pub fn of<'a, T: 'a, K: ToString>(items: impl IntoIterator<Item = &'a T>, key: impl Fn(&'a T) -> K) -> Self {
+        let mut used = HashSet::new();
+        let labels = items
+            .into_iter()
+            .enumerate()
+            .map(|(index, item)| {
+                let key = key(item).to_string();
+                if key.is_empty() {
+                    format!("Item {index}")
+                } else if used.insert(key.clone()) {
+                    key
+                } else {
+                    // the labels give their id to the items, they must be unique
+                    format!("{key} ({index})")
+                }
+            })
+            .collect();
+        Self { labels }
+    }
+    /// Makes the keys available to the list of the field being rendered.
+    pub fn register(self, ui: &Ui) {
+        InspectContext::with(ui.ctx(), |context| context.item_keys = Some(self));
+    }
+    /// Takes the keys registered for the field being rendered, if any.
+    pub(crate) fn take(ui: &Ui) -> Option<Self> {
+        InspectContext::with(ui.ctx(), |context| context.item_keys.take())
+    }
+}
+
+/// Label of the item `index` of a list, from its key if the list has [`ItemKeys`].
+///
+/// The [`egui::Id`]s of the item are built from its label.
+pub(crate) fn item_label(keys: Option<&ItemKeys>, index: usize) -> String {
+    keys.and_then(|keys| keys.labels.get(index))
+        .cloned()
+        .unwrap_or_else(|| format!("Item {index}"))
+}
+
+/// Action selected in the context menu of an item.
+pub(crate) enum ItemAction<T> {
+    InsertAbove,
+    InsertBelow,
+    /// Inserts the given copy of the item below it.
+    Duplicate(T),
+    Remove,
+    MoveToTop,
+    MoveToBottom,
+}
+
+/// Adds the context menu of the item `index` of a list of `len` items to `response` (its drag
+/// handle), and returns the selected action.
+///
+/// "Duplicate" is only offered if `duplicate` can copy the item.
+pub(crate) fn item_menu<T>(
+    response: &Response,
+    item: &T,
+    index: usize,
+    len: usize,
+    limits: ListLimits,
+    duplicate: Option<fn(&T) -> Option<T>>,
+) -> Option<ItemAction<T>> {
+    let mut action = None;
+    response.context_menu(|ui| {
+        let can_insert = limits.can_insert(len);
+        if ui.add_enabled(can_insert, egui::Button::new("Insert above")).clicked() {
+            action = Some(ItemAction::InsertAbove);
+        }
+        if ui.add_enabled(can_insert, egui::Button::new("Insert below")).clicked() {
+            action = Some(ItemAction::InsertBelow);
+        }
+        if let Some(duplicate) = duplicate.filter(|_| !limits.fixed_len) {
+            let copy = duplicate(item);
+            let button = ui.add_enabled(can_insert && copy.is_some(), egui::Button::new("Duplicate"));
+            if button.clicked() {
+                action = copy.map(ItemAction::Duplicate);
+            }
+        }
+        if ui.add_enabled(limits.can_remove(len), egui::Button::new("Remove")).clicked() {
+            action = Some(ItemAction::Remove);
+        }
+        ui.separator();
+        if ui.add_enabled(index > 0, egui::Button::new("Move to top")).clicked() {
+            action = Some(ItemAction::MoveToTop);
+        }
+        if ui.add_enabled(index + 1 < len, egui::Button::new("Move to bottom")).clicked() {
+            action = Some(ItemAction::MoveToBottom);
+        }
+    });
+    action
+}
+
+/// Applies the moves of `action` to the item `index` of `items`.
+///
+/// Returns `false` if the action changes the length of the list.
+pub(crate) fn move_item<T>(items: &mut [T], index: usize, action: &ItemAction<T>) -> bool {
+    match action {
+        ItemAction::MoveToTop => items[..=index].rotate_right(1),
+        ItemAction::MoveToBottom => items[index..].rotate_left(1),
+        _ => return false,
+    }
+    true
+}
+
+/// Applies `action` to the item `index` of `items`, new items being built with `T::default()`.
+pub(crate) fn apply_item_action<T: Default>(items: &mut Vec<T>, index: usize, action: ItemAction<T>) {
+    if move_item(items, index, &action) {
+        return;
+    }
+    match action {
+        ItemAction::InsertAbove => items.insert(index, T::default()),
+        ItemAction::InsertBelow => items.insert(index + 1, T::default()),
+        ItemAction::Duplicate(copy) => items.insert(index + 1, copy),
+        ItemAction::Remove => {
+            items.remove(index);
+        }
+        ItemAction::MoveToTop | ItemAction::MoveToBottom => {}
+    }
+}
+
+/// Shows the `+`, `-` and Clear buttons below a list, Clear asking for a confirmation.
+pub(crate) fn list_buttons<T: Default>(
+    items: &mut Vec<T>,
+    id: Id,
+    limits: ListLimits,
+    read_only: bool,
+    ui: &mut Ui,
+) -> InspectResponse {
+    let mut response = InspectResponse::default();
+    let confirm_id = id.with("egui_field_editor::clear");
+    let mut confirm = ui.data(|d| d.get_temp::<bool>(confirm_id)).unwrap_or(false);
+    ui.add_enabled_ui(!read_only, |ui| {
+        ui.horizontal_top(|ui| {
+            if confirm {
+                ui.label(format!("Remove the {} items?", items.len()));
+                if ui.button("Clear").clicked() {
+                    items.clear();
+                    response.mark_changed();
+                    confirm = false;
+                }
+                if ui.button("Cancel").clicked() {
+                    confirm = false;
+                }
+                return;
+            }
+            ui.add_space(ui.available_width() - 78.);
+            let can_clear = !items.is_empty() && !limits.fixed_len && limits.min_len == 0;
+            if ui
+                .add_enabled(can_clear, egui::Button::new("🗑").min_size(egui::Vec2::new(20., 20.)))
+                .on_hover_text("Remove every item")
+                .clicked()
+            {
+                confirm = true;
+            }
+            let add = egui::Button::new("+").min_size(egui::Vec2::new(20., 20.));
+            if ui.add_enabled(limits.can_insert(items.len()), add).clicked() {
+                items.push(T::default());
+                response.mark_changed();
+            }
+            let remove = egui::Button::new("-").min_size(egui::Vec2::new(20., 20.));
+            if ui.add_enabled(limits.can_remove(items.len()), remove).clicked() && items.pop().is_some() {
+                response.mark_changed();
+            }
+        });
+    });
+    ui.data_mut(|d| d.insert_temp(confirm_id, confirm));
+    response
+}
@@ -0,0 +1,70 @@
+use std::{collections::BTreeSet, sync::Arc};
+
+use egui::{Id, Ui};
+
+use crate::{context::InspectContext, diff_paths, search, EguiInspect, InspectResponse};
+
+/// Text shown instead of the value of a field differing between the edited objects.
+pub const MIXED: &str = "—";
+
+/// Renders several objects at once, see [`EguiInspector::multi`](crate::EguiInspector::multi).
+pub(crate) type ShowMulti<T> = fn(&mut [&mut T], Id, &str, bool, &mut Ui) -> InspectResponse;
+
+/// `true` if the field being rendered has different values in the objects edited by
+/// [`EguiInspector::multi`](crate::EguiInspector::multi).
+///
+/// Manual implementations of [`EguiInspect`] can use it to show a mixed value, the `add_*`
+/// helpers already do.
+pub fn is_mixed(ui: &Ui) -> bool {
+    InspectContext::with(ui.ctx(), |context| {
+        context.mixed.as_ref().is_some_and(|mixed| mixed.contains(context.path()))
+    })
+}
+
+/// Renders the first object, showing the fields differing from the other objects as mixed,
+/// then copies the edited fields to the other objects.
+pub(crate) fn show<T: EguiInspect + Clone>(
+    objs: &mut [&mut T],
+    id: Id,
+    query: &str,
+    read_only: bool,
+    ui: &mut Ui,
+) -> InspectResponse {
+    let Some((first, others)) = objs.split_first_mut() else {
+        ui.weak("No object");
+        return InspectResponse::default();
+    };
+    let mixed: BTreeSet<String> = others.iter().flat_map(|other| diff_paths(&**first, &**other)).collect();
+    let mixed = Arc::new(mixed);
+    let before = first.clone();
+    let response = search::scope(ui, id, None, query, |ui, id| {
+        InspectContext::with(ui.ctx(), |context| context.mixed = Some(mixed.clone()));
+        if id == Id::NULL {
+            first.inspect("", "", read_only, ui)
+        } else {
+            first.inspect_with_custom_id(id, "", "", read_only, ui)
+        }
+    });
+    if response.changed() {
+        for path in diff_paths(&before, &**first) {
+            for other in others.iter_mut() {
+                copy_field(other, first, &path);
+            }
+        }
+    }
+    response
+}
+
+/// Copies the field at `path` from `source` to `target`, or its closest parent which can be
+/// copied (e.g. the whole list when `target` has less items than `source`).
+fn copy_field<T: EguiInspect>(target: &mut T, source: &T, mut path: &str) {
+    loop {
+        if target.copy_field_from(source, path).is_ok() || path.is_empty() {
+            return;
+        }
+        path = match path.rfind(['.', '[']) {
+            Some(end) => &path[..end],
+            None => "",
+        };
+    }
+}
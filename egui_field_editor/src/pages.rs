@@ -0,0 +1,68 @@
+use std::ops::Range;
+
+use egui::{Id, Ui};
+
+use crate::{context::InspectContext, search};
+
+/// Number of items shown per page by default, see
+/// [`EguiInspector::with_page_size`](crate::EguiInspector::with_page_size).
+pub(crate) const DEFAULT_PAGE_SIZE: usize = 100;
+
+/// Shows the page selector of a list of `len` items if they don't fit in one page, and
+/// returns the range of the items of the current page.
+///
+/// Only the items of the current page are rendered, so that lists with many thousands of
+/// items stay responsive. The current page is kept in `egui` memory with `id`.
+pub(crate) fn page_range(len: usize, id: Id, ui: &mut Ui) -> Range<usize> {
+    let page_size = InspectContext::with(ui.ctx(), |context| context.page_size)
+        .unwrap_or(DEFAULT_PAGE_SIZE)
+        .max(1);
+    if len <= page_size {
+        return 0..len;
+    }
+    let page_count = len.div_ceil(page_size);
+    let id = id.with("egui_field_editor::page");
+    // the list may have been shortened since the last frame
+    let mut page = ui.data(|d| d.get_temp::<usize>(id)).unwrap_or(0).min(page_count - 1);
+    ui.horizontal(|ui| {
+        if ui.add_enabled(page > 0, egui::Button::new("◀")).on_hover_text("Previous page").clicked() {
+            page -= 1;
+        }
+        let start = page * page_size;
+        ui.label(format!("{start}..{} of {len}", (start + page_size).min(len)));
+        if ui
+            .add_enabled(page + 1 < page_count, egui::Button::new("▶"))
+            .on_hover_text("Next page")
+            .clicked()
+        {
+            page += 1;
+        }
+        let mut index = start;
+        let jump = egui::DragValue::new(&mut index).range(0..=len - 1).prefix("Go to ");
+        if ui.add(jump).on_hover_text("Show the page of this item").changed() {
+            page = index / page_size;
+        }
+    });
+    ui.data_mut(|d| d.insert_temp(id, page));
+    let start = page * page_size;
+    start..(start + page_size).min(len)
+}
+
+/// Indices of the items of a list of `len` items to render, in order: those of the current page
+/// (see [`page_range`]).
+///
+/// While searching, every item is rendered to find the matching fields, then the pages are
+/// those of the visible items (see [`search::visible_items`]), kept apart from the page shown
+/// without a search.
+pub(crate) fn page_items(len: usize, id: Id, ui: &mut Ui) -> Vec<usize> {
+    if !search::is_active(ui) {
+        return page_range(len, id, ui).collect();
+    }
+    match search::visible_items(len, ui) {
+        Some(items) => {
+            let range = page_range(items.len(), id.with("egui_field_editor::search"), ui);
+            items[range].to_vec()
+        }
+        None => (0..len).collect(),
+    }
+}
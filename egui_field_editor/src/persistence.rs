@@ -0,0 +1,252 @@
+use std::path::{Path, PathBuf};
+
+use egui::{Id, Ui};
+use serde::{Serialize, de::DeserializeOwned};
+
+use crate::InspectResponse;
+
+/// File format used by [`EguiInspector::with_persistence`](crate::EguiInspector::with_persistence).
+///
+/// The functions of this enum can also be used to save or load an object outside of an inspector.
+///
+/// # Examples
+///
+/// ```rust
+/// use egui_field_editor::Format;
+/// #[derive(serde::Serialize, serde::Deserialize, PartialEq, Debug)]
+/// struct Config {
+///     name: String,
+///     port: u16,
+/// }
+/// let path = std::env::temp_dir().join("egui_field_editor_format_example.ron");
+/// let config = Config { name: "server".to_owned(), port: 8080 };
+/// Format::Ron.save(&config, &path).unwrap();
+/// assert_eq!(Format::from_path(&path), Some(Format::Ron));
+/// assert_eq!(Format::Ron.load::<Config>(&path).unwrap(), config);
+/// assert!(Format::Json.load::<Config>(&path).is_err());
+/// # std::fs::remove_file(&path).unwrap();
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Format {
+    /// [JSON](https://www.json.org), using [`serde_json`].
+    Json,
+    /// [RON](https://github.com/ron-rs/ron), using [`ron`].
+    Ron,
+    /// [TOML](https://toml.io), using [`toml`].
+    Toml,
+}
+
+impl Format {
+    /// Usual file extension of the format.
+    pub fn extension(self) -> &'static str {
+        match self {
+            Format::Json => "json",
+            Format::Ron => "ron",
+            Format::Toml => "toml",
+        }
+    }
+    /// Guesses the format of a file from its extension.
+    pub fn from_path(path: impl AsRef<Path>) -> Option<Self> {
+        let extension = path.as_ref().extension()?.to_str()?.to_ascii_lowercase();
+        [Format::Json, Format::Ron, Format::Toml]
+            .into_iter()
+            .find(|format| format.extension() == extension)
+    }
+    /// Serializes `value` in a human readable way.
+    pub fn serialize<T: Serialize + ?Sized>(self, value: &T) -> Result<String, String> {
+        match self {
+            Format::Json => serde_json::to_string_pretty(value).map_err(|e| e.to_string()),
+            Format::Ron => ron::ser::to_string_pretty(value, ron::ser::PrettyConfig::default())
+                .map_err(|e| e.to_string()),
+            Format::Toml => toml::to_string_pretty(value).map_err(|e| e.to_string()),
+        }
+    }
+    /// Deserializes a value from `text`.
+    pub fn deserialize<T: DeserializeOwned>(self, text: &str) -> Result<T, String> {
+        match self {
+            Format::Json => serde_json::from_str(text).map_err(|e| e.to_string()),
+            Format::Ron => ron::from_str(text).map_err(|e| e.to_string()),
+            Format::Toml => toml::from_str(text).map_err(|e| e.to_string()),
+        }
+    }
+    /// Writes `value` to the file at `path`.
+    pub fn save<T: Serialize + ?Sized>(self, value: &T, path: impl AsRef<Path>) -> Result<(), String> {
+        let path = path.as_ref();
+        let text = self.serialize(value)?;
+        std::fs::write(path, text).map_err(|e| format!("Failed to write {}: {e}", path.display()))
+    }
+    /// Reads a value from the file at `path`.
+    pub fn load<T: DeserializeOwned>(self, path: impl AsRef<Path>) -> Result<T, String> {
+        let path = path.as_ref();
+        let text = std::fs::read_to_string(path)
+            .map_err(|e| format!("Failed to read {}: {e}", path.display()))?;
+        self.deserialize(&text)
+    }
+}
+
+/// File an [`EguiInspector`](crate::EguiInspector) saves the inspected object to.
+pub(crate) struct Persistence<T> {
+    path: PathBuf,
+    format: Format,
+    serialize: fn(&T, Format) -> Result<String, String>,
+    deserialize: fn(&str, Format) -> Result<T, String>,
+}
+
+/// State of the persistence toolbar, kept in `egui` memory between frames.
+#[derive(Clone, Default)]
+struct PersistenceState {
+    /// Path given to the inspector, the state is reset when it changes.
+    initial_path: PathBuf,
+    /// Path and format used by Save/Load (changed by "Save As").
+    path: PathBuf,
+    format: Option<Format>,
+    /// Serialized value of the object when it was last loaded or saved.
+    saved: Option<String>,
+    modified: bool,
+    error: Option<String>,
+}
+
+impl<T: Serialize + DeserializeOwned> Persistence<T> {
+    pub(crate) fn new(path: PathBuf, format: Format) -> Self {
+        Self {
+            path,
+            format,
+            serialize: |value, format| format.serialize(value),
+            deserialize: |text, format| format.deserialize(text),
+        }
+    }
+}
+
+impl<T> Persistence<T> {
+    fn state_id(id_salt: Option<Id>, ui: &Ui) -> Id {
+        ui.make_persistent_id(("egui_field_editor::persistence", id_salt))
+    }
+    fn load_state(&self, obj: &T, id: Id, ui: &Ui) -> PersistenceState {
+        let state = ui.data(|d| d.get_temp::<PersistenceState>(id));
+        match state {
+            Some(state) if state.initial_path == self.path => state,
+            _ => {
+                // compare with the file content, if any, to know whether the object is modified
+                let format = self.format;
+                let saved = std::fs::read_to_string(&self.path)
+                    .ok()
+                    .and_then(|text| (self.deserialize)(&text, format).ok())
+                    .and_then(|value| (self.serialize)(&value, format).ok());
+                let mut state = PersistenceState {
+                    initial_path: self.path.clone(),
+                    path: self.path.clone(),
+                    format: Some(format),
+                    saved,
+                    ..Default::default()
+                };
+                self.update_modified(&mut state, obj);
+                state
+            }
+        }
+    }
+    fn update_modified(&self, state: &mut PersistenceState, obj: &T) {
+        let format = state.format.unwrap_or(self.format);
+        state.modified = state.saved.is_none() || state.saved != (self.serialize)(obj, format).ok();
+    }
+
+    /// Shows the Save/Load/Save As buttons above the inspected fields.
+    ///
+    /// Returns an [`InspectResponse`] marked as changed if `obj` was loaded from the file.
+    pub(crate) fn toolbar(&self, obj: &mut T, read_only: bool, id_salt: Option<Id>, ui: &mut Ui) -> InspectResponse {
+        let mut response = InspectResponse::default();
+        if let Some(loaded) = self.show(obj, !read_only, id_salt, ui) {
+            *obj = loaded;
+            response.mark_changed();
+        }
+        response
+    }
+    /// Same as [`Self::toolbar`] for objects which can't be modified: "Load" is disabled.
+    pub(crate) fn toolbar_read_only(&self, obj: &T, id_salt: Option<Id>, ui: &mut Ui) {
+        self.show(obj, false, id_salt, ui);
+    }
+    /// Updates the "modified" indicator once the object was edited.
+    ///
+    /// The object is only serialized when `response` reports an edit.
+    pub(crate) fn record(&self, obj: &T, response: InspectResponse, id_salt: Option<Id>, ui: &Ui) {
+        if !response.changed() {
+            return;
+        }
+        let id = Self::state_id(id_salt, ui);
+        let mut state = self.load_state(obj, id, ui);
+        self.update_modified(&mut state, obj);
+        ui.data_mut(|d| d.insert_temp(id, state));
+    }
+
+    /// Returns the loaded value, if any.
+    fn show(&self, obj: &T, can_load: bool, id_salt: Option<Id>, ui: &mut Ui) -> Option<T> {
+        let id = Self::state_id(id_salt, ui);
+        let mut state = self.load_state(obj, id, ui);
+        let format = state.format.unwrap_or(self.format);
+        let mut loaded = None;
+        ui.horizontal(|ui| {
+            if ui.button("💾 Save").clicked() {
+                self.save(obj, &mut state);
+            }
+            if ui.add_enabled(can_load, egui::Button::new("📂 Load")).clicked() {
+                match std::fs::read_to_string(&state.path)
+                    .map_err(|e| format!("Failed to read {}: {e}", state.path.display()))
+                    .and_then(|text| (self.deserialize)(&text, format))
+                {
+                    Ok(value) => {
+                        state.saved = (self.serialize)(&value, format).ok();
+                        state.modified = false;
+                        state.error = None;
+                        loaded = Some(value);
+                    }
+                    Err(e) => state.error = Some(e),
+                }
+            }
+            #[cfg(all(feature = "filepicker", not(target_arch = "wasm32")))]
+            if ui.button("Save As…").clicked() {
+                let mut dialog = rfd::FileDialog::new();
+                let other_formats = [Format::Json, Format::Ron, Format::Toml]
+                    .into_iter()
+                    .filter(|other| *other != format);
+                // the current format first, so that it is selected by default
+                for filter in std::iter::once(format).chain(other_formats) {
+                    dialog = dialog.add_filter(format!("{filter:?}"), &[filter.extension()]);
+                }
+                if let Some(file_name) = state.path.file_name() {
+                    dialog = dialog.set_file_name(file_name.to_string_lossy());
+                }
+                if let Some(path) = dialog.save_file() {
+                    state.format = Some(Format::from_path(&path).unwrap_or(format));
+                    state.path = path;
+                    self.save(obj, &mut state);
+                }
+            }
+            ui.label(state.path.file_name().map(|name| name.to_string_lossy()).unwrap_or_default())
+                .on_hover_text(state.path.display().to_string());
+            if state.modified {
+                ui.colored_label(ui.visuals().warn_fg_color, "● Modified since load")
+                    .on_hover_text("The inspected value differs from the file content");
+            }
+        });
+        if let Some(error) = &state.error {
+            ui.colored_label(ui.visuals().error_fg_color, format!("❌ {error}"));
+        }
+        ui.data_mut(|d| d.insert_temp(id, state));
+        loaded
+    }
+    fn save(&self, obj: &T, state: &mut PersistenceState) {
+        let format = state.format.unwrap_or(self.format);
+        let result = (self.serialize)(obj, format).and_then(|text| {
+            std::fs::write(&state.path, &text)
+                .map(|_| text)
+                .map_err(|e| format!("Failed to write {}: {e}", state.path.display()))
+        });
+        match result {
+            Ok(text) => {
+                state.saved = Some(text);
+                state.modified = false;
+                state.error = None;
+            }
+            Err(e) => state.error = Some(e),
+        }
+    }
+}
@@ -0,0 +1,125 @@
+//! Ranges of numbers, see [`crate::add_range`] and [`crate::add_range_slider`].
+
+use std::ops::{Range, RangeInclusive};
+
+use egui::{emath::Numeric, Response, Sense, Ui};
+
+/// A range of numbers edited by [`crate::add_range`] and [`crate::add_range_slider`].
+///
+/// Implemented by [`Range`] and [`RangeInclusive`].
+pub trait NumericRange {
+    /// Type of the bounds.
+    type Num: Numeric;
+    /// The start and end of the range.
+    fn bounds(&self) -> (Self::Num, Self::Num);
+    /// Replaces the start and end of the range.
+    fn set_bounds(&mut self, start: Self::Num, end: Self::Num);
+}
+
+impl<Num: Numeric> NumericRange for Range<Num> {
+    type Num = Num;
+    fn bounds(&self) -> (Num, Num) {
+        (self.start, self.end)
+    }
+    fn set_bounds(&mut self, start: Num, end: Num) {
+        *self = start..end;
+    }
+}
+
+impl<Num: Numeric> NumericRange for RangeInclusive<Num> {
+    type Num = Num;
+    fn bounds(&self) -> (Num, Num) {
+        (*self.start(), *self.end())
+    }
+    fn set_bounds(&mut self, start: Num, end: Num) {
+        *self = start..=end;
+    }
+}
+
+/// `value` converted to `Num`, rounded for integers.
+fn from_f64<Num: Numeric>(value: f64) -> Num {
+    Num::from_f64(if Num::INTEGRAL { value.round() } else { value })
+}
+
+/// A slider with a handle for `start` and another for `end`, between `min` and `max`.
+///
+/// The handle nearest to the pointer is dragged, and can't go past the other one.
+pub(crate) fn range_slider<Num: Numeric>(
+    start: &mut Num,
+    end: &mut Num,
+    min: Num,
+    max: Num,
+    width: f32,
+    ui: &mut Ui,
+) -> Response {
+    let height = ui.spacing().interact_size.y;
+    let (rect, mut response) = ui.allocate_exact_size(egui::vec2(width, height), Sense::click_and_drag());
+    let radius = rect.height() / 2.5;
+    let rail = rect.shrink2(egui::vec2(radius, 0.));
+    let (min, max) = (min.to_f64(), max.to_f64());
+    let to_x = |value: Num| {
+        egui::remap_clamp(value.to_f64(), min..=max, rail.left() as f64..=rail.right() as f64) as f32
+    };
+
+    if let Some(pointer) = response.interact_pointer_pos() {
+        // which handle is dragged, kept until the pointer is released
+        let dragging_end_id = response.id.with("egui_field_editor::dragging_end");
+        let dragging_end = if response.drag_started() || response.clicked() {
+            let dragging_end = (pointer.x - to_x(*end)).abs() < (pointer.x - to_x(*start)).abs()
+                || (*start == *end && pointer.x > to_x(*end));
+            ui.data_mut(|d| d.insert_temp(dragging_end_id, dragging_end));
+            dragging_end
+        } else {
+            ui.data(|d| d.get_temp(dragging_end_id)).unwrap_or_default()
+        };
+        let value: Num = from_f64(egui::remap_clamp(
+            pointer.x as f64,
+            rail.left() as f64..=rail.right() as f64,
+            min..=max,
+        ));
+        let (bound, limit) = if dragging_end { (&mut *end, *start) } else { (&mut *start, *end) };
+        let value = if dragging_end == (value < limit) { limit } else { value };
+        if *bound != value {
+            *bound = value;
+            response.mark_changed();
+        }
+    }
+
+    if ui.is_rect_visible(rect) {
+        let visuals = ui.style().interact(&response);
+        let rail_rect = egui::Rect::from_center_size(rect.center(), egui::vec2(rail.width(), height / 4.));
+        let painter = ui.painter();
+        painter.rect_filled(rail_rect, rail_rect.height() / 2., ui.visuals().widgets.inactive.bg_fill);
+        let selected = egui::Rect::from_x_y_ranges(to_x(*start)..=to_x(*end), rail_rect.y_range());
+        painter.rect_filled(selected, rail_rect.height() / 2., ui.visuals().selection.bg_fill);
+        for value in [*start, *end] {
+            let center = egui::pos2(to_x(value), rect.center().y);
+            painter.circle(center, radius, visuals.bg_fill, visuals.fg_stroke);
+        }
+    }
+    response
+}
+
+/// Two drag values editing `start` and `end`, the start can't go past the end.
+pub(crate) fn range_drag_values<Num: Numeric>(
+    start: &mut Num,
+    end: &mut Num,
+    minmax: Option<(Num, Num)>,
+    width: f32,
+    ui: &mut Ui,
+) -> Response {
+    let (min, max) = minmax.unwrap_or((Num::MIN, Num::MAX));
+    let width = (width - ui.spacing().item_spacing.x) / 2.;
+    let mixed = crate::is_mixed(ui);
+    let drag_value = |value: &mut Num, range: RangeInclusive<Num>, hover: &str, ui: &mut Ui| {
+        let mut editor = egui::DragValue::new(value).range(range);
+        if mixed {
+            editor = editor.custom_formatter(|_, _| crate::multi::MIXED.to_owned());
+        }
+        ui.add_sized([width, 0.], editor).on_hover_text(hover)
+    };
+    let end_value = *end;
+    let response = drag_value(start, min..=end_value, "Start", ui);
+    let start_value = *start;
+    response | drag_value(end, start_value..=max, "End", ui)
+}
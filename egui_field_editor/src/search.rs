@@ -0,0 +1,208 @@
+//! Filtering of the inspected fields by label, see
+//! [`EguiInspector::with_search`](crate::EguiInspector::with_search).
+//!
+//! Whether a collapsed header contains a matching field can't be known without rendering its
+//! content, so the object is first rendered in an invisible `Ui` with every header open to
+//! find the paths of the matching labels, then rendered again with only these fields (and
+//! their parents and children) visible. The matching items of a list are paginated like its
+//! items without a search, see [`crate::pages::page_items`].
+
+use std::{collections::BTreeSet, sync::Arc};
+
+use egui::{text::LayoutJob, Color32, Id, TextFormat, Ui, UiBuilder};
+
+use crate::context::InspectContext;
+
+/// Search state of the [`InspectContext`].
+#[derive(Clone, Default)]
+pub(crate) struct Search {
+    /// The query, in lowercase.
+    query: String,
+    /// Paths of the fields whose label matches the query, `None` while they are being found.
+    matches: Option<Arc<BTreeSet<String>>>,
+    /// Paths found so far by the invisible pass.
+    found: BTreeSet<String>,
+}
+
+/// `true` if `path` is `other` or one of its parents.
+fn contains(path: &str, other: &str) -> bool {
+    other
+        .strip_prefix(path)
+        .is_some_and(|rest| rest.is_empty() || path.is_empty() || rest.starts_with(['.', '[']))
+}
+
+/// Shows the search field of an inspector and returns the query.
+pub(crate) fn search_bar(id_salt: Option<Id>, ui: &mut Ui) -> String {
+    let id = ui.make_persistent_id(("egui_field_editor::search", id_salt));
+    let mut query = ui.data(|d| d.get_temp::<String>(id)).unwrap_or_default();
+    ui.horizontal(|ui| {
+        ui.label("🔍");
+        ui.add(
+            egui::TextEdit::singleline(&mut query)
+                .hint_text("Search fields")
+                .desired_width(ui.available_width() - 30.),
+        );
+        if !query.is_empty() && ui.small_button("✖").on_hover_text("Clear the search").clicked() {
+            query.clear();
+        }
+    });
+    ui.data_mut(|d| d.insert_temp(id, query.clone()));
+    query
+}
+
+/// Renders the inspected object with [`InspectContext::scope`], only showing the fields
+/// matching `query` if it isn't empty.
+///
+/// `add_contents` is called twice while searching: it is given the [`Id`] to use, `id` itself
+/// for the visible pass so that the state of the widgets is kept.
+pub(crate) fn scope<R>(
+    ui: &mut Ui,
+    id: Id,
+    modified: Option<BTreeSet<String>>,
+    query: &str,
+    mut add_contents: impl FnMut(&mut Ui, Id) -> R,
+) -> R {
+    let query = query.trim().to_lowercase();
+    if query.is_empty() {
+        return InspectContext::scope(ui, modified, |ui| add_contents(ui, id));
+    }
+    let mut search = Search {
+        query,
+        ..Default::default()
+    };
+
+    // the invisible pass: nothing can be hovered or clicked, and explicit ids are salted to
+    // avoid clashes with the visible pass
+    let mut invisible_ui = ui.new_child(UiBuilder::new().invisible().sizing_pass());
+    invisible_ui.set_clip_rect(egui::Rect::NOTHING);
+    let invisible_id = if id == Id::NULL {
+        id
+    } else {
+        id.with("egui_field_editor::search")
+    };
+    let found = InspectContext::scope(&mut invisible_ui, None, |ui| {
+        InspectContext::with(ui.ctx(), |context| context.search = Some(search.clone()));
+        add_contents(ui, invisible_id);
+        InspectContext::with(ui.ctx(), |context| context.search.take().map(|search| search.found))
+    });
+
+    search.matches = Some(Arc::new(found.unwrap_or_default()));
+    InspectContext::scope(ui, modified, |ui| {
+        if search.matches.as_ref().is_some_and(|matches| matches.is_empty()) {
+            ui.weak("No matching field");
+        }
+        InspectContext::with(ui.ctx(), |context| context.search = Some(search));
+        add_contents(ui, id)
+    })
+}
+
+/// `true` while the fields are filtered by a search.
+pub(crate) fn is_active(ui: &Ui) -> bool {
+    InspectContext::with(ui.ctx(), |context| context.search.is_some())
+}
+
+/// Indices of the visible items (see [`is_field_visible`]) of the list of `len` items being
+/// rendered once the fields matching the search are found, `None` before.
+///
+/// Found from the paths of the matching fields, so that only the page of them being shown
+/// needs to be rendered.
+pub(crate) fn visible_items(len: usize, ui: &Ui) -> Option<Vec<usize>> {
+    InspectContext::with(ui.ctx(), |context| {
+        let matches = context.search.as_ref()?.matches.as_ref()?;
+        let path = context.path();
+        if matches.iter().any(|matched| contains(matched, path)) {
+            return Some((0..len).collect());
+        }
+        let prefix = format!("{path}[");
+        let items: BTreeSet<usize> = matches
+            .range(prefix.clone()..)
+            .take_while(|matched| matched.starts_with(&prefix))
+            .filter_map(|matched| matched[prefix.len()..].split_once(']')?.0.parse().ok())
+            .filter(|&index| index < len)
+            .collect();
+        Some(items.into_iter().collect())
+    })
+}
+
+/// `false` if the fields are filtered by a search and neither the field being rendered, one of
+/// its parents nor one of its children matches it.
+pub fn is_field_visible(ui: &Ui) -> bool {
+    InspectContext::with(ui.ctx(), |context| {
+        let Some(matches) = context.search.as_ref().and_then(|search| search.matches.as_ref()) else {
+            return true;
+        };
+        let path = context.path();
+        matches
+            .iter()
+            .any(|matched| contains(path, matched) || contains(matched, path))
+    })
+}
+
+/// Open state of the collapsing header of the field being rendered: forced open while
+/// searching if its label or one of its children matches the search.
+///
+/// In a [`crate::EguiDiffInspector`], the headers are also opened or closed depending on
+/// whether the fields differ when the differences change.
+pub fn header_open(ui: &Ui) -> Option<bool> {
+    InspectContext::with(ui.ctx(), |context| {
+        let Some(search) = context.search.as_ref() else {
+            let collapse = context.diff.as_ref().is_some_and(|diff| diff.collapse);
+            return collapse.then(|| context.is_path_modified());
+        };
+        let Some(matches) = &search.matches else {
+            // every label must be rendered to be found
+            return Some(true);
+        };
+        let path = context.path();
+        matches
+            .iter()
+            .any(|matched| contains(path, matched))
+            .then_some(true)
+    })
+}
+
+/// Records the field being rendered if `label` matches the search while the matching fields
+/// are being found.
+///
+/// Returns the query once they are found and `label` matches it, to highlight it.
+pub(crate) fn match_label(label: &str, ui: &Ui) -> Option<String> {
+    InspectContext::with(ui.ctx(), |context| {
+        let path = context.path().to_owned();
+        let search = context.search.as_mut()?;
+        if !label.to_lowercase().contains(&search.query) {
+            return None;
+        }
+        if search.matches.is_none() {
+            search.found.insert(path);
+            None
+        } else {
+            Some(search.query.clone())
+        }
+    })
+}
+
+/// `label` with the parts matching `query` (in lowercase) highlighted.
+pub(crate) fn highlight(label: &str, query: &str, color: Color32, ui: &Ui) -> egui::WidgetText {
+    let normal = TextFormat::simple(egui::TextStyle::Body.resolve(ui.style()), color);
+    let highlighted = TextFormat {
+        color: ui.visuals().selection.stroke.color,
+        background: ui.visuals().selection.bg_fill,
+        ..normal.clone()
+    };
+    let mut job = LayoutJob::default();
+    let lowercase = label.to_lowercase();
+    if lowercase.len() != label.len() {
+        // the positions of the matches in `lowercase` don't apply to `label`
+        job.append(label, 0.0, highlighted);
+        return job.into();
+    }
+    let mut start = 0;
+    for (index, matched) in lowercase.match_indices(query) {
+        let end = index + matched.len();
+        job.append(label.get(start..index).unwrap_or_default(), 0.0, normal.clone());
+        job.append(label.get(index..end).unwrap_or_default(), 0.0, highlighted.clone());
+        start = end;
+    }
+    job.append(label.get(start..).unwrap_or_default(), 0.0, normal);
+    job.into()
+}
@@ -0,0 +1,96 @@
+//! Comparing the inspected object to a baseline, and resetting its fields one by one.
+
+mod common;
+
+use common::Harness;
+use egui::accesskit::Role;
+use egui_field_editor::{EguiInspect, EguiInspector};
+
+#[derive(EguiInspect, Clone, Debug, Default, PartialEq)]
+struct Lens {
+    focal: u16,
+    aperture: u8,
+}
+
+#[derive(EguiInspect, Clone, Debug, Default, PartialEq)]
+struct Camera {
+    name: String,
+    fov: u8,
+    exposure: u8,
+    lens: Lens,
+}
+
+fn preset() -> Camera {
+    Camera {
+        name: "wide".to_owned(),
+        fov: 90,
+        exposure: 4,
+        lens: Lens { focal: 24, aperture: 2 },
+    }
+}
+
+fn edited() -> Camera {
+    Camera {
+        name: "mine".to_owned(),
+        fov: 70,
+        exposure: 4,
+        lens: Lens { focal: 35, aperture: 8 },
+    }
+}
+
+fn harness() -> Harness<Camera, impl FnMut(&mut Camera, &mut egui::Ui)> {
+    Harness::new(edited(), |camera, ui| {
+        ui.add(EguiInspector::new(camera).with_baseline(&preset()));
+    })
+}
+
+/// Clicks the reset button on the row of `label`.
+fn reset(harness: &mut Harness<Camera, impl FnMut(&mut Camera, &mut egui::Ui)>, label: &str) {
+    let button = harness.frame.widget_on_row(Role::Button, label);
+    assert_eq!(button.label, "↺");
+    let pos = button.rect.center();
+    harness.click(pos);
+}
+
+#[test]
+fn differing_fields_are_marked() {
+    let mut harness = harness();
+    harness.click_text("• Lens");
+    let frame = &harness.frame;
+    for label in ["• Name", "• Fov", "• Focal", "• Aperture"] {
+        assert!(frame.shown(label), "{label}: {:?}", frame.texts);
+    }
+    assert!(frame.shown("Exposure"), "{:?}", frame.texts);
+    let resets = frame.widgets(Role::Button).into_iter().filter(|button| button.label == "↺").count();
+    assert_eq!(resets, 4);
+}
+
+#[test]
+fn reset_restores_only_this_field() {
+    let mut harness = harness();
+    reset(&mut harness, "• Fov");
+    assert_eq!(harness.state, Camera { fov: 90, ..edited() });
+    assert!(harness.frame.shown("Fov"), "{:?}", harness.frame.texts);
+
+    harness.click_text("• Lens");
+    reset(&mut harness, "• Focal");
+    assert_eq!(harness.state, Camera { fov: 90, lens: Lens { focal: 24, aperture: 8 }, ..edited() });
+    reset(&mut harness, "• Aperture");
+    assert_eq!(harness.state, Camera { fov: 90, lens: preset().lens, ..edited() });
+    assert!(harness.frame.shown("Lens"), "{:?}", harness.frame.texts);
+
+    reset(&mut harness, "• Name");
+    assert_eq!(harness.state, preset());
+    assert!(!harness.frame.widgets(Role::Button).iter().any(|button| button.label == "↺"));
+}
+
+#[test]
+fn default_values_as_baseline() {
+    let mut harness = Harness::new(Camera { fov: 70, ..Camera::default() }, |camera, ui| {
+        ui.add(EguiInspector::new(camera).show_modified_from_default());
+    });
+    assert!(harness.frame.shown("• Fov") && harness.frame.shown("Name"), "{:?}", harness.frame.texts);
+    let button = harness.frame.widget_on_row(Role::Button, "• Fov").rect;
+    harness.click(button.center());
+    assert_eq!(harness.state, Camera::default());
+}
@@ -0,0 +1,213 @@
+//! Changes reported by `field_changes` and `EguiInspector::record_changes`, and their replay on
+//! a copy of the object.
+
+mod common;
+
+use common::Harness;
+use egui::accesskit::Role;
+use egui_field_editor::{field_changes, ChangeKind, EguiInspect, EguiInspector, FieldChange};
+
+#[derive(EguiInspect, Clone, Debug, Default, PartialEq)]
+struct Host {
+    name: String,
+    port: u16,
+}
+
+#[derive(EguiInspect, Clone, Debug, Default, PartialEq)]
+struct Cluster {
+    name: String,
+    hosts: Vec<Host>,
+    backup: Option<Host>,
+}
+
+fn host(name: &str, port: u16) -> Host {
+    Host {
+        name: name.to_owned(),
+        port,
+    }
+}
+
+fn cluster() -> Cluster {
+    Cluster {
+        name: "main".to_owned(),
+        hosts: vec![host("a", 80), host("b", 81), host("c", 82)],
+        backup: None,
+    }
+}
+
+/// Index of the host at `path`, e.g. `hosts[2]`.
+fn host_index(path: &str) -> usize {
+    path.strip_prefix("hosts[").and_then(|rest| rest.strip_suffix(']')).and_then(|index| index.parse().ok()).unwrap()
+}
+
+/// Applies `changes` to `cluster` the way a remote peer would.
+fn replay(cluster: &mut Cluster, changes: &[FieldChange]) {
+    for change in changes {
+        match change.kind {
+            ChangeKind::Set => cluster.set_by_path_from_str(&change.path, &change.new_value).unwrap(),
+            ChangeKind::Insert if change.path == "backup" => cluster.backup = Some(Host::default()),
+            ChangeKind::Insert => cluster.hosts.insert(host_index(&change.path), Host::default()),
+            ChangeKind::Remove if change.path == "backup" => cluster.backup = None,
+            ChangeKind::Remove => {
+                cluster.hosts.remove(host_index(&change.path));
+            }
+            ChangeKind::Move { from, to } => {
+                assert_eq!(change.path, "hosts");
+                let moved = cluster.hosts.remove(from);
+                cluster.hosts.insert(to, moved);
+            }
+            ChangeKind::Opaque => panic!("{} has no values", change.path),
+        }
+    }
+}
+
+/// Checks that replaying the changes from `old` to `new` gives `new`, and returns them.
+fn changes(old: &Cluster, new: &Cluster) -> Vec<FieldChange> {
+    let changes = field_changes(old, new);
+    let mut replica = old.clone();
+    replay(&mut replica, &changes);
+    assert_eq!(&replica, new, "{changes:?}");
+    changes
+}
+
+#[test]
+fn set_changes_have_the_old_and_new_values() {
+    let old = cluster();
+    let mut new = old.clone();
+    new.name = "backup".to_owned();
+    new.hosts[1].port = 8080;
+    assert_eq!(
+        changes(&old, &new),
+        [FieldChange::set("name", "main", "backup"), FieldChange::set("hosts[1].port", 81, 8080)]
+    );
+    assert_eq!(changes(&old, &old), []);
+}
+
+#[test]
+fn inserted_items_are_followed_by_their_fields() {
+    let old = cluster();
+    let mut new = old.clone();
+    new.hosts.insert(1, host("d", 0));
+    assert_eq!(
+        changes(&old, &new),
+        [FieldChange::new("hosts[1]", ChangeKind::Insert), FieldChange::set("hosts[1].name", "", "d")]
+    );
+
+    new = old.clone();
+    new.backup = Some(host("e", 22));
+    assert_eq!(
+        changes(&old, &new),
+        [
+            FieldChange::new("backup", ChangeKind::Insert),
+            FieldChange::set("backup.name", "", "e"),
+            FieldChange::set("backup.port", 0, 22),
+        ]
+    );
+    // a default value has no following change
+    new.backup = Some(Host::default());
+    assert_eq!(changes(&old, &new), [FieldChange::new("backup", ChangeKind::Insert)]);
+}
+
+#[test]
+fn removed_items_are_reported_from_the_end() {
+    let old = cluster();
+    let mut new = old.clone();
+    new.hosts.remove(1);
+    assert_eq!(changes(&old, &new), [FieldChange::new("hosts[1]", ChangeKind::Remove)]);
+
+    new.hosts.truncate(0);
+    assert_eq!(
+        changes(&old, &new),
+        [
+            FieldChange::new("hosts[2]", ChangeKind::Remove),
+            FieldChange::new("hosts[1]", ChangeKind::Remove),
+            FieldChange::new("hosts[0]", ChangeKind::Remove),
+        ]
+    );
+
+    let old = Cluster { backup: Some(host("e", 22)), ..cluster() };
+    new = Cluster { backup: None, ..old.clone() };
+    assert_eq!(changes(&old, &new), [FieldChange::new("backup", ChangeKind::Remove)]);
+}
+
+#[test]
+fn moved_items_are_reported_once() {
+    let old = cluster();
+    let mut new = old.clone();
+    new.hosts.rotate_left(1);
+    assert_eq!(changes(&old, &new), [FieldChange::new("hosts", ChangeKind::Move { from: 0, to: 2 })]);
+    new = old.clone();
+    new.hosts.rotate_right(1);
+    assert_eq!(changes(&old, &new), [FieldChange::new("hosts", ChangeKind::Move { from: 2, to: 0 })]);
+    // two swapped neighbours are a single move
+    new = old.clone();
+    new.hosts.swap(0, 1);
+    assert_eq!(changes(&old, &new), [FieldChange::new("hosts", ChangeKind::Move { from: 0, to: 1 })]);
+}
+
+/// A manual implementation which doesn't report its changes.
+#[derive(Clone, Debug, Default, PartialEq)]
+struct Unit(String);
+
+impl EguiInspect for Unit {
+    fn inspect_with_custom_id(&mut self, _parent_id: egui::Id, label: &str, tooltip: &str, read_only: bool, ui: &mut egui::Ui) -> egui_field_editor::InspectResponse {
+        self.0.inspect(label, tooltip, read_only, ui)
+    }
+}
+
+#[derive(EguiInspect, Clone, Debug, Default, PartialEq)]
+struct Gauge {
+    value: f32,
+    unit: Unit,
+}
+
+#[test]
+fn fields_without_changes_are_opaque() {
+    let old = Gauge { value: 1., unit: Unit("bar".to_owned()) };
+    let new = Gauge { value: 2., unit: Unit("psi".to_owned()) };
+    let changes = field_changes(&old, &new);
+    assert_eq!(changes, [FieldChange::set("value", 1., 2.), FieldChange::new("unit", ChangeKind::Opaque)]);
+
+    // the opaque fields are copied as a whole
+    let mut replica = old.clone();
+    for change in &changes {
+        match change.kind {
+            ChangeKind::Set => replica.set_by_path_from_str(&change.path, &change.new_value).unwrap(),
+            ChangeKind::Opaque => replica.copy_field_from(&new, &change.path).unwrap(),
+            _ => unreachable!(),
+        }
+    }
+    assert_eq!(replica, new);
+}
+
+/// The cluster, and the changes recorded by its inspector.
+type State = (Cluster, Vec<FieldChange>);
+
+fn harness() -> Harness<State, impl FnMut(&mut State, &mut egui::Ui)> {
+    Harness::new((cluster(), Vec::new()), |(cluster, changes), ui| {
+        ui.add(EguiInspector::new(cluster).record_changes(changes));
+    })
+}
+
+#[test]
+fn edits_are_recorded_once_committed() {
+    let mut harness = harness();
+    let name = harness.frame.widget_on_row(Role::TextInput, "Name").rect;
+    harness.click(name.right_center() - egui::vec2(5., 0.));
+    harness.type_text("2");
+    assert_eq!(harness.state.0.name, "main2");
+    // the text field still has the focus
+    assert_eq!(harness.state.1, []);
+    harness.click(harness.frame.find("Hosts[3]") + egui::vec2(300., 0.));
+    assert_eq!(harness.state.1, [FieldChange::set("name", "main", "main2")]);
+
+    harness.click_text("Hosts[3]");
+    let handles: Vec<_> = harness.frame.texts.iter().filter(|(text, _)| text == "☰").map(|(_, pos)| *pos).collect();
+    harness.drag(handles[0], handles[1] + egui::vec2(0., 5.), 10);
+    assert_eq!(harness.state.0.hosts, [host("b", 81), host("a", 80), host("c", 82)]);
+    assert_eq!(harness.state.1[1..], [FieldChange::new("hosts", ChangeKind::Move { from: 0, to: 1 })]);
+
+    let mut replica = cluster();
+    replay(&mut replica, &harness.state.1);
+    assert_eq!(replica, harness.state.0);
+}
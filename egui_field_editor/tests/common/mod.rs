@@ -0,0 +1,248 @@
+//! Headless rendering of a UI for the integration tests: the texts and widgets shown by each
+//! frame are collected, and the pointer and keyboard events are sent to the next frame.
+
+#![allow(dead_code)]
+
+use egui::accesskit::Role;
+use egui::{Event, Key, Modifiers, Pos2, Rect};
+
+/// A widget shown by a frame, as described to screen readers.
+#[derive(Clone, Debug)]
+pub struct Widget {
+    pub role: Role,
+    pub rect: Rect,
+    pub label: String,
+    pub value: Option<f64>,
+    pub disabled: bool,
+    /// `true` for a checked checkbox or a selected radio button.
+    pub checked: bool,
+    /// `true` for a checkbox in the indeterminate state.
+    pub mixed: bool,
+}
+
+/// Texts and widgets shown by a frame.
+#[derive(Clone, Debug, Default)]
+pub struct Frame {
+    /// The texts, with their center.
+    pub texts: Vec<(String, Pos2)>,
+    /// The color of each text of `texts`.
+    pub colors: Vec<egui::Color32>,
+    pub widgets: Vec<Widget>,
+}
+
+impl Frame {
+    /// Center of the first `text` shown.
+    pub fn find(&self, text: &str) -> Pos2 {
+        self.texts
+            .iter()
+            .find(|(t, _)| t == text)
+            .unwrap_or_else(|| panic!("`{text}` not shown: {:?}", self.texts))
+            .1
+    }
+    /// Center of the last `text` shown, e.g. the item of an open popup.
+    pub fn find_last(&self, text: &str) -> Pos2 {
+        self.texts
+            .iter()
+            .rfind(|(t, _)| t == text)
+            .unwrap_or_else(|| panic!("`{text}` not shown: {:?}", self.texts))
+            .1
+    }
+    pub fn shown(&self, text: &str) -> bool {
+        self.texts.iter().any(|(t, _)| t == text)
+    }
+    pub fn count(&self, text: &str) -> usize {
+        self.texts.iter().filter(|(t, _)| t == text).count()
+    }
+    /// The widgets with `role`, from top to bottom.
+    pub fn widgets(&self, role: Role) -> Vec<&Widget> {
+        let mut widgets: Vec<_> = self.widgets.iter().filter(|widget| widget.role == role).collect();
+        widgets.sort_by(|a, b| a.rect.min.y.total_cmp(&b.rect.min.y).then(a.rect.min.x.total_cmp(&b.rect.min.x)));
+        widgets
+    }
+    /// The widget with `role` on the row of `text`.
+    pub fn widget_on_row(&self, role: Role, text: &str) -> &Widget {
+        let y = self.find(text).y;
+        self.widgets(role)
+            .into_iter()
+            .find(|widget| widget.rect.y_range().contains(y))
+            .unwrap_or_else(|| panic!("no {role:?} on the row of `{text}`: {:?}", self.widgets))
+    }
+    /// Color of the first `text` shown.
+    pub fn color(&self, text: &str) -> egui::Color32 {
+        let index = self.texts.iter().position(|(t, _)| t == text);
+        self.colors[index.unwrap_or_else(|| panic!("`{text}` not shown: {:?}", self.texts))]
+    }
+    /// The topmost button labelled `label`.
+    pub fn button(&self, label: &str) -> &Widget {
+        self.widgets(Role::Button)
+            .into_iter()
+            .find(|widget| widget.label == label)
+            .unwrap_or_else(|| panic!("no `{label}` button: {:?}", self.widgets))
+    }
+}
+
+/// Renders `state` with `ui` in a headless [`egui::Context`].
+pub struct Harness<S, F> {
+    pub ctx: egui::Context,
+    pub state: S,
+    ui: F,
+    modifiers: Modifiers,
+    /// Texts and widgets shown by the last frame.
+    pub frame: Frame,
+}
+
+impl<S, F: FnMut(&mut S, &mut egui::Ui)> Harness<S, F> {
+    /// Shows `state` once with `ui`, in a central panel.
+    pub fn new(state: S, ui: F) -> Self {
+        let ctx = egui::Context::default();
+        ctx.enable_accesskit();
+        let mut harness = Self {
+            ctx,
+            state,
+            ui,
+            modifiers: Modifiers::NONE,
+            frame: Frame::default(),
+        };
+        harness.run(vec![]);
+        harness
+    }
+
+    /// Runs a frame receiving `events`.
+    pub fn run(&mut self, events: Vec<Event>) -> &Frame {
+        let input = egui::RawInput {
+            events,
+            modifiers: self.modifiers,
+            screen_rect: Some(Rect::from_min_size(Pos2::ZERO, egui::vec2(800., 4000.))),
+            ..Default::default()
+        };
+        let Self { state, ui, .. } = self;
+        let mut output = self.ctx.run(input, |ctx| {
+            egui::CentralPanel::default().show(ctx, |panel| ui(state, panel));
+        });
+        output.textures_delta.clear();
+        let (texts, colors) = output
+            .shapes
+            .iter()
+            .filter_map(|shape| match &shape.shape {
+                egui::epaint::Shape::Text(text) => {
+                    let color = match text.galley.job.sections.first() {
+                        Some(section) if section.format.color != egui::Color32::PLACEHOLDER => section.format.color,
+                        _ => text.fallback_color,
+                    };
+                    Some(((text.galley.text().to_owned(), text.pos + text.galley.rect.center().to_vec2()), color))
+                }
+                _ => None,
+            })
+            .unzip();
+        let widgets = output
+            .platform_output
+            .accesskit_update
+            .map(|update| update.nodes)
+            .unwrap_or_default()
+            .into_iter()
+            .filter_map(|(_, node)| {
+                let bounds = node.bounds()?;
+                Some(Widget {
+                    role: node.role(),
+                    rect: Rect::from_min_max(
+                        egui::pos2(bounds.x0 as f32, bounds.y0 as f32),
+                        egui::pos2(bounds.x1 as f32, bounds.y1 as f32),
+                    ),
+                    label: node.label().unwrap_or_default().to_owned(),
+                    value: node.numeric_value(),
+                    disabled: node.is_disabled(),
+                    checked: node.toggled() == Some(egui::accesskit::Toggled::True),
+                    mixed: node.toggled() == Some(egui::accesskit::Toggled::Mixed),
+                })
+            })
+            .collect();
+        self.frame = Frame { texts, colors, widgets };
+        &self.frame
+    }
+
+    /// Runs frames until the animations (e.g. of the collapsing headers) are over.
+    pub fn settle(&mut self) -> &Frame {
+        for _ in 0..20 {
+            self.run(vec![]);
+        }
+        self.run(vec![])
+    }
+
+    /// Clicks at `pos`.
+    pub fn click(&mut self, pos: Pos2) -> &Frame {
+        self.run(vec![Event::PointerMoved(pos), button(pos, true, self.modifiers)]);
+        self.run(vec![button(pos, false, self.modifiers)]);
+        self.settle()
+    }
+
+    /// Clicks at `pos` with the secondary button, e.g. to open a context menu.
+    pub fn right_click(&mut self, pos: Pos2) -> &Frame {
+        let secondary = |pressed| Event::PointerButton {
+            pos,
+            button: egui::PointerButton::Secondary,
+            pressed,
+            modifiers: Modifiers::NONE,
+        };
+        self.run(vec![Event::PointerMoved(pos), secondary(true)]);
+        self.run(vec![secondary(false)]);
+        self.settle()
+    }
+
+    /// Moves the pointer to `pos` and keeps it there until its tooltip (if any) is shown.
+    pub fn hover(&mut self, pos: Pos2) -> &Frame {
+        self.run(vec![Event::PointerMoved(pos)]);
+        for _ in 0..60 {
+            self.run(vec![]);
+        }
+        self.run(vec![])
+    }
+
+    /// Clicks on the first `text` shown by the last frame.
+    pub fn click_text(&mut self, text: &str) -> &Frame {
+        let pos = self.frame.find(text);
+        self.click(pos)
+    }
+
+    /// Drags the pointer from `from` to `to` in `steps` frames, then releases it.
+    pub fn drag(&mut self, from: Pos2, to: Pos2, steps: usize) -> &Frame {
+        self.run(vec![Event::PointerMoved(from)]);
+        self.run(vec![button(from, true, self.modifiers)]);
+        for step in 1..=steps {
+            let pos = from.lerp(to, step as f32 / steps as f32);
+            self.run(vec![Event::PointerMoved(pos)]);
+        }
+        self.run(vec![button(to, false, self.modifiers)]);
+        self.settle()
+    }
+
+    /// Types `text` in the focused text field.
+    pub fn type_text(&mut self, text: &str) -> &Frame {
+        self.run(vec![Event::Text(text.to_owned())]);
+        self.run(vec![])
+    }
+
+    /// Presses and releases `key` with `modifiers`.
+    pub fn key(&mut self, key: Key, modifiers: Modifiers) -> &Frame {
+        self.modifiers = modifiers;
+        let event = |pressed| Event::Key {
+            key,
+            physical_key: None,
+            pressed,
+            repeat: false,
+            modifiers,
+        };
+        self.run(vec![event(true)]);
+        self.run(vec![event(false)]);
+        self.modifiers = Modifiers::NONE;
+        self.run(vec![])
+    }
+}
+
+fn button(pos: Pos2, pressed: bool, modifiers: Modifiers) -> Event {
+    Event::PointerButton {
+        pos,
+        button: egui::PointerButton::Primary,
+        pressed,
+        modifiers,
+    }
+}
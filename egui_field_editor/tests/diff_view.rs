@@ -0,0 +1,85 @@
+//! Comparing two objects with `EguiDiffInspector`.
+
+mod common;
+
+use common::Harness;
+use egui::accesskit::Role;
+use egui_field_editor::{EguiDiffInspector, EguiInspect};
+
+#[derive(EguiInspect, Clone, Debug, Default, PartialEq)]
+struct Band {
+    low: u16,
+    high: u16,
+}
+
+#[derive(EguiInspect, Clone, Debug, Default, PartialEq)]
+struct Tuning {
+    gain: u8,
+    filter: bool,
+    band: Band,
+    limits: Band,
+}
+
+fn preset() -> Tuning {
+    Tuning {
+        gain: 3,
+        filter: true,
+        band: Band { low: 20, high: 8000 },
+        limits: Band { low: 10, high: 20000 },
+    }
+}
+
+fn live() -> Tuning {
+    Tuning {
+        gain: 7,
+        band: Band { low: 20, high: 12000 },
+        ..preset()
+    }
+}
+
+/// The preset on the left, and the live values on the right.
+type State = (Tuning, Tuning);
+
+fn harness() -> Harness<State, impl FnMut(&mut State, &mut egui::Ui)> {
+    Harness::new((preset(), live()), |(preset, live), ui| {
+        ui.add(EguiDiffInspector::new(preset, live).with_titles("Preset", "Live"));
+    })
+}
+
+#[test]
+fn differing_fields_are_highlighted() {
+    let harness = harness();
+    let frame = &harness.frame;
+    let warn = harness.ctx.style().visuals.warn_fg_color;
+    for label in ["Gain", "Band", "High"] {
+        assert_eq!(frame.count(label), 2, "{:?}", frame.texts);
+        assert_eq!(frame.color(label), warn, "{label}");
+    }
+    for label in ["Filter", "Limits", "Low"] {
+        assert_ne!(frame.color(label), warn, "{label}");
+    }
+    // the headers of the identical fields are collapsed
+    assert_eq!(frame.count("Low"), 2, "{:?}", frame.texts);
+    assert!(!frame.shown("20000"), "{:?}", frame.texts);
+    // a copy button for each differing field, and its parents
+    let copies: Vec<_> = frame.widgets(Role::Button).into_iter().filter(|button| button.label == "➡").collect();
+    assert_eq!(copies.len(), 3);
+}
+
+#[test]
+fn copy_left_to_right() {
+    let mut harness = harness();
+    let copy = harness.frame.widget_on_row(Role::Button, "High").rect;
+    harness.click(copy.center());
+    assert_eq!(harness.state.1, Tuning { gain: 7, ..preset() });
+    assert_eq!(harness.state.0, preset());
+    // the copied field isn't highlighted anymore
+    let warn = harness.ctx.style().visuals.warn_fg_color;
+    assert_ne!(harness.frame.color("Band"), warn);
+    assert_eq!(harness.frame.color("Gain"), warn);
+
+    let copy = harness.frame.widget_on_row(Role::Button, "Gain").rect;
+    harness.click(copy.center());
+    assert_eq!(harness.state, (preset(), preset()));
+    assert!(!harness.frame.widgets(Role::Button).iter().any(|button| button.label == "➡"));
+}
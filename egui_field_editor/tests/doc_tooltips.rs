@@ -0,0 +1,88 @@
+//! Doc comments of the types, fields and variants used as their default tooltips.
+
+mod common;
+
+use common::Harness;
+use egui_field_editor::{EguiInspect, EguiInspector};
+
+/// Output device.
+#[derive(EguiInspect, Default)]
+struct Speaker {
+    /// Volume in percent,
+    /// from 0 to 100.
+    volume: u8,
+    /// Replaced by the tooltip attribute.
+    #[inspect(tooltip = "Left or right")]
+    balance: i8,
+    muted: bool,
+}
+
+/// How the channels are mixed.
+#[derive(EguiInspect)]
+#[inspect(style = "radio")]
+enum Mix {
+    /// One channel.
+    Mono,
+    /// Two channels.
+    Stereo,
+}
+
+/// Not a tooltip.
+#[derive(EguiInspect, Default)]
+#[inspect(no_doc_tooltip)]
+struct Plain {
+    /// Not a tooltip either.
+    gain: u8,
+    #[inspect(tooltip = "Kept")]
+    level: u8,
+}
+
+#[derive(EguiInspect)]
+struct Audio {
+    speaker: Speaker,
+    mix: Mix,
+    plain: Plain,
+}
+
+fn audio() -> Audio {
+    Audio {
+        speaker: Speaker::default(),
+        mix: Mix::Mono,
+        plain: Plain::default(),
+    }
+}
+
+#[test]
+fn field_docs_are_tooltips() {
+    let tooltips: Vec<_> = audio().field_paths().into_iter().map(|field| (field.path, field.tooltip)).collect();
+    let tooltip = |path: &str| tooltips.iter().find(|(p, _)| p == path).map(|(_, tooltip)| tooltip.as_str()).unwrap();
+    assert_eq!(tooltip("speaker.volume"), "Volume in percent, from 0 to 100.");
+    assert_eq!(tooltip("speaker.balance"), "Left or right");
+    assert_eq!(tooltip("speaker.muted"), "");
+    // opted out
+    assert_eq!(tooltip("plain.gain"), "");
+    assert_eq!(tooltip("plain.level"), "Kept");
+}
+
+#[test]
+fn type_and_variant_docs_are_tooltips() {
+    let mut harness = Harness::new(audio(), |audio, ui| {
+        ui.add(EguiInspector::new(audio));
+    });
+    let pos = harness.frame.find("Speaker");
+    assert!(harness.hover(pos).shown("Output device."), "{:?}", harness.frame.texts);
+    let pos = harness.frame.find("Mix");
+    assert!(harness.hover(pos).shown("How the channels are mixed."), "{:?}", harness.frame.texts);
+    let pos = harness.frame.find("Stereo");
+    assert!(harness.hover(pos).shown("Two channels."), "{:?}", harness.frame.texts);
+
+    // opted out
+    let pos = harness.frame.find("Plain");
+    harness.hover(pos);
+    assert!(!harness.frame.shown("Not a tooltip."), "{:?}", harness.frame.texts);
+    harness.click(pos);
+    let pos = harness.frame.find("Gain");
+    assert!(!harness.hover(pos).shown("Not a tooltip either."), "{:?}", harness.frame.texts);
+    let pos = harness.frame.find("Level");
+    assert!(harness.hover(pos).shown("Kept"), "{:?}", harness.frame.texts);
+}
@@ -0,0 +1,89 @@
+//! Drafts, only written back to the inspected object by "Apply".
+
+mod common;
+
+use common::Harness;
+use egui::accesskit::Role;
+use egui_field_editor::{EguiInspect, EguiInspector};
+
+#[derive(EguiInspect, Clone, Debug, PartialEq)]
+struct RendererSettings {
+    #[inspect(slider(min = 0., max = 8.))]
+    msaa: u8,
+    vsync: bool,
+}
+
+impl Default for RendererSettings {
+    fn default() -> Self {
+        Self { msaa: 4, vsync: true }
+    }
+}
+
+/// The settings, and the number of frames in which the inspector reported a change.
+type State = (RendererSettings, usize);
+
+fn harness() -> Harness<State, impl FnMut(&mut State, &mut egui::Ui)> {
+    let settings = RendererSettings { msaa: 0, vsync: false };
+    Harness::new((settings, 0), |(settings, changes), ui| {
+        if ui.add(EguiInspector::draft(settings).with_default(RendererSettings::default)).changed() {
+            *changes += 1;
+        }
+    })
+}
+
+fn toggle_vsync(harness: &mut Harness<State, impl FnMut(&mut State, &mut egui::Ui)>) {
+    let checkbox = harness.frame.widget_on_row(Role::CheckBox, "Vsync").rect;
+    harness.click(checkbox.left_center() + egui::vec2(8., 0.));
+}
+
+#[test]
+fn apply_writes_back() {
+    let mut harness = harness();
+    assert!(harness.frame.button("✔ Apply").disabled);
+    toggle_vsync(&mut harness);
+    assert!(harness.frame.widget_on_row(Role::CheckBox, "Vsync").checked);
+    let slider = harness.frame.widget_on_row(Role::Slider, "Msaa").rect;
+    harness.click(slider.right_center() - egui::vec2(1., 0.));
+    // only the draft is edited
+    assert_eq!(harness.state, (RendererSettings { msaa: 0, vsync: false }, 0));
+    assert!(!harness.frame.button("✔ Apply").disabled);
+
+    harness.click(harness.frame.button("✔ Apply").rect.center());
+    assert_eq!(harness.state, (RendererSettings { msaa: 8, vsync: true }, 1));
+    assert!(harness.frame.button("✔ Apply").disabled);
+    assert!(harness.frame.button("↩ Revert").disabled);
+}
+
+#[test]
+fn revert_discards() {
+    let mut harness = harness();
+    toggle_vsync(&mut harness);
+    harness.click(harness.frame.button("↩ Revert").rect.center());
+    assert!(harness.frame.button("✔ Apply").disabled);
+    assert!(!harness.frame.widget_on_row(Role::CheckBox, "Vsync").checked);
+
+    // nothing left to apply: a new edit starts from the object again
+    toggle_vsync(&mut harness);
+    harness.click(harness.frame.button("✔ Apply").rect.center());
+    assert_eq!(harness.state, (RendererSettings { msaa: 0, vsync: true }, 1));
+}
+
+#[test]
+fn reset_to_default_is_applied_on_demand() {
+    let mut harness = harness();
+    harness.click(harness.frame.button("Reset to default").rect.center());
+    assert_eq!(harness.state, (RendererSettings { msaa: 0, vsync: false }, 0));
+    harness.click(harness.frame.button("✔ Apply").rect.center());
+    assert_eq!(harness.state, (RendererSettings::default(), 1));
+}
+
+#[test]
+fn object_modified_outside_is_followed() {
+    let mut harness = harness();
+    harness.state.0.msaa = 2;
+    harness.run(vec![]);
+    assert!(harness.frame.button("✔ Apply").disabled);
+    toggle_vsync(&mut harness);
+    harness.click(harness.frame.button("✔ Apply").rect.center());
+    assert_eq!(harness.state, (RendererSettings { msaa: 2, vsync: true }, 1));
+}
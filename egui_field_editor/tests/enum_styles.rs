@@ -0,0 +1,90 @@
+//! The radio, toggle and tabs presentations of the variant selector of the derived enums.
+
+mod common;
+
+use common::Harness;
+use egui::accesskit::Role;
+use egui_field_editor::{EguiInspect, EguiInspector};
+
+#[derive(EguiInspect, Debug, PartialEq)]
+#[inspect(style = "radio")]
+enum Align {
+    Left,
+    Center,
+    Right,
+}
+
+#[derive(EguiInspect, Debug, PartialEq)]
+#[inspect(style = "toggle")]
+enum Unit {
+    Metric,
+    Imperial,
+}
+
+#[derive(EguiInspect, Debug, PartialEq)]
+#[inspect(style = "tabs")]
+enum Source {
+    File { path: String },
+    Url { address: String, retries: u8 },
+}
+
+#[derive(EguiInspect, Debug, PartialEq)]
+struct Settings {
+    align: Align,
+    unit: Unit,
+    source: Source,
+}
+
+fn harness() -> Harness<Settings, impl FnMut(&mut Settings, &mut egui::Ui)> {
+    let settings = Settings {
+        align: Align::Left,
+        unit: Unit::Metric,
+        source: Source::File { path: "a.txt".to_owned() },
+    };
+    Harness::new(settings, |settings, ui| {
+        ui.add(EguiInspector::new(settings));
+    })
+}
+
+#[test]
+fn radio_buttons() {
+    let mut harness = harness();
+    let radios: Vec<_> = harness.frame.widgets(Role::RadioButton).into_iter().map(|radio| (radio.label.as_str(), radio.checked)).collect();
+    assert_eq!(radios, [("Left", true), ("Center", false), ("Right", false)]);
+    assert!(harness.frame.widgets(Role::ComboBox).is_empty());
+
+    harness.click_text("Right");
+    assert_eq!(harness.state.align, Align::Right);
+    let checked: Vec<_> = harness.frame.widgets(Role::RadioButton).into_iter().filter(|radio| radio.checked).map(|radio| radio.label.clone()).collect();
+    assert_eq!(checked, ["Right"]);
+}
+
+#[test]
+fn toggle_buttons() {
+    let mut harness = harness();
+    let metric = harness.frame.widget_on_row(Role::Button, "Metric").rect;
+    let imperial = harness.frame.button("Imperial").rect;
+    // the buttons are on the row of the label
+    assert!(metric.y_range().contains(harness.frame.find("Unit").y), "{:?}", harness.frame.widgets);
+    assert!(imperial.left() > metric.right() && imperial.left() - metric.right() < 2.);
+
+    harness.click(imperial.center());
+    assert_eq!(harness.state.unit, Unit::Imperial);
+    harness.click_text("Metric");
+    assert_eq!(harness.state.unit, Unit::Metric);
+}
+
+#[test]
+fn tabs_above_the_variant_fields() {
+    let mut harness = harness();
+    let frame = &harness.frame;
+    assert!(frame.find("File").y > frame.find("Source").y, "{:?}", frame.texts);
+    assert!(frame.find("Path").y > frame.find("File").y, "{:?}", frame.texts);
+    assert_eq!(frame.find("Url").y, frame.find("File").y);
+
+    harness.click_text("Url");
+    assert_eq!(harness.state.source, Source::Url { address: String::new(), retries: 0 });
+    let frame = &harness.frame;
+    assert!(!frame.shown("Path"), "{:?}", frame.texts);
+    assert!(frame.find("Address").y > frame.find("Url").y && frame.find("Retries").y > frame.find("Address").y, "{:?}", frame.texts);
+}
@@ -0,0 +1,146 @@
+//! Picking the variants of enums and `Option`s whose types implement neither `PartialEq` nor
+//! `Default`.
+
+mod common;
+
+use common::Harness;
+use egui::accesskit::Role;
+use egui_field_editor::{EguiInspect, EguiInspector};
+
+#[derive(EguiInspect)]
+struct Timeout {
+    secs: u32,
+}
+
+#[derive(EguiInspect)]
+enum Trigger {
+    Manual,
+    #[inspect(default = "Trigger::Timer { timeout: Timeout { secs: 5 }, repeat: true }")]
+    Timer { timeout: Timeout, repeat: bool },
+    Key(char),
+}
+
+#[derive(EguiInspect)]
+struct Job {
+    trigger: Trigger,
+    timeout: Option<Timeout>,
+    retries: Option<u8>,
+}
+
+fn harness() -> Harness<Job, impl FnMut(&mut Job, &mut egui::Ui)> {
+    let job = Job {
+        trigger: Trigger::Manual,
+        timeout: None,
+        retries: None,
+    };
+    Harness::new(job, |job, ui| {
+        ui.add(EguiInspector::new(job));
+    })
+}
+
+/// Opens the combo box on the row of `label` and picks `item`.
+fn pick(harness: &mut Harness<Job, impl FnMut(&mut Job, &mut egui::Ui)>, label: &str, item: &str) {
+    let combo = harness.frame.widget_on_row(Role::ComboBox, label).rect;
+    harness.click(combo.center());
+    harness.click(harness.frame.find_last(item));
+}
+
+#[test]
+fn variants_are_built_when_picked() {
+    let mut harness = harness();
+    pick(&mut harness, "Trigger", "Timer");
+    assert!(matches!(harness.state.trigger, Trigger::Timer { timeout: Timeout { secs: 5 }, repeat: true }));
+    // the fields of the variant are shown
+    assert!(harness.frame.shown("Repeat"), "{:?}", harness.frame.texts);
+
+    pick(&mut harness, "Trigger", "Key");
+    assert!(matches!(harness.state.trigger, Trigger::Key('\0')));
+    pick(&mut harness, "Trigger", "Manual");
+    assert!(matches!(harness.state.trigger, Trigger::Manual));
+}
+
+#[test]
+fn some_needs_a_default_value() {
+    assert!(Timeout::default_value().is_none());
+    assert_eq!(Option::<u8>::default_value(), Some(None));
+
+    let mut harness = harness();
+    let combo = harness.frame.widget_on_row(Role::ComboBox, "Timeout").rect;
+    harness.click(combo.center());
+    let some = harness.frame.widgets.iter().rfind(|widget| widget.label == "Some").unwrap();
+    assert!(some.disabled);
+    harness.click(harness.frame.find_last("Some"));
+    assert!(harness.state.timeout.is_none());
+
+    pick(&mut harness, "Retries", "Some");
+    assert_eq!(harness.state.retries, Some(0));
+
+    assert_eq!(
+        harness.state.set_by_path_from_str("timeout.secs", "3"),
+        Err("The value is `None` and its type has no default value".to_owned())
+    );
+    assert!(harness.state.timeout.is_none());
+    harness.state.set_by_path_from_str("retries", "2").unwrap();
+    assert_eq!(harness.state.retries, Some(2));
+}
+
+/// A manual implementation, picked as `Some` once it overrides `default_value`.
+#[derive(Default)]
+struct Celsius(f32);
+
+impl EguiInspect for Celsius {
+    fn inspect_with_custom_id(&mut self, _parent_id: egui::Id, label: &str, tooltip: &str, read_only: bool, ui: &mut egui::Ui) -> egui_field_editor::InspectResponse {
+        egui_field_editor::add_number(&mut self.0, label, tooltip, read_only, None, ui)
+    }
+    fn default_value() -> Option<Self> {
+        Some(Self::default())
+    }
+}
+
+/// A manual implementation keeping the default `default_value`.
+#[derive(Default)]
+struct Kelvin(f32);
+
+impl EguiInspect for Kelvin {
+    fn inspect_with_custom_id(&mut self, _parent_id: egui::Id, label: &str, tooltip: &str, read_only: bool, ui: &mut egui::Ui) -> egui_field_editor::InspectResponse {
+        egui_field_editor::add_number(&mut self.0, label, tooltip, read_only, None, ui)
+    }
+}
+
+#[derive(EguiInspect)]
+struct Sensor {
+    celsius: Option<Celsius>,
+    kelvin: Option<Kelvin>,
+    reading: Result<u8, Timeout>,
+}
+
+#[test]
+fn manual_implementations_give_their_default_value() {
+    let sensor = Sensor {
+        celsius: None,
+        kelvin: None,
+        reading: Ok(1),
+    };
+    let mut harness = Harness::new(sensor, |sensor, ui| {
+        ui.add(EguiInspector::new(sensor));
+    });
+    let combo = harness.frame.widget_on_row(Role::ComboBox, "Celsius").rect;
+    harness.click(combo.center());
+    harness.click(harness.frame.find_last("Some"));
+    assert_eq!(harness.state.celsius.as_ref().map(|celsius| celsius.0), Some(0.));
+
+    let combo = harness.frame.widget_on_row(Role::ComboBox, "Kelvin").rect;
+    harness.click(combo.center());
+    let some = harness.frame.widgets.iter().rfind(|widget| widget.label == "Some").unwrap();
+    assert!(some.disabled);
+    harness.click(harness.frame.find_last("Some"));
+    assert!(harness.state.kelvin.is_none());
+
+    // `Result` needs a default value of the other variant too
+    let combo = harness.frame.widget_on_row(Role::ComboBox, "Reading").rect;
+    harness.click(combo.center());
+    let err = harness.frame.widgets.iter().rfind(|widget| widget.label == "Err").unwrap();
+    assert!(err.disabled);
+    harness.click(harness.frame.find_last("Err"));
+    assert!(matches!(harness.state.reading, Ok(1)));
+}
@@ -0,0 +1,110 @@
+//! "Copy value"/"Paste value" of the context menu of the fields.
+
+#![cfg(feature = "serde")]
+
+mod common;
+
+use common::Harness;
+use egui_field_editor::{EguiInspect, EguiInspector};
+
+#[derive(EguiInspect, serde::Serialize, serde::Deserialize, Clone, Debug, Default, PartialEq)]
+struct Point {
+    x: i32,
+    y: i32,
+}
+
+#[derive(EguiInspect, serde::Serialize, serde::Deserialize, Clone, Debug, Default, PartialEq)]
+struct Shape {
+    name: String,
+    points: Vec<Point>,
+}
+
+// borrowed fields can be copied, not pasted, but don't prevent the derive
+#[derive(EguiInspect, serde::Serialize, Default)]
+struct Tag {
+    name: &'static str,
+    weight: u32,
+}
+
+fn shape_harness() -> Harness<Shape, impl FnMut(&mut Shape, &mut egui::Ui)> {
+    let shape = Shape {
+        name: "triangle".to_owned(),
+        points: vec![Point { x: 1, y: 2 }, Point { x: 3, y: 4 }],
+    };
+    let mut harness = Harness::new(shape, |shape, ui| {
+        ui.add(EguiInspector::new(shape));
+    });
+    harness.click_text("Points[2]");
+    harness
+}
+
+fn error(harness: &Harness<Shape, impl FnMut(&mut Shape, &mut egui::Ui)>) -> Option<String> {
+    harness.frame.texts.iter().find(|(text, _)| text.starts_with('❌')).map(|(text, _)| text.clone())
+}
+
+#[test]
+fn paste_an_item_onto_another() {
+    let mut harness = shape_harness();
+    harness.right_click(harness.frame.find("Item 0"));
+    harness.click(harness.frame.find_last("Copy value"));
+    harness.right_click(harness.frame.find("Item 1"));
+    harness.click(harness.frame.find_last("Paste value"));
+    assert_eq!(harness.state.points, [Point { x: 1, y: 2 }, Point { x: 1, y: 2 }]);
+    assert_eq!(error(&harness), None);
+}
+
+#[test]
+fn paste_from_the_system_clipboard() {
+    let mut harness = shape_harness();
+    // nothing copied yet: Ctrl+V pastes the clipboard
+    harness.right_click(harness.frame.find("Item 1"));
+    assert!(harness.frame.shown("Paste value"), "{:?}", harness.frame.texts);
+    harness.run(vec![egui::Event::Paste("(x: 7, y: 8)".to_owned())]);
+    harness.settle();
+    assert_eq!(harness.state.points[1], Point { x: 7, y: 8 });
+    assert!(!harness.frame.shown("Paste value"), "the menu is still open: {:?}", harness.frame.texts);
+
+    // then the button pastes the same text, in JSON too
+    harness.right_click(harness.frame.find("Item 0"));
+    harness.click(harness.frame.find_last("Paste value"));
+    assert_eq!(harness.state.points[0], Point { x: 7, y: 8 });
+    harness.right_click(harness.frame.find("Name"));
+    harness.run(vec![egui::Event::Paste("\"square\"".to_owned())]);
+    harness.settle();
+    assert_eq!(harness.state.name, "square");
+}
+
+#[test]
+fn invalid_data_shows_an_error() {
+    let mut harness = shape_harness();
+    harness.right_click(harness.frame.find("Item 1"));
+    harness.run(vec![egui::Event::Paste("not a point".to_owned())]);
+    harness.run(vec![]);
+    assert_eq!(harness.state.points[1], Point { x: 3, y: 4 });
+    // shown in a tooltip on the label
+    harness.run(vec![egui::Event::PointerMoved(harness.frame.find("Item 1"))]);
+    harness.run(vec![]);
+    assert!(error(&harness).is_some(), "{:?}", harness.frame.texts);
+
+    // another type
+    harness.right_click(harness.frame.find("Name"));
+    harness.run(vec![egui::Event::Paste("(x: 7, y: 8)".to_owned())]);
+    harness.settle();
+    assert_eq!(harness.state.name, "triangle");
+}
+
+#[test]
+fn borrowed_fields_can_not_be_pasted() {
+    let mut harness = Harness::new(Tag { name: "fast", weight: 2 }, |tag, ui| {
+        ui.add(EguiInspector::new(tag));
+    });
+    harness.right_click(harness.frame.find("Name"));
+    assert!(harness.frame.shown("Copy value"), "{:?}", harness.frame.texts);
+    assert!(!harness.frame.shown("Paste value"), "{:?}", harness.frame.texts);
+    harness.run(vec![egui::Event::Paste("\"slow\"".to_owned())]);
+    harness.settle();
+    assert_eq!(harness.state.name, "fast");
+
+    harness.right_click(harness.frame.find("Weight"));
+    assert!(harness.frame.shown("Paste value"), "{:?}", harness.frame.texts);
+}
@@ -0,0 +1,47 @@
+//! Text buffer of the fields edited through their string representation.
+
+mod common;
+
+use std::net::Ipv4Addr;
+
+use common::Harness;
+use egui::accesskit::Role;
+use egui::{Key, Modifiers};
+use egui_field_editor::{EguiInspect, EguiInspector};
+
+#[derive(EguiInspect, Clone, Debug)]
+struct Server {
+    #[inspect(from_string)]
+    address: Ipv4Addr,
+    port: u16,
+}
+
+#[test]
+fn invalid_text_is_kept_with_the_parse_error() {
+    let server = Server {
+        address: Ipv4Addr::new(10, 0, 0, 1),
+        port: 80,
+    };
+    let mut harness = Harness::new(server, |server, ui| {
+        ui.add(EguiInspector::new(server));
+    });
+    let field = harness.frame.widget_on_row(Role::TextInput, "Address").rect;
+    harness.click(field.center());
+    harness.key(Key::A, Modifiers::COMMAND);
+    harness.type_text("192.168.");
+    assert_eq!(harness.state.address, Ipv4Addr::new(10, 0, 0, 1));
+    assert!(harness.frame.shown("192.168."), "{:?}", harness.frame.texts);
+    assert!(harness.frame.shown("❌ invalid IPv4 address syntax"), "{:?}", harness.frame.texts);
+
+    // the text survives the loss of the focus and the next frames
+    harness.click(harness.frame.find("Port"));
+    harness.settle();
+    assert!(harness.frame.shown("192.168."), "{:?}", harness.frame.texts);
+    assert!(harness.frame.shown("❌ invalid IPv4 address syntax"), "{:?}", harness.frame.texts);
+    assert_eq!(harness.state.address, Ipv4Addr::new(10, 0, 0, 1));
+
+    harness.click(field.right_center() - egui::vec2(5., 0.));
+    harness.type_text("1.1");
+    assert_eq!(harness.state.address, Ipv4Addr::new(192, 168, 1, 1));
+    assert!(!harness.frame.texts.iter().any(|(text, _)| text.starts_with('❌')), "{:?}", harness.frame.texts);
+}
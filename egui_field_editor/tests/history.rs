@@ -0,0 +1,115 @@
+//! Undo/redo of the edits made through an inspector with a history.
+
+mod common;
+
+use common::Harness;
+use egui::accesskit::Role;
+use egui::{Key, Modifiers};
+use egui_field_editor::{EguiInspect, EguiInspector, InspectorHistory};
+
+#[derive(EguiInspect, Clone, Debug, Default, PartialEq)]
+struct Settings {
+    #[inspect(slider(min = 0., max = 100.))]
+    volume: f32,
+    names: Vec<String>,
+}
+
+type State = (Settings, InspectorHistory<Settings>);
+
+fn harness(history: InspectorHistory<Settings>) -> Harness<State, impl FnMut(&mut State, &mut egui::Ui)> {
+    let settings = Settings {
+        volume: 0.,
+        names: vec!["first".to_owned(), "second".to_owned()],
+    };
+    Harness::new((settings, history), |(settings, history), ui| {
+        ui.add(EguiInspector::new(settings).with_history(history));
+    })
+}
+
+/// Clicks on the slider of the volume, at `fraction` of its width.
+fn set_volume(harness: &mut Harness<State, impl FnMut(&mut State, &mut egui::Ui)>, fraction: f32) {
+    let slider = harness.frame.widget_on_row(Role::Slider, "Volume").rect;
+    harness.click(slider.left_center() + egui::vec2(slider.width() * fraction, 0.));
+}
+
+#[test]
+fn slider_drag_is_one_undo_step() {
+    let mut harness = harness(InspectorHistory::new());
+    let slider = harness.frame.widget_on_row(Role::Slider, "Volume").rect;
+    harness.drag(slider.left_center(), slider.center(), 10);
+    let volume = harness.state.0.volume;
+    assert!(volume > 40. && volume < 60., "{volume}");
+    assert_eq!(harness.state.1.undo_len(), 1);
+
+    harness.click(harness.frame.button("⟲ Undo").rect.center());
+    assert_eq!(harness.state.0.volume, 0.);
+    assert_eq!((harness.state.1.undo_len(), harness.state.1.redo_len()), (0, 1));
+    assert!(harness.frame.button("⟲ Undo").disabled);
+
+    harness.click(harness.frame.button("⟳ Redo").rect.center());
+    assert_eq!(harness.state.0.volume, volume);
+    assert_eq!((harness.state.1.undo_len(), harness.state.1.redo_len()), (1, 0));
+}
+
+#[test]
+fn depth_is_capped() {
+    let mut harness = harness(InspectorHistory::with_max_depth(3));
+    for step in 1..=5 {
+        set_volume(&mut harness, step as f32 * 0.1);
+    }
+    let volumes: Vec<f32> = (0..3)
+        .map(|_| {
+            harness.click(harness.frame.button("⟲ Undo").rect.center());
+            harness.state.0.volume
+        })
+        .collect();
+    assert_eq!(harness.state.1.undo_len(), 0);
+    assert!(volumes[0] > volumes[1] && volumes[1] > volumes[2], "{volumes:?}");
+    // the oldest steps were dropped: the volume doesn't go back to 0
+    assert!(volumes[2] > 0., "{volumes:?}");
+    assert!(harness.frame.button("⟲ Undo").disabled);
+}
+
+#[test]
+fn new_edit_clears_redo() {
+    let mut harness = harness(InspectorHistory::new());
+    set_volume(&mut harness, 0.2);
+    set_volume(&mut harness, 0.8);
+    harness.click(harness.frame.button("⟲ Undo").rect.center());
+    assert_eq!(harness.state.1.redo_len(), 1);
+
+    set_volume(&mut harness, 0.5);
+    assert_eq!((harness.state.1.undo_len(), harness.state.1.redo_len()), (2, 0));
+    assert!(harness.frame.button("⟳ Redo").disabled);
+}
+
+#[test]
+fn keyboard_shortcuts() {
+    let mut harness = harness(InspectorHistory::new());
+    set_volume(&mut harness, 0.5);
+    let volume = harness.state.0.volume;
+
+    harness.key(Key::Z, Modifiers::COMMAND);
+    assert_eq!(harness.state.0.volume, 0.);
+    harness.key(Key::Z, Modifiers::COMMAND | Modifiers::SHIFT);
+    assert_eq!(harness.state.0.volume, volume);
+    harness.key(Key::Z, Modifiers::COMMAND);
+    assert_eq!(harness.state.0.volume, 0.);
+    harness.key(Key::Y, Modifiers::COMMAND);
+    assert_eq!(harness.state.0.volume, volume);
+    assert_eq!((harness.state.1.undo_len(), harness.state.1.redo_len()), (1, 0));
+}
+
+#[test]
+fn list_reorder_is_undoable() {
+    let mut harness = harness(InspectorHistory::new());
+    harness.click_text("Names[2]");
+    let handles: Vec<_> = harness.frame.texts.iter().filter(|(text, _)| text == "☰").map(|(_, pos)| *pos).collect();
+    assert_eq!(handles.len(), 2, "{:?}", harness.frame.texts);
+    harness.drag(handles[0], handles[1] + egui::vec2(0., 15.), 10);
+    assert_eq!(harness.state.0.names, ["second", "first"]);
+    assert_eq!(harness.state.1.undo_len(), 1);
+
+    harness.click(harness.frame.button("⟲ Undo").rect.center());
+    assert_eq!(harness.state.0.names, ["first", "second"]);
+}
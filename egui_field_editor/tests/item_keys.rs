@@ -0,0 +1,84 @@
+//! Labelling the items of the lists with their key, and keeping their state when reordered.
+
+mod common;
+
+use common::Harness;
+use egui_field_editor::{EguiInspect, EguiInspector, InspectKey};
+
+#[derive(EguiInspect, Clone, Debug, Default, PartialEq)]
+struct Layer {
+    name: String,
+    opacity: u8,
+}
+
+impl InspectKey for Layer {
+    fn inspect_key(&self) -> String {
+        self.name.to_uppercase()
+    }
+}
+
+#[derive(EguiInspect, Default)]
+struct Image {
+    #[inspect(key = "name")]
+    layers: Vec<Layer>,
+    #[inspect(key)]
+    masks: Vec<Layer>,
+}
+
+fn layer(name: &str) -> Layer {
+    Layer {
+        name: name.to_owned(),
+        opacity: 0,
+    }
+}
+
+fn harness(layers: &[&str]) -> Harness<Image, impl FnMut(&mut Image, &mut egui::Ui)> {
+    let image = Image {
+        layers: layers.iter().map(|name| layer(name)).collect(),
+        masks: vec![layer("alpha"), layer("depth")],
+    };
+    Harness::new(image, |image, ui| {
+        ui.add(EguiInspector::new(image));
+    })
+}
+
+#[test]
+fn items_are_labelled_with_their_key() {
+    let mut harness = harness(&["sky", "ground"]);
+    harness.click_text("Layers[2]");
+    harness.click_text("Masks[2]");
+    let frame = &harness.frame;
+    for label in ["sky", "ground", "ALPHA", "DEPTH"] {
+        assert!(frame.shown(label), "{label}: {:?}", frame.texts);
+    }
+    assert!(!frame.shown("Item 0"), "{:?}", frame.texts);
+}
+
+#[test]
+fn duplicate_keys_are_made_unique() {
+    let mut harness = harness(&["sky", "sky", "", "sky"]);
+    harness.click_text("Layers[4]");
+    let frame = &harness.frame;
+    for label in ["sky", "sky (1)", "Item 2", "sky (3)"] {
+        assert_eq!(frame.count(label), 1, "{label}: {:?}", frame.texts);
+    }
+}
+
+#[test]
+fn expanded_items_stay_expanded_when_moved() {
+    let mut harness = harness(&["sky", "ground", "trees"]);
+    harness.click_text("Layers[3]");
+    harness.click_text("sky");
+    assert_eq!(harness.frame.count("Opacity"), 1, "{:?}", harness.frame.texts);
+    let below_sky = |frame: &common::Frame| frame.find("Opacity").y > frame.find("sky").y;
+    assert!(below_sky(&harness.frame));
+
+    let handles: Vec<_> = harness.frame.texts.iter().filter(|(text, _)| text == "☰").map(|(_, pos)| *pos).collect();
+    harness.drag(handles[0], handles[2] + egui::vec2(0., 5.), 10);
+    let names: Vec<_> = harness.state.layers.iter().map(|layer| layer.name.as_str()).collect();
+    assert_eq!(names, ["ground", "trees", "sky"]);
+    // the fields shown are still the ones of `sky`, now the last item
+    let frame = &harness.frame;
+    assert_eq!(frame.count("Opacity"), 1, "{:?}", frame.texts);
+    assert!(below_sky(frame) && frame.find("sky").y > frame.find("trees").y, "{:?}", frame.texts);
+}
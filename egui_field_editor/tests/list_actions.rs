@@ -0,0 +1,165 @@
+//! Context menu of the list items, and the length limits of the lists.
+
+mod common;
+
+use common::{Frame, Harness};
+use egui::Pos2;
+use egui_field_editor::{EguiInspect, EguiInspector};
+
+#[derive(EguiInspect, Clone, Debug, Default, PartialEq)]
+struct Layer {
+    name: String,
+    // not copied by `copy_field_from`, only by `Clone`
+    #[inspect(hidden)]
+    id: u64,
+}
+
+// duplicated by copying its fields into a default note
+#[derive(EguiInspect, Debug, Default, PartialEq)]
+struct Note {
+    text: String,
+}
+
+// the derived `duplicate` can't clone a generic type, its fields are copied
+#[derive(EguiInspect, Clone, Default)]
+struct Labeled<T> {
+    value: T,
+}
+
+#[derive(EguiInspect, Default)]
+struct Image {
+    layers: Vec<Layer>,
+    notes: Vec<Note>,
+    #[inspect(min_len = 1, max_len = 2)]
+    sizes: Vec<u32>,
+    #[inspect(fixed_len)]
+    channels: Vec<u8>,
+    labels: Vec<Labeled<u8>>,
+}
+
+fn image() -> Image {
+    Image {
+        layers: vec![
+            Layer { name: "background".to_owned(), id: 7 },
+            Layer { name: "sky".to_owned(), id: 8 },
+        ],
+        notes: vec![Note { text: "draft".to_owned() }],
+        sizes: vec![16, 32],
+        channels: vec![1, 2, 3],
+        labels: vec![Labeled { value: 1 }],
+    }
+}
+
+fn harness(read_only: bool) -> Harness<Image, impl FnMut(&mut Image, &mut egui::Ui)> {
+    Harness::new(image(), move |image, ui| {
+        let inspector = EguiInspector::new(image);
+        ui.add(if read_only { inspector.read_only() } else { inspector });
+    })
+}
+
+/// Drag handles of the items shown, from top to bottom.
+fn handles(frame: &Frame) -> Vec<Pos2> {
+    let mut handles: Vec<_> = frame.texts.iter().filter(|(text, _)| text == "☰").map(|(_, pos)| *pos).collect();
+    handles.sort_by(|a, b| a.y.total_cmp(&b.y));
+    handles
+}
+
+/// `true` if the entry `label` of the open context menu is disabled.
+fn menu_disabled(frame: &Frame, label: &str) -> bool {
+    frame
+        .widgets
+        .iter()
+        .rfind(|widget| widget.label == label)
+        .unwrap_or_else(|| panic!("no `{label}` entry: {:?}", frame.texts))
+        .disabled
+}
+
+/// `+`, `-` and `🗑` buttons of the list below `header`, whether they are disabled.
+fn buttons_disabled(frame: &Frame, header: &str) -> [bool; 3] {
+    let y = frame.find(header).y;
+    ["+", "-", "🗑"].map(|label| {
+        frame
+            .widgets
+            .iter()
+            .filter(|widget| widget.label == label && widget.rect.center().y > y)
+            .min_by(|a, b| a.rect.center().y.total_cmp(&b.rect.center().y))
+            .unwrap_or_else(|| panic!("no `{label}` button below `{header}`: {:?}", frame.widgets))
+            .disabled
+    })
+}
+
+#[test]
+fn duplicate_clones_the_item() {
+    let mut harness = harness(false);
+    harness.click_text("Layers[2]");
+    harness.right_click(handles(&harness.frame)[0]);
+    harness.click(harness.frame.find_last("Duplicate"));
+    assert_eq!(
+        harness.state.layers,
+        [
+            Layer { name: "background".to_owned(), id: 7 },
+            Layer { name: "background".to_owned(), id: 7 },
+            Layer { name: "sky".to_owned(), id: 8 },
+        ]
+    );
+}
+
+#[test]
+fn duplicate_copies_the_fields_without_clone() {
+    let mut harness = harness(false);
+    harness.click_text("Notes[1]");
+    harness.right_click(handles(&harness.frame)[0]);
+    harness.click(harness.frame.find_last("Duplicate"));
+    assert_eq!(harness.state.notes, [Note { text: "draft".to_owned() }, Note { text: "draft".to_owned() }]);
+
+    harness.click_text("Labels[1]");
+    harness.right_click(*handles(&harness.frame).last().unwrap());
+    harness.click(harness.frame.find_last("Duplicate"));
+    assert_eq!(harness.state.labels.iter().map(|label| label.value).collect::<Vec<_>>(), [1, 1]);
+}
+
+#[test]
+fn length_limits_disable_the_buttons() {
+    let mut harness = harness(false);
+    // two sizes: at the maximum
+    assert_eq!(buttons_disabled(&harness.frame, "Sizes[2]"), [true, false, true]);
+    harness.click_text("Sizes[2]");
+    harness.right_click(handles(&harness.frame)[0]);
+    for (label, disabled) in [("Insert above", true), ("Insert below", true), ("Duplicate", true), ("Remove", false)] {
+        assert_eq!(menu_disabled(&harness.frame, label), disabled, "{label}");
+    }
+    harness.click(harness.frame.find_last("Remove"));
+    assert_eq!(harness.state.sizes, [32]);
+    // one size: at the minimum
+    assert_eq!(buttons_disabled(&harness.frame, "Sizes[1]"), [false, true, true]);
+    harness.right_click(handles(&harness.frame)[0]);
+    for (label, disabled) in [("Insert above", false), ("Duplicate", false), ("Remove", true)] {
+        assert_eq!(menu_disabled(&harness.frame, label), disabled, "{label}");
+    }
+}
+
+#[test]
+fn fixed_length_lists_can_only_be_reordered() {
+    let mut harness = harness(false);
+    assert_eq!(buttons_disabled(&harness.frame, "Channels[3]"), [true, true, true]);
+    harness.click_text("Channels[3]");
+    harness.right_click(handles(&harness.frame)[1]);
+    assert!(menu_disabled(&harness.frame, "Insert above"));
+    assert!(menu_disabled(&harness.frame, "Remove"));
+    // no copy can be added
+    assert!(!harness.frame.shown("Duplicate"), "{:?}", harness.frame.texts);
+    harness.click(harness.frame.find_last("Move to top"));
+    assert_eq!(harness.state.channels, [2, 1, 3]);
+}
+
+#[test]
+fn read_only_lists_have_no_drag_handles() {
+    let mut harness = harness(true);
+    harness.click_text("Layers[2]");
+    assert!(harness.frame.shown("Item 1"), "{:?}", harness.frame.texts);
+    assert_eq!(handles(&harness.frame), []);
+
+    let mut harness = self::harness(false);
+    harness.click_text("Layers[2]");
+    assert_eq!(handles(&harness.frame).len(), 2);
+}
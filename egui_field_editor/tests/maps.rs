@@ -0,0 +1,118 @@
+//! Editing the keys and entries of the maps.
+
+mod common;
+
+use std::collections::{BTreeMap, HashMap};
+
+use common::{Frame, Harness};
+use egui::accesskit::Role;
+use egui::{Key, Modifiers};
+use egui_field_editor::{EguiInspect, EguiInspector};
+
+#[derive(EguiInspect, Default)]
+struct Ports {
+    by_name: BTreeMap<String, u16>,
+}
+
+fn ports() -> Ports {
+    Ports {
+        by_name: BTreeMap::from([("http".to_owned(), 80), ("ssh".to_owned(), 22)]),
+    }
+}
+
+fn harness<S: EguiInspect>(state: S) -> Harness<S, impl FnMut(&mut S, &mut egui::Ui)> {
+    Harness::new(state, |state, ui| {
+        ui.add(EguiInspector::new(state));
+    })
+}
+
+/// Replaces the text of the key of the entry `index` by `text`, keeping the focus on it.
+fn edit_key<S, F: FnMut(&mut S, &mut egui::Ui)>(harness: &mut Harness<S, F>, index: usize, text: &str) {
+    let key = harness.frame.widgets(Role::TextInput)[index].rect;
+    harness.click(key.center());
+    harness.key(Key::A, Modifiers::COMMAND);
+    harness.type_text(text);
+}
+
+/// Vertical position of each text, `None` if it isn't shown.
+fn rows(frame: &Frame, texts: &[&str]) -> Vec<Option<f32>> {
+    texts.iter().map(|text| frame.texts.iter().find(|(t, _)| t == text).map(|(_, pos)| pos.y)).collect()
+}
+
+#[test]
+fn rename_a_key() {
+    let mut harness = harness(ports());
+    harness.click_text("By Name[2]");
+    edit_key(&mut harness, 0, "https");
+    // the entry is renamed once the edit is finished
+    assert!(harness.state.by_name.contains_key("http"));
+    harness.click(harness.frame.find("By Name[2]") + egui::vec2(200., 0.));
+    assert_eq!(harness.state.by_name, BTreeMap::from([("https".to_owned(), 80), ("ssh".to_owned(), 22)]));
+}
+
+#[test]
+fn renaming_to_an_existing_key_is_rejected() {
+    let mut harness = harness(ports());
+    harness.click_text("By Name[2]");
+    edit_key(&mut harness, 0, "ssh");
+    assert!(harness.frame.shown("❌ Another entry has this key"), "{:?}", harness.frame.texts);
+
+    harness.click(harness.frame.find("By Name[2]") + egui::vec2(200., 0.));
+    assert_eq!(harness.state.by_name, ports().by_name);
+    assert!(!harness.frame.shown("❌ Another entry has this key"), "{:?}", harness.frame.texts);
+    assert_eq!(harness.frame.count("http"), 1, "{:?}", harness.frame.texts);
+
+    let mut ports = ports();
+    assert_eq!(ports.set_by_path_from_str("by_name[0].key", "ssh"), Err("Another entry has this key".to_owned()));
+    assert_eq!(ports.by_name, self::ports().by_name);
+}
+
+#[test]
+fn add_and_remove_entries() {
+    let mut harness = harness(ports());
+    harness.click_text("By Name[2]");
+    harness.click(harness.frame.button("+").rect.center());
+    assert_eq!(harness.state.by_name.get(""), Some(&0));
+    // a single entry can have the default key
+    assert!(harness.frame.button("+").disabled);
+
+    // the entries are sorted: the new entry is the first one
+    let removes: Vec<_> = harness.frame.widgets(Role::Button).into_iter().filter(|button| button.label == "🗑").collect();
+    harness.click(removes[1].rect.center());
+    assert_eq!(harness.state.by_name, BTreeMap::from([(String::new(), 0), ("ssh".to_owned(), 22)]));
+}
+
+#[test]
+fn hash_maps_are_shown_sorted() {
+    #[derive(EguiInspect, Default)]
+    struct Env {
+        vars: HashMap<String, u8>,
+    }
+    let vars = ["zeta", "alpha", "mid"].into_iter().map(|name| (name.to_owned(), 0)).collect();
+    let mut harness = harness(Env { vars });
+    harness.click_text("Vars[3]");
+    let rows = rows(&harness.frame, &["alpha", "mid", "zeta"]);
+    assert!(rows.iter().all(Option::is_some) && rows.is_sorted(), "{:?}", harness.frame.texts);
+    // and their entries have the paths of this order
+    assert_eq!(harness.state.get_by_path("vars[2].key").and_then(|key| key.downcast_ref::<String>()).map(String::as_str), Some("zeta"));
+}
+
+#[cfg(feature = "indexmap")]
+#[test]
+fn index_maps_can_be_reordered() {
+    use indexmap::IndexMap;
+
+    #[derive(EguiInspect, Default)]
+    struct Steps {
+        steps: IndexMap<String, u8>,
+    }
+    let steps = ["build", "test", "deploy"].into_iter().map(|name| (name.to_owned(), 0)).collect();
+    let mut harness = harness(Steps { steps });
+    harness.click_text("Steps[3]");
+    let handles: Vec<_> = harness.frame.texts.iter().filter(|(text, _)| text == "☰").map(|(_, pos)| *pos).collect();
+    assert_eq!(handles.len(), 3, "{:?}", harness.frame.texts);
+    harness.drag(handles[0], handles[1] + egui::vec2(0., 15.), 10);
+    assert_eq!(harness.state.steps.keys().collect::<Vec<_>>(), ["test", "build", "deploy"]);
+    let rows = rows(&harness.frame, &["test", "build", "deploy"]);
+    assert!(rows.iter().all(Option::is_some) && rows.is_sorted(), "{:?}", harness.frame.texts);
+}
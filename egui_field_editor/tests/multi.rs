@@ -0,0 +1,102 @@
+//! Editing several objects at once with `EguiInspector::multi`.
+
+mod common;
+
+use common::Harness;
+use egui::accesskit::Role;
+use egui_field_editor::{EguiInspect, EguiInspector};
+
+/// Text shown instead of a mixed value.
+const MIXED: &str = "—";
+
+#[derive(EguiInspect, Clone, Debug, Default, PartialEq)]
+struct Light {
+    name: String,
+    on: bool,
+    level: u8,
+    room: String,
+}
+
+fn light(name: &str, on: bool, level: u8) -> Light {
+    Light {
+        name: name.to_owned(),
+        on,
+        level,
+        room: "hall".to_owned(),
+    }
+}
+
+fn harness(lights: Vec<Light>) -> Harness<Vec<Light>, impl FnMut(&mut Vec<Light>, &mut egui::Ui)> {
+    Harness::new(lights, |lights, ui| {
+        let mut selection: Vec<&mut Light> = lights.iter_mut().collect();
+        ui.add(EguiInspector::multi(&mut selection));
+    })
+}
+
+#[test]
+fn differing_fields_are_mixed() {
+    let harness = harness(vec![light("desk", true, 5), light("lamp", false, 5), light("desk", true, 9)]);
+    let frame = &harness.frame;
+    // the name and level differ, the empty name field shows the mixed hint
+    assert_eq!(frame.count(MIXED), 2, "{:?}", frame.texts);
+    assert!((frame.find(MIXED).y - frame.find("Name").y).abs() < 10., "{:?}", frame.texts);
+    assert!(frame.widget_on_row(Role::CheckBox, "On").mixed);
+    assert!(frame.shown("hall"), "{:?}", frame.texts);
+
+    // the same objects have no mixed field
+    let harness = self::harness(vec![light("desk", true, 5), light("desk", true, 5)]);
+    let frame = &harness.frame;
+    assert!(!frame.shown(MIXED), "{:?}", frame.texts);
+    assert!(!frame.widget_on_row(Role::CheckBox, "On").mixed);
+    assert!(frame.shown("desk") && frame.shown("5"), "{:?}", frame.texts);
+}
+
+#[test]
+fn edits_apply_to_every_object() {
+    let mut harness = harness(vec![light("desk", true, 5), light("lamp", false, 9)]);
+    let name = harness.frame.widget_on_row(Role::TextInput, "Name").rect;
+    harness.click(name.center());
+    harness.type_text("reading");
+    harness.click(harness.frame.find("Room") + egui::vec2(-60., 0.));
+    assert_eq!(harness.state, [light("reading", true, 5), light("reading", false, 9)]);
+
+    let room = harness.frame.widget_on_row(Role::TextInput, "Room").rect;
+    harness.click(room.right_center() - egui::vec2(5., 0.));
+    harness.type_text("way");
+    assert_eq!(harness.state.iter().map(|light| light.room.as_str()).collect::<Vec<_>>(), ["hallway", "hallway"]);
+    // the other mixed fields keep their own values
+    assert_eq!(harness.state.iter().map(|light| (light.on, light.level)).collect::<Vec<_>>(), [(true, 5), (false, 9)]);
+
+    // a mixed checkbox gets the toggled value of the first object
+    let on = harness.frame.widget_on_row(Role::CheckBox, "On").rect;
+    harness.click(on.center());
+    assert_eq!(harness.state.iter().map(|light| light.on).collect::<Vec<_>>(), [false, false]);
+    assert!(!harness.frame.widget_on_row(Role::CheckBox, "On").mixed);
+    assert_eq!(harness.state.iter().map(|light| light.level).collect::<Vec<_>>(), [5, 9]);
+}
+
+/// A manual implementation, copied by `Clone` (it doesn't implement `copy_field_from`).
+#[derive(Clone, Debug, Default, PartialEq)]
+struct Color(String);
+
+impl EguiInspect for Color {
+    fn inspect_with_custom_id(&mut self, _parent_id: egui::Id, label: &str, tooltip: &str, read_only: bool, ui: &mut egui::Ui) -> egui_field_editor::InspectResponse {
+        self.0.inspect(label, tooltip, read_only, ui)
+    }
+}
+
+#[derive(EguiInspect, Clone, Debug, Default, PartialEq)]
+struct Bulb {
+    color: Color,
+}
+
+#[test]
+fn fields_without_copy_are_cloned() {
+    let red = Bulb { color: Color("red".to_owned()) };
+    let mut bulb = Bulb::default();
+    bulb.copy_field_from(&red, "color").unwrap();
+    assert_eq!(bulb, red);
+    let mut bulb = Bulb::default();
+    bulb.copy_field_from(&red, "").unwrap();
+    assert_eq!(bulb, red);
+}
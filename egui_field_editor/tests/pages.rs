@@ -0,0 +1,113 @@
+//! Pagination of long lists.
+
+use egui_field_editor::{EguiInspect, EguiInspector};
+
+#[derive(EguiInspect, Default)]
+struct Samples {
+    values: Vec<u32>,
+}
+
+/// Texts shown by a frame, with their center.
+fn run_frame(ctx: &egui::Context, samples: &mut Samples, events: Vec<egui::Event>) -> Vec<(String, egui::Pos2)> {
+    let input = egui::RawInput {
+        events,
+        ..Default::default()
+    };
+    let mut output = ctx.run(input, |ctx| {
+        egui::CentralPanel::default().show(ctx, |ui| {
+            ui.add(EguiInspector::new(samples).with_page_size(10));
+        });
+    });
+    output.textures_delta.clear();
+    output
+        .shapes
+        .iter()
+        .filter_map(|shape| match &shape.shape {
+            egui::epaint::Shape::Text(text) => Some((text.galley.text().to_owned(), text.pos + text.galley.rect.center().to_vec2())),
+            _ => None,
+        })
+        .collect()
+}
+
+fn click(ctx: &egui::Context, samples: &mut Samples, pos: egui::Pos2) -> Vec<(String, egui::Pos2)> {
+    let button = |pressed| egui::Event::PointerButton {
+        pos,
+        button: egui::PointerButton::Primary,
+        pressed,
+        modifiers: Default::default(),
+    };
+    run_frame(ctx, samples, vec![egui::Event::PointerMoved(pos), button(true)]);
+    run_frame(ctx, samples, vec![button(false)]);
+    // let the header open
+    for _ in 0..20 {
+        run_frame(ctx, samples, vec![]);
+    }
+    run_frame(ctx, samples, vec![])
+}
+
+fn find(texts: &[(String, egui::Pos2)], text: &str) -> egui::Pos2 {
+    texts
+        .iter()
+        .find(|(t, _)| t == text)
+        .unwrap_or_else(|| panic!("`{text}` not shown: {texts:?}"))
+        .1
+}
+
+fn shown(texts: &[(String, egui::Pos2)], text: &str) -> bool {
+    texts.iter().any(|(t, _)| t == text)
+}
+
+/// Opens the list of `samples` and returns the texts shown once open.
+fn open(ctx: &egui::Context, samples: &mut Samples) -> Vec<(String, egui::Pos2)> {
+    let texts = run_frame(ctx, samples, vec![]);
+    let header = format!("Values[{}]", samples.values.len());
+    click(ctx, samples, find(&texts, &header))
+}
+
+#[test]
+fn only_the_current_page_is_shown() {
+    let ctx = egui::Context::default();
+    let mut samples = Samples {
+        values: (0..250).collect(),
+    };
+    let texts = open(&ctx, &mut samples);
+    find(&texts, "0..10 of 250");
+    assert!(shown(&texts, "Item 0") && shown(&texts, "Item 9"), "{texts:?}");
+    assert!(!shown(&texts, "Item 10"), "{texts:?}");
+
+    let texts = click(&ctx, &mut samples, find(&texts, "▶"));
+    find(&texts, "10..20 of 250");
+    assert!(shown(&texts, "Item 10") && shown(&texts, "Item 19"), "{texts:?}");
+    assert!(!shown(&texts, "Item 9") && !shown(&texts, "Item 20"), "{texts:?}");
+
+    let texts = click(&ctx, &mut samples, find(&texts, "◀"));
+    find(&texts, "0..10 of 250");
+}
+
+#[test]
+fn short_lists_have_no_page_selector() {
+    let ctx = egui::Context::default();
+    let mut samples = Samples {
+        values: (0..10).collect(),
+    };
+    let texts = open(&ctx, &mut samples);
+    assert!(shown(&texts, "Item 9"), "{texts:?}");
+    assert!(!texts.iter().any(|(text, _)| text.contains(" of ")), "{texts:?}");
+}
+
+#[test]
+fn page_is_clamped_when_the_list_shrinks() {
+    let ctx = egui::Context::default();
+    let mut samples = Samples {
+        values: (0..30).collect(),
+    };
+    let texts = open(&ctx, &mut samples);
+    let texts = click(&ctx, &mut samples, find(&texts, "▶"));
+    let texts = click(&ctx, &mut samples, find(&texts, "▶"));
+    find(&texts, "20..30 of 30");
+
+    samples.values.truncate(15);
+    let texts = run_frame(&ctx, &mut samples, vec![]);
+    find(&texts, "10..15 of 15");
+    assert!(shown(&texts, "Item 14") && !shown(&texts, "Item 15"), "{texts:?}");
+}
@@ -0,0 +1,137 @@
+//! Save/Load toolbar of the inspectors, writing to a temporary directory.
+
+#![cfg(feature = "serde")]
+
+mod common;
+
+use std::path::{Path, PathBuf};
+
+use common::Harness;
+use egui::accesskit::Role;
+use egui_field_editor::{EguiInspect, EguiInspector, Format};
+
+#[derive(EguiInspect, serde::Serialize, serde::Deserialize, Clone, Debug, Default, PartialEq)]
+struct Config {
+    name: String,
+    verbose: bool,
+}
+
+/// A directory removed when dropped.
+struct TempDir(PathBuf);
+
+impl TempDir {
+    fn new(test: &str) -> Self {
+        let path = std::env::temp_dir().join(format!("egui_field_editor_{test}_{}", std::process::id()));
+        std::fs::create_dir_all(&path).unwrap();
+        Self(path)
+    }
+    fn join(&self, file: &str) -> PathBuf {
+        self.0.join(file)
+    }
+}
+
+impl Drop for TempDir {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_dir_all(&self.0);
+    }
+}
+
+/// The config, and the number of frames in which the inspector reported a change.
+type State = (Config, usize);
+
+fn harness(path: &Path, read_only: bool) -> Harness<State, impl FnMut(&mut State, &mut egui::Ui)> {
+    let config = Config {
+        name: "server".to_owned(),
+        verbose: false,
+    };
+    let path = path.to_owned();
+    Harness::new((config, 0), move |(config, changes), ui| {
+        let mut inspector = EguiInspector::new(config).with_persistence(&path, Format::Json);
+        if read_only {
+            inspector = inspector.read_only();
+        }
+        if ui.add(inspector).changed() {
+            *changes += 1;
+        }
+    })
+}
+
+fn toggle_verbose(harness: &mut Harness<State, impl FnMut(&mut State, &mut egui::Ui)>) {
+    let checkbox = harness.frame.widget_on_row(Role::CheckBox, "Verbose").rect;
+    harness.click(checkbox.left_center() + egui::vec2(8., 0.));
+}
+
+const MODIFIED: &str = "● Modified since load";
+
+#[test]
+fn save_writes_the_file() {
+    let dir = TempDir::new("save");
+    let path = dir.join("config.json");
+    let mut harness = harness(&path, false);
+    // nothing saved yet
+    assert!(harness.frame.shown(MODIFIED), "{:?}", harness.frame.texts);
+    assert!(harness.frame.shown("config.json"), "{:?}", harness.frame.texts);
+
+    harness.click(harness.frame.button("💾 Save").rect.center());
+    assert_eq!(Format::Json.load::<Config>(&path).unwrap(), harness.state.0);
+    assert!(!harness.frame.shown(MODIFIED), "{:?}", harness.frame.texts);
+    // the content of an existing file is compared with the object
+    let other = self::harness(&path, false);
+    assert!(!other.frame.shown(MODIFIED), "{:?}", other.frame.texts);
+
+    toggle_verbose(&mut harness);
+    assert!(harness.frame.shown(MODIFIED), "{:?}", harness.frame.texts);
+    harness.click(harness.frame.button("💾 Save").rect.center());
+    assert!(!harness.frame.shown(MODIFIED), "{:?}", harness.frame.texts);
+    assert!(Format::Json.load::<Config>(&path).unwrap().verbose);
+}
+
+#[test]
+fn load_replaces_the_object() {
+    let dir = TempDir::new("load");
+    let path = dir.join("config.json");
+    let saved = Config {
+        name: "client".to_owned(),
+        verbose: true,
+    };
+    Format::Json.save(&saved, &path).unwrap();
+    let mut harness = harness(&path, false);
+    assert!(harness.frame.shown(MODIFIED), "{:?}", harness.frame.texts);
+
+    harness.click(harness.frame.button("📂 Load").rect.center());
+    assert_eq!(harness.state, (saved, 1));
+    assert!(!harness.frame.shown(MODIFIED), "{:?}", harness.frame.texts);
+}
+
+#[test]
+fn load_errors_are_shown() {
+    let dir = TempDir::new("load_errors");
+    let path = dir.join("config.json");
+    let mut harness = harness(&path, false);
+    harness.click(harness.frame.button("📂 Load").rect.center());
+    assert_eq!(harness.state, (Config { name: "server".to_owned(), verbose: false }, 0));
+    let error = |harness: &Harness<State, _>| harness.frame.texts.iter().find(|(text, _)| text.starts_with('❌')).map(|(text, _)| text.clone());
+    let missing = error(&harness).expect("no error shown");
+    assert!(missing.contains("Failed to read"), "{missing}");
+
+    std::fs::write(&path, "not json").unwrap();
+    harness.click(harness.frame.button("📂 Load").rect.center());
+    assert_eq!(harness.state.1, 0);
+    let invalid = error(&harness).expect("no error shown");
+    assert_ne!(invalid, missing);
+
+    // the error is cleared by the next successful action
+    harness.click(harness.frame.button("💾 Save").rect.center());
+    assert_eq!(error(&harness), None);
+}
+
+#[test]
+fn read_only_inspectors_can_not_load() {
+    let dir = TempDir::new("read_only");
+    let path = dir.join("config.json");
+    Format::Json.save(&Config::default(), &path).unwrap();
+    let mut harness = harness(&path, true);
+    assert!(harness.frame.button("📂 Load").disabled);
+    harness.click(harness.frame.button("📂 Load").rect.center());
+    assert_eq!(harness.state.0.name, "server");
+}
@@ -0,0 +1,122 @@
+//! Fields found by their path with `field_paths`, `get_by_path` and `set_by_path_from_str`.
+
+use std::collections::BTreeMap;
+
+use egui_field_editor::{EguiInspect, FieldInfo};
+
+#[derive(EguiInspect, Clone, Debug, Default, PartialEq)]
+struct Point {
+    name: String,
+    coords: [f32; 2],
+}
+
+#[derive(EguiInspect, Clone, Debug, PartialEq)]
+enum Marker {
+    // also the name of a parameter of the generated methods
+    Pin { label: String },
+    Dot(u8),
+}
+
+impl Default for Marker {
+    fn default() -> Self {
+        Self::Dot(0)
+    }
+}
+
+#[derive(EguiInspect, Clone, Debug, Default, PartialEq)]
+struct Route {
+    array: [Point; 3],
+    stops: Vec<Point>,
+    origin: Option<Point>,
+    speeds: BTreeMap<String, u16>,
+    marker: Marker,
+}
+
+fn route() -> Route {
+    let point = |name: &str| Point {
+        name: name.to_owned(),
+        coords: [1., 2.],
+    };
+    Route {
+        array: [point("a"), point("b"), point("c")],
+        stops: vec![point("s")],
+        origin: Some(point("o")),
+        speeds: BTreeMap::from([("road".to_owned(), 50), ("highway".to_owned(), 110)]),
+        marker: Marker::Pin { label: "home".to_owned() },
+    }
+}
+
+/// The value at `path`, if it is a `T`.
+fn get<T: Clone + 'static>(route: &Route, path: &str) -> Option<T> {
+    route.get_by_path(path)?.downcast_ref::<T>().cloned()
+}
+
+fn field_paths(route: &Route) -> Vec<String> {
+    route.field_paths().into_iter().map(|field: FieldInfo| field.path).collect()
+}
+
+#[test]
+fn paths_go_through_collections_and_variants() {
+    let paths = field_paths(&route());
+    for path in ["array[2].name", "array[2].coords[1]", "stops[0].name", "origin.name", "speeds[1].value", "marker.label"] {
+        assert!(paths.iter().any(|p| p == path), "{path}: {paths:?}");
+    }
+    // only the fields of the current variant have a path
+    assert!(!paths.iter().any(|p| p == "marker.0"), "{paths:?}");
+    assert!(!field_paths(&Route::default()).iter().any(|p| p.starts_with("origin.")));
+}
+
+#[test]
+fn values_are_read_by_path() {
+    let route = route();
+    assert_eq!(get::<String>(&route, "array[2].name").as_deref(), Some("c"));
+    assert_eq!(get::<f32>(&route, "array[1].coords[1]"), Some(2.));
+    assert_eq!(get::<String>(&route, "stops[0].name").as_deref(), Some("s"));
+    assert_eq!(get::<String>(&route, "origin.name").as_deref(), Some("o"));
+    // the entries of a map are sorted by key
+    assert_eq!(get::<String>(&route, "speeds[0].key").as_deref(), Some("highway"));
+    assert_eq!(get::<u16>(&route, "speeds[1].value"), Some(50));
+    assert_eq!(get::<String>(&route, "marker.label").as_deref(), Some("home"));
+    assert_eq!(get::<Marker>(&route, "marker"), Some(route.marker.clone()));
+}
+
+#[test]
+fn values_are_set_by_path() {
+    let mut route = route();
+    route.set_by_path_from_str("array[2].name", "z").unwrap();
+    route.set_by_path_from_str("array[0].coords[1]", "3.5").unwrap();
+    route.set_by_path_from_str("stops[0].name", "t").unwrap();
+    route.set_by_path_from_str("origin.coords[0]", "-1").unwrap();
+    route.set_by_path_from_str("speeds[1].value", "30").unwrap();
+    route.set_by_path_from_str("marker.label", "work").unwrap();
+    assert_eq!(route.array[2].name, "z");
+    assert_eq!(route.array[0].coords, [1., 3.5]);
+    assert_eq!(route.stops[0].name, "t");
+    assert_eq!(route.origin.as_ref().unwrap().coords, [-1., 2.]);
+    assert_eq!(route.speeds["road"], 30);
+    assert_eq!(route.marker, Marker::Pin { label: "work".to_owned() });
+
+    // a variant is picked by its name, its fields then have a path
+    route.set_by_path_from_str("marker", "Dot").unwrap();
+    route.set_by_path_from_str("marker.0", "7").unwrap();
+    assert_eq!(route.marker, Marker::Dot(7));
+}
+
+#[test]
+fn invalid_paths_and_values_are_errors() {
+    let mut route = route();
+    let old = route.clone();
+    for path in ["array[3].name", "stops[1].name", "speeds[2].value", "array[x].name"] {
+        assert!(route.set_by_path_from_str(path, "1").is_err(), "{path}");
+        assert!(route.get_by_path(path).is_none(), "{path}");
+    }
+    assert_eq!(route.set_by_path_from_str("array[0].color", "red"), Err("No field `color`".to_owned()));
+    assert!(route.get_by_path("array[0].color").is_none());
+    // the fields of another variant aren't found
+    assert!(route.set_by_path_from_str("marker.0", "1").is_err());
+    assert!(route.set_by_path_from_str("marker", "Square").is_err());
+    for (path, value) in [("array[0].coords[0]", "one"), ("speeds[0].value", "-1"), ("speeds[0].value", "70000")] {
+        assert!(route.set_by_path_from_str(path, value).is_err(), "{path} = {value}");
+    }
+    assert_eq!(route, old);
+}
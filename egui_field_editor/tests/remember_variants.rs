@@ -0,0 +1,64 @@
+//! Values of the variants restored when they are picked again with `remember_variants`.
+
+mod common;
+
+use common::Harness;
+use egui::accesskit::Role;
+use egui_field_editor::{EguiInspect, EguiInspector};
+
+#[derive(EguiInspect, Clone, Debug, PartialEq)]
+#[inspect(remember_variants)]
+enum Remembered {
+    Unit,
+    Named { a: u8, b: String },
+}
+
+#[derive(EguiInspect, Clone, Debug, PartialEq)]
+enum Forgotten {
+    Unit,
+    Named { a: u8, b: String },
+}
+
+#[derive(EguiInspect)]
+struct Form {
+    remembered: Remembered,
+    forgotten: Forgotten,
+}
+
+fn harness() -> Harness<Form, impl FnMut(&mut Form, &mut egui::Ui)> {
+    let form = Form {
+        remembered: Remembered::Named { a: 7, b: "kept".to_owned() },
+        forgotten: Forgotten::Named { a: 7, b: "lost".to_owned() },
+    };
+    Harness::new(form, |form, ui| {
+        ui.add(EguiInspector::new(form));
+    })
+}
+
+/// Opens the combo box on the row of `label` and picks `item`.
+fn pick(harness: &mut Harness<Form, impl FnMut(&mut Form, &mut egui::Ui)>, label: &str, item: &str) {
+    let combo = harness.frame.widget_on_row(Role::ComboBox, label).rect;
+    harness.click(combo.center());
+    harness.click(harness.frame.find_last(item));
+}
+
+#[test]
+fn picked_again_variants_get_their_values_back() {
+    let mut harness = harness();
+    pick(&mut harness, "Remembered", "Unit");
+    assert_eq!(harness.state.remembered, Remembered::Unit);
+    pick(&mut harness, "Remembered", "Named");
+    assert_eq!(harness.state.remembered, Remembered::Named { a: 7, b: "kept".to_owned() });
+    assert!(harness.frame.shown("kept"), "{:?}", harness.frame.texts);
+}
+
+#[test]
+fn variants_are_reset_without_the_attribute() {
+    let mut harness = harness();
+    pick(&mut harness, "Forgotten", "Unit");
+    assert_eq!(harness.state.forgotten, Forgotten::Unit);
+    pick(&mut harness, "Forgotten", "Named");
+    assert_eq!(harness.state.forgotten, Forgotten::Named { a: 0, b: String::new() });
+    // the other enum is left alone
+    assert_eq!(harness.state.remembered, Remembered::Named { a: 7, b: "kept".to_owned() });
+}
@@ -0,0 +1,155 @@
+//! Labels of the fields and variants styled by the `rename_all` attribute.
+
+mod common;
+
+use common::Harness;
+use egui_field_editor::{EguiInspect, EguiInspector};
+
+#[derive(EguiInspect, Default)]
+struct Unstyled {
+    max_speed: u8,
+    #[inspect(name = "Named")]
+    min_speed: u8,
+}
+
+#[derive(EguiInspect, Default)]
+#[inspect(rename_all = "Title Case")]
+struct Title {
+    max_speed: u8,
+}
+
+#[derive(EguiInspect, Default)]
+#[inspect(rename_all = "lowercase")]
+struct Lower {
+    max_speed: u8,
+    #[inspect(name = "Named")]
+    min_speed: u8,
+}
+
+#[derive(EguiInspect, Default)]
+#[inspect(rename_all = "SCREAMING_SNAKE")]
+struct Screaming {
+    max_speed: u8,
+}
+
+#[derive(EguiInspect, Default)]
+#[inspect(rename_all = "as-is")]
+struct AsIs {
+    max_speed: u8,
+}
+
+#[derive(EguiInspect, Default)]
+struct Pair(u8, u8);
+
+#[derive(EguiInspect, Default)]
+#[inspect(rename_all = "lowercase")]
+struct LowerPair(u8);
+
+#[derive(EguiInspect, Default)]
+#[inspect(rename_all = "SCREAMING_SNAKE")]
+struct ScreamingPair(u8);
+
+#[derive(EguiInspect, Default)]
+#[inspect(rename_all = "as-is")]
+struct AsIsPair(u8);
+
+fn labels(value: &impl EguiInspect) -> Vec<(String, String)> {
+    value.field_paths().into_iter().map(|field| (field.path, field.label)).collect()
+}
+
+fn pairs(pairs: &[(&str, &str)]) -> Vec<(String, String)> {
+    pairs.iter().map(|(path, label)| (path.to_string(), label.to_string())).collect()
+}
+
+#[test]
+fn field_labels() {
+    assert_eq!(labels(&Unstyled::default()), pairs(&[("max_speed", "Max Speed"), ("min_speed", "Named")]));
+    assert_eq!(labels(&Title::default()), pairs(&[("max_speed", "Max Speed")]));
+    assert_eq!(labels(&Lower::default()), pairs(&[("max_speed", "max speed"), ("min_speed", "Named")]));
+    assert_eq!(labels(&Screaming::default()), pairs(&[("max_speed", "MAX_SPEED")]));
+    assert_eq!(labels(&AsIs::default()), pairs(&[("max_speed", "max_speed")]));
+}
+
+#[derive(EguiInspect, Default)]
+#[allow(non_snake_case)]
+struct Camel {
+    maxSpeed: u8,
+    vec3: u8,
+}
+
+#[derive(EguiInspect, Default)]
+#[inspect(rename_all = "Title Case")]
+#[allow(non_snake_case)]
+struct TitleCamel {
+    maxSpeed: u8,
+    vec3: u8,
+}
+
+#[test]
+fn case_changes_are_only_split_with_rename_all() {
+    assert_eq!(labels(&Camel::default()), pairs(&[("maxSpeed", "MaxSpeed"), ("vec3", "Vec 3")]));
+    assert_eq!(labels(&TitleCamel::default()), pairs(&[("maxSpeed", "Max Speed"), ("vec3", "Vec 3")]));
+}
+
+#[test]
+fn tuple_field_labels() {
+    assert_eq!(labels(&Pair::default()), pairs(&[("0", "Field 0"), ("1", "Field 1")]));
+    assert_eq!(labels(&LowerPair::default()), pairs(&[("0", "field 0")]));
+    assert_eq!(labels(&ScreamingPair::default()), pairs(&[("0", "FIELD_0")]));
+    assert_eq!(labels(&AsIsPair::default()), pairs(&[("0", "0")]));
+}
+
+#[derive(EguiInspect, Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+enum Gear {
+    LowRange,
+    HighRange,
+}
+
+#[derive(EguiInspect, Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[inspect(rename_all = "Title Case")]
+enum TitleGear {
+    LowRange,
+    #[inspect(name = "Top")]
+    HighRange,
+}
+
+#[derive(EguiInspect, Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[inspect(rename_all = "lowercase")]
+enum LowerGear {
+    LowRange,
+}
+
+#[derive(EguiInspect, Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[inspect(rename_all = "SCREAMING_SNAKE")]
+enum ScreamingGear {
+    LowRange,
+}
+
+#[test]
+fn variant_labels() {
+    // kept as is by default
+    assert_eq!(Gear::all_variants(), [(Gear::LowRange, "LowRange"), (Gear::HighRange, "HighRange")]);
+    assert_eq!(TitleGear::all_variants(), [(TitleGear::LowRange, "Low Range"), (TitleGear::HighRange, "Top")]);
+    assert_eq!(LowerGear::all_variants(), [(LowerGear::LowRange, "low range")]);
+    assert_eq!(ScreamingGear::all_variants(), [(ScreamingGear::LowRange, "LOW_RANGE")]);
+}
+
+#[derive(EguiInspect)]
+#[inspect(rename_all = "lowercase", style = "radio")]
+enum Drive {
+    FrontWheels(u8, bool),
+    AllWheels { torque_split: u8 },
+}
+
+#[test]
+fn variant_field_labels() {
+    let mut harness = Harness::new(Drive::FrontWheels(0, false), |drive, ui| {
+        ui.add(EguiInspector::new(drive));
+    });
+    let frame = &harness.frame;
+    for label in ["front wheels", "all wheels", "field 0", "field 1"] {
+        assert!(frame.shown(label), "{label}: {:?}", frame.texts);
+    }
+    harness.click_text("all wheels");
+    assert!(harness.frame.shown("torque split"), "{:?}", harness.frame.texts);
+}
@@ -0,0 +1,114 @@
+//! The responses of the inspector: edits of nested fields reported by the `Response` of
+//! `ui.add` and by the `InspectResponse` of `EguiInspector::show`.
+
+mod common;
+
+use common::Harness;
+use egui::accesskit::Role;
+use egui_field_editor::{EguiInspect, EguiInspector, InspectResponse};
+
+#[derive(EguiInspect, Clone, Debug, Default, PartialEq)]
+struct Layer {
+    name: String,
+    opacity: f32,
+}
+
+#[derive(EguiInspect, Clone, Debug, Default, PartialEq)]
+struct Scene {
+    layers: Vec<Layer>,
+}
+
+fn scene() -> Scene {
+    Scene {
+        layers: vec![Layer {
+            name: "sky".to_owned(),
+            opacity: 1.,
+        }],
+    }
+}
+
+/// What a frame reported.
+#[derive(Clone, Copy, Debug, Default)]
+struct Reported {
+    changed: bool,
+    drag_started: bool,
+    drag_stopped: bool,
+    lost_focus: bool,
+}
+
+impl From<InspectResponse> for Reported {
+    fn from(response: InspectResponse) -> Self {
+        Self {
+            changed: response.changed(),
+            drag_started: response.drag_started(),
+            drag_stopped: response.drag_stopped(),
+            lost_focus: response.lost_focus(),
+        }
+    }
+}
+
+/// The scene, and what each frame reported.
+type State = (Scene, Vec<Reported>);
+
+/// Opens the first layer.
+fn open(harness: &mut Harness<State, impl FnMut(&mut State, &mut egui::Ui)>) {
+    harness.click_text("Layers[1]");
+    harness.click_text("Item 0");
+    harness.state.1.clear();
+}
+
+/// Drags the opacity field of the first layer to the right.
+fn drag_opacity(harness: &mut Harness<State, impl FnMut(&mut State, &mut egui::Ui)>) {
+    let opacity = harness.frame.widget_on_row(Role::SpinButton, "Opacity").rect.center();
+    harness.drag(opacity, opacity + egui::vec2(40., 0.), 8);
+}
+
+#[test]
+fn nested_edits_are_reported_by_show() {
+    let mut harness = Harness::new((scene(), Vec::new()), |(scene, reported), ui| {
+        reported.push(EguiInspector::new(scene).show(ui).into());
+    });
+    open(&mut harness);
+    drag_opacity(&mut harness);
+    assert!(harness.state.0.layers[0].opacity > 1.);
+    let reported = &harness.state.1;
+    assert_eq!(reported.iter().filter(|frame| frame.drag_started).count(), 1, "{reported:?}");
+    assert_eq!(reported.iter().filter(|frame| frame.drag_stopped).count(), 1, "{reported:?}");
+    assert!(reported.iter().any(|frame| frame.changed), "{reported:?}");
+    let started = reported.iter().position(|frame| frame.drag_started).unwrap();
+    let stopped = reported.iter().position(|frame| frame.drag_stopped).unwrap();
+    assert!(started < stopped);
+    assert!(reported.iter().all(|frame| !frame.lost_focus), "{reported:?}");
+
+    harness.state.1.clear();
+    let name = harness.frame.widget_on_row(Role::TextInput, "Name").rect;
+    harness.click(name.center());
+    harness.type_text("s");
+    assert!(harness.state.1.iter().any(|frame| frame.changed));
+    assert!(!harness.state.1.iter().any(|frame| frame.lost_focus));
+    harness.click(harness.frame.find("Layers[1]") + egui::vec2(300., 0.));
+    assert_eq!(harness.state.1.iter().filter(|frame| frame.lost_focus).count(), 1, "{:?}", harness.state.1);
+}
+
+#[test]
+fn nested_edits_change_the_widget_response() {
+    let mut harness = Harness::new((scene(), Vec::new()), |(scene, reported), ui| {
+        ui.label("Above");
+        let response = ui.add(EguiInspector::new(scene));
+        // the response covers the inspector only
+        assert!(response.rect.top() > ui.min_rect().top() && response.rect.height() < 1000.);
+        reported.push(Reported {
+            changed: response.changed(),
+            ..Default::default()
+        });
+    });
+    open(&mut harness);
+    assert!(harness.state.1.iter().all(|frame| !frame.changed));
+    drag_opacity(&mut harness);
+    assert!(harness.state.0.layers[0].opacity > 1.);
+    assert!(harness.state.1.iter().any(|frame| frame.changed));
+    // no frame changes without an edit
+    let edits = harness.state.1.iter().filter(|frame| frame.changed).count();
+    harness.settle();
+    assert_eq!(harness.state.1.iter().filter(|frame| frame.changed).count(), edits);
+}
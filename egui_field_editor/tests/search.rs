@@ -0,0 +1,170 @@
+//! Search of the inspected fields: filtering, opening of the headers and highlighting.
+
+use egui_field_editor::{EguiInspect, EguiInspector};
+
+#[derive(EguiInspect, Clone, Default)]
+struct Address {
+    street: String,
+    city: String,
+}
+
+#[derive(EguiInspect, Clone, Default)]
+struct Contact {
+    name: String,
+    phone: String,
+    address: Address,
+}
+
+/// A text shown by a frame, with its center and its highlighted parts.
+struct Text {
+    text: String,
+    pos: egui::Pos2,
+    highlighted: String,
+}
+
+/// Texts shown by a frame.
+fn run_frame(ctx: &egui::Context, contact: &mut Contact, events: Vec<egui::Event>) -> Vec<Text> {
+    let input = egui::RawInput {
+        events,
+        ..Default::default()
+    };
+    let mut output = ctx.run(input, |ctx| {
+        egui::CentralPanel::default().show(ctx, |ui| {
+            ui.add(EguiInspector::new(contact).with_search());
+        });
+    });
+    output.textures_delta.clear();
+    output
+        .shapes
+        .iter()
+        .filter_map(|shape| match &shape.shape {
+            egui::epaint::Shape::Text(text) => {
+                let job = &text.galley.job;
+                let highlighted = job
+                    .sections
+                    .iter()
+                    .filter(|section| section.format.background != egui::Color32::TRANSPARENT)
+                    .map(|section| &job.text[section.byte_range.clone()])
+                    .collect();
+                Some(Text {
+                    text: text.galley.text().to_owned(),
+                    pos: text.pos + text.galley.rect.center().to_vec2(),
+                    highlighted,
+                })
+            }
+            _ => None,
+        })
+        .collect()
+}
+
+/// Runs frames until the headers are open or closed.
+fn settle(ctx: &egui::Context, contact: &mut Contact) -> Vec<Text> {
+    for _ in 0..20 {
+        run_frame(ctx, contact, vec![]);
+    }
+    run_frame(ctx, contact, vec![])
+}
+
+/// Replaces the query of the search field by `query`.
+fn search(ctx: &egui::Context, contact: &mut Contact, query: &str) -> Vec<Text> {
+    let texts = run_frame(ctx, contact, vec![]);
+    // the search field is on the right of its icon
+    let pos = texts.iter().find(|text| text.text == "🔍").expect("no search field").pos + egui::vec2(100., 0.);
+    let button = |pressed| egui::Event::PointerButton {
+        pos,
+        button: egui::PointerButton::Primary,
+        pressed,
+        modifiers: Default::default(),
+    };
+    let select_all = |pressed| egui::Event::Key {
+        key: egui::Key::A,
+        physical_key: None,
+        pressed,
+        repeat: false,
+        modifiers: egui::Modifiers::COMMAND,
+    };
+    run_frame(ctx, contact, vec![egui::Event::PointerMoved(pos), button(true)]);
+    run_frame(ctx, contact, vec![button(false)]);
+    run_frame(ctx, contact, vec![select_all(true), select_all(false)]);
+    let event = if query.is_empty() {
+        egui::Event::Key {
+            key: egui::Key::Backspace,
+            physical_key: None,
+            pressed: true,
+            repeat: false,
+            modifiers: Default::default(),
+        }
+    } else {
+        egui::Event::Text(query.to_owned())
+    };
+    run_frame(ctx, contact, vec![event]);
+    settle(ctx, contact)
+}
+
+fn shown(texts: &[Text], text: &str) -> bool {
+    texts.iter().any(|t| t.text == text)
+}
+
+fn labels(texts: &[Text]) -> Vec<&str> {
+    texts.iter().map(|text| text.text.as_str()).collect()
+}
+
+fn contact() -> Contact {
+    Contact {
+        name: "Ada".to_owned(),
+        phone: "555".to_owned(),
+        address: Address {
+            street: "Main street".to_owned(),
+            city: "Cincinnati".to_owned(),
+        },
+    }
+}
+
+#[test]
+fn only_matching_fields_are_shown() {
+    let ctx = egui::Context::default();
+    let mut contact = contact();
+    let texts = settle(&ctx, &mut contact);
+    // the header of the address is collapsed
+    assert!(shown(&texts, "Name") && shown(&texts, "Address") && !shown(&texts, "City"), "{:?}", labels(&texts));
+
+    // the matching field is shown with its parents, its header being opened
+    let texts = search(&ctx, &mut contact, "city");
+    assert!(shown(&texts, "Address") && shown(&texts, "City") && shown(&texts, "Cincinnati"), "{:?}", labels(&texts));
+    for hidden in ["Name", "Phone", "Street"] {
+        assert!(!shown(&texts, hidden), "{hidden}: {:?}", labels(&texts));
+    }
+
+    // the children of a matching header are shown
+    let texts = search(&ctx, &mut contact, "address");
+    assert!(shown(&texts, "Street") && shown(&texts, "City"), "{:?}", labels(&texts));
+    assert!(!shown(&texts, "Name"), "{:?}", labels(&texts));
+
+    let texts = search(&ctx, &mut contact, "zip");
+    assert!(shown(&texts, "No matching field"), "{:?}", labels(&texts));
+    assert!(!shown(&texts, "Address"), "{:?}", labels(&texts));
+}
+
+#[test]
+fn clearing_the_search_restores_the_fields() {
+    let ctx = egui::Context::default();
+    let mut contact = contact();
+    search(&ctx, &mut contact, "city");
+    let texts = search(&ctx, &mut contact, "");
+    assert!(shown(&texts, "Name") && shown(&texts, "Phone"), "{:?}", labels(&texts));
+    // the header opened by the search is closed again
+    assert!(!shown(&texts, "City"), "{:?}", labels(&texts));
+}
+
+#[test]
+fn matches_are_highlighted() {
+    let ctx = egui::Context::default();
+    let mut contact = contact();
+    let texts = search(&ctx, &mut contact, "ci");
+    let city = texts.iter().find(|text| text.text == "City").expect("no City label");
+    assert_eq!(city.highlighted, "Ci");
+    let address = texts.iter().find(|text| text.text == "Address").expect("no Address label");
+    assert_eq!(address.highlighted, "");
+    // the values aren't searched
+    assert!(texts.iter().all(|text| text.text != "Cincinnati" || text.highlighted.is_empty()));
+}
@@ -0,0 +1,97 @@
+//! Pagination of the matching items of long lists while searching.
+
+use egui_field_editor::{EguiInspect, EguiInspector};
+
+#[derive(EguiInspect, Clone, Default)]
+struct Entry {
+    name: String,
+    size: u32,
+}
+
+#[derive(EguiInspect, Default)]
+struct Catalog {
+    title: String,
+    #[inspect(key = "name")]
+    entries: Vec<Entry>,
+}
+
+/// Texts shown by a frame, with their center.
+fn run_frame(ctx: &egui::Context, catalog: &mut Catalog, events: Vec<egui::Event>) -> Vec<(String, egui::Pos2)> {
+    let input = egui::RawInput {
+        events,
+        screen_rect: Some(egui::Rect::from_min_size(egui::Pos2::ZERO, egui::vec2(600., 20000.))),
+        ..Default::default()
+    };
+    let mut output = ctx.run(input, |ctx| {
+        egui::CentralPanel::default().show(ctx, |ui| {
+            ui.add(EguiInspector::new(catalog).with_search().with_page_size(10));
+        });
+    });
+    output.textures_delta.clear();
+    output
+        .shapes
+        .iter()
+        .filter_map(|shape| match &shape.shape {
+            egui::epaint::Shape::Text(text) => Some((text.galley.text().to_owned(), text.pos + text.galley.rect.center().to_vec2())),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Types `query` in the search field, then waits for the headers to open.
+fn search(ctx: &egui::Context, catalog: &mut Catalog, query: &str) -> Vec<(String, egui::Pos2)> {
+    let texts = run_frame(ctx, catalog, vec![]);
+    let pos = texts
+        .iter()
+        .find(|(text, _)| text == "Search fields")
+        .expect("no search field")
+        .1;
+    let button = |pressed| egui::Event::PointerButton {
+        pos,
+        button: egui::PointerButton::Primary,
+        pressed,
+        modifiers: Default::default(),
+    };
+    run_frame(ctx, catalog, vec![egui::Event::PointerMoved(pos), button(true)]);
+    run_frame(ctx, catalog, vec![button(false)]);
+    run_frame(ctx, catalog, vec![egui::Event::Text(query.to_owned())]);
+    for _ in 0..20 {
+        run_frame(ctx, catalog, vec![]);
+    }
+    run_frame(ctx, catalog, vec![])
+}
+
+fn count(texts: &[(String, egui::Pos2)], text: &str) -> usize {
+    texts.iter().filter(|(t, _)| t == text).count()
+}
+
+fn catalog() -> Catalog {
+    Catalog {
+        title: "Parts".to_owned(),
+        entries: (0..250)
+            .map(|index| Entry {
+                name: format!("part {index}"),
+                size: index,
+            })
+            .collect(),
+    }
+}
+
+#[test]
+fn matching_items_are_paginated() {
+    let ctx = egui::Context::default();
+    let mut catalog = catalog();
+    let texts = search(&ctx, &mut catalog, "size");
+    // every entry matches, a page of them is shown
+    assert_eq!(count(&texts, "Size"), 10, "{texts:?}");
+    assert!(texts.iter().any(|(text, _)| text == "0..10 of 250"), "{texts:?}");
+    assert_eq!(count(&texts, "Title"), 0, "{texts:?}");
+
+    // the matching entries far apart are on the same page
+    let ctx = egui::Context::default();
+    catalog.entries[120].name = "Special part".to_owned();
+    catalog.entries[240].name = "Special part".to_owned();
+    let texts = search(&ctx, &mut catalog, "special");
+    assert_eq!(count(&texts, "Name"), 2, "{texts:?}");
+    assert!(!texts.iter().any(|(text, _)| text.contains(" of ")), "{texts:?}");
+}
@@ -0,0 +1,97 @@
+//! Editing the items of the sets, and the checkboxes of the sets of unit enums.
+
+mod common;
+
+use std::collections::BTreeSet;
+
+use common::Harness;
+use egui::accesskit::Role;
+use egui::{Key, Modifiers};
+use egui_field_editor::{EguiInspect, EguiInspector};
+
+#[derive(EguiInspect, Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+enum Weekday {
+    #[default]
+    Monday,
+    #[inspect(name = "Tue.")]
+    Tuesday,
+    #[inspect(hidden)]
+    Wednesday,
+}
+
+#[derive(EguiInspect, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+enum Shape {
+    #[default]
+    Dot,
+    Circle(u8),
+}
+
+#[derive(EguiInspect, Default)]
+struct Calendar {
+    tags: BTreeSet<String>,
+    days: BTreeSet<Weekday>,
+    shapes: BTreeSet<Shape>,
+}
+
+fn calendar() -> Calendar {
+    Calendar {
+        tags: BTreeSet::from(["home".to_owned(), "work".to_owned()]),
+        days: BTreeSet::from([Weekday::Monday, Weekday::Wednesday]),
+        shapes: BTreeSet::from([Shape::Dot, Shape::Circle(2)]),
+    }
+}
+
+fn harness() -> Harness<Calendar, impl FnMut(&mut Calendar, &mut egui::Ui)> {
+    Harness::new(calendar(), |calendar, ui| {
+        ui.add(EguiInspector::new(calendar));
+    })
+}
+
+#[test]
+fn adding_an_existing_item_is_rejected() {
+    let mut harness = harness();
+    harness.click_text("Tags[2]");
+    let home = harness.frame.widget_on_row(Role::TextInput, "Item 0").rect;
+    harness.click(home.center());
+    harness.key(Key::A, Modifiers::COMMAND);
+    harness.type_text("work");
+    assert!(harness.frame.shown("❌ The set already contains this value"), "{:?}", harness.frame.texts);
+
+    harness.click(harness.frame.find("Tags[2]") + egui::vec2(200., 0.));
+    assert_eq!(harness.state.tags, calendar().tags);
+    assert!(!harness.frame.shown("❌ The set already contains this value"), "{:?}", harness.frame.texts);
+
+    // the default value is added once
+    harness.click(harness.frame.button("+").rect.center());
+    assert!(harness.state.tags.contains(""));
+    assert!(harness.frame.button("+").disabled);
+
+    let mut calendar = calendar();
+    assert_eq!(calendar.set_by_path_from_str("tags[0]", "work"), Err("The set already contains this value".to_owned()));
+    assert_eq!(calendar.tags, self::calendar().tags);
+}
+
+#[test]
+fn unit_enums_list_their_variants() {
+    assert_eq!(Weekday::all_variants(), [(Weekday::Monday, "Monday"), (Weekday::Tuesday, "Tue.")]);
+
+    let mut harness = harness();
+    let checkboxes: Vec<_> = harness.frame.widgets(Role::CheckBox).into_iter().map(|checkbox| (checkbox.label.clone(), checkbox.checked)).collect();
+    assert_eq!(checkboxes, [("Monday".to_owned(), true), ("Tue.".to_owned(), false)]);
+    // the hidden variants are kept
+    harness.click_text("Tue.");
+    assert_eq!(harness.state.days, BTreeSet::from([Weekday::Monday, Weekday::Tuesday, Weekday::Wednesday]));
+    harness.click_text("Monday");
+    assert_eq!(harness.state.days, BTreeSet::from([Weekday::Tuesday, Weekday::Wednesday]));
+}
+
+#[test]
+fn data_enums_have_no_variant_list() {
+    assert_eq!(Shape::all_variants(), []);
+
+    // their sets show the items instead of checkboxes
+    let mut harness = harness();
+    assert!(harness.frame.shown("Shapes[2]"), "{:?}", harness.frame.texts);
+    harness.click_text("Shapes[2]");
+    assert!(harness.frame.shown("Item 1"), "{:?}", harness.frame.texts);
+}
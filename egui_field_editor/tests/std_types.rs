@@ -0,0 +1,178 @@
+//! Tuples, ranges, `Result`, `PhantomData` and `()` inside a derived struct.
+
+use std::marker::PhantomData;
+use std::ops::{Range, RangeInclusive};
+
+use egui_field_editor::{field_changes, EguiInspect, EguiInspector, FieldChange};
+
+#[derive(EguiInspect, Clone, Debug, Default, PartialEq)]
+struct Point {
+    x: u8,
+    y: u8,
+}
+
+type Twelve = (u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8);
+
+#[derive(EguiInspect, Clone, Debug, PartialEq)]
+struct Job {
+    result: Result<Point, String>,
+    #[inspect(range(min = 1., max = 64.))]
+    threads: RangeInclusive<u8>,
+    window: Range<f32>,
+    twelve: Twelve,
+    // not editable, but shouldn't prevent the derive
+    marker: PhantomData<*const u8>,
+    unit: (),
+}
+
+impl Default for Job {
+    fn default() -> Self {
+        Self {
+            result: Ok(Point::default()),
+            threads: 1..=8,
+            window: 0.0..1.0,
+            twelve: Default::default(),
+            marker: PhantomData,
+            unit: (),
+        }
+    }
+}
+
+/// Texts shown by a frame, with their center.
+fn run_frame(ctx: &egui::Context, job: &mut Job, events: Vec<egui::Event>) -> Vec<(String, egui::Pos2)> {
+    let input = egui::RawInput {
+        events,
+        ..Default::default()
+    };
+    let mut output = ctx.run(input, |ctx| {
+        egui::CentralPanel::default().show(ctx, |ui| {
+            ui.add(EguiInspector::new(job));
+        });
+    });
+    output.textures_delta.clear();
+    output
+        .shapes
+        .iter()
+        .filter_map(|shape| match &shape.shape {
+            egui::epaint::Shape::Text(text) => Some((text.galley.text().to_owned(), text.pos + text.galley.rect.center().to_vec2())),
+            _ => None,
+        })
+        .collect()
+}
+
+fn click(ctx: &egui::Context, job: &mut Job, pos: egui::Pos2) -> Vec<(String, egui::Pos2)> {
+    let button = |pressed| egui::Event::PointerButton {
+        pos,
+        button: egui::PointerButton::Primary,
+        pressed,
+        modifiers: Default::default(),
+    };
+    run_frame(ctx, job, vec![egui::Event::PointerMoved(pos), button(true)]);
+    run_frame(ctx, job, vec![button(false)]);
+    run_frame(ctx, job, vec![])
+}
+
+fn find(texts: &[(String, egui::Pos2)], text: &str) -> egui::Pos2 {
+    texts
+        .iter()
+        .find(|(t, _)| t == text)
+        .unwrap_or_else(|| panic!("`{text}` not shown: {texts:?}"))
+        .1
+}
+
+#[test]
+fn result_variant_switch() {
+    let ctx = egui::Context::default();
+    let mut job = Job {
+        result: Ok(Point { x: 1, y: 2 }),
+        ..Default::default()
+    };
+    let texts = run_frame(&ctx, &mut job, vec![]);
+    let texts = click(&ctx, &mut job, find(&texts, "Ok"));
+    // the items of the combo box
+    let err = texts.iter().rfind(|(t, _)| t == "Err").expect("no Err item").1;
+    click(&ctx, &mut job, err);
+    assert_eq!(job.result, Err(String::new()));
+
+    let texts = run_frame(&ctx, &mut job, vec![]);
+    let texts = click(&ctx, &mut job, find(&texts, "Err"));
+    let ok = texts.iter().rfind(|(t, _)| t == "Ok").expect("no Ok item").1;
+    click(&ctx, &mut job, ok);
+    assert_eq!(job.result, Ok(Point::default()));
+}
+
+#[test]
+fn result_changes_and_copy() {
+    let old = Job::default();
+    let mut new = old.clone();
+    new.result = Ok(Point { x: 3, y: 0 });
+    assert_eq!(field_changes(&old, &new), [FieldChange::set("result.x", 0, 3)]);
+
+    new.result = Err("timeout".to_owned());
+    assert_eq!(
+        field_changes(&old, &new),
+        [FieldChange::set("result", "Ok", "Err"), FieldChange::set("result", "", "timeout")]
+    );
+    let mut copy = old.clone();
+    copy.copy_field_from(&new, "result").unwrap();
+    assert_eq!(copy.result, new.result);
+
+    copy.result = Ok(Point { x: 5, y: 6 });
+    let mut other = old.clone();
+    other.copy_field_from(&copy, "result.y").unwrap();
+    assert_eq!(other.result, Ok(Point { x: 0, y: 6 }));
+}
+
+#[test]
+fn range_inclusive() {
+    let old = Job::default();
+    let mut job = old.clone();
+    job.set_by_path_from_str("threads.end", "16").unwrap();
+    assert_eq!(job.threads, 1..=16);
+    assert_eq!(job.get_by_path("threads").and_then(|threads| threads.downcast_ref::<RangeInclusive<u8>>()), Some(&(1..=16)));
+    assert!(job.set_by_path_from_str("threads.middle", "2").is_err());
+    assert_eq!(field_changes(&old, &job), [FieldChange::set("threads.end", 8, 16)]);
+
+    let mut copy = old.clone();
+    copy.copy_field_from(&job, "threads").unwrap();
+    assert_eq!(copy.threads, 1..=16);
+}
+
+#[test]
+fn unit_and_phantom_data() {
+    let job = Job::default();
+    let paths: Vec<_> = job.field_paths().into_iter().map(|field| field.path).collect();
+    assert!(paths.contains(&"marker".to_owned()), "{paths:?}");
+    assert!(paths.contains(&"unit".to_owned()), "{paths:?}");
+    assert!(field_changes(&job, &job.clone()).is_empty());
+    assert!(egui_field_editor::diff_paths(&job, &job.clone()).is_empty());
+
+    let ctx = egui::Context::default();
+    let mut job = job;
+    // nothing to edit: the fields are not shown
+    let texts = run_frame(&ctx, &mut job, vec![]);
+    find(&texts, "Twelve");
+    assert!(!texts.iter().any(|(text, _)| text == "Marker" || text == "Unit"), "{texts:?}");
+}
+
+#[test]
+fn twelve_elements_tuple() {
+    let old = Job::default();
+    let mut job = old.clone();
+    job.set_by_path_from_str("twelve.11", "12").unwrap();
+    job.set_by_path_from_str("twelve.0", "1").unwrap();
+    assert!(job.set_by_path_from_str("twelve.12", "1").is_err());
+    assert_eq!(job.twelve.11, 12);
+    assert_eq!(job.get_by_path("twelve.11").and_then(|value| value.downcast_ref::<u8>()), Some(&12));
+    assert_eq!(
+        field_changes(&old, &job),
+        [FieldChange::set("twelve.0", 0, 1), FieldChange::set("twelve.11", 0, 12)]
+    );
+
+    let mut copy = old.clone();
+    copy.copy_field_from(&job, "twelve.11").unwrap();
+    assert_eq!(copy.twelve, (0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 12));
+
+    let paths: Vec<_> = job.field_paths().into_iter().map(|field| field.path).collect();
+    assert!(paths.contains(&"twelve.11".to_owned()), "{paths:?}");
+}
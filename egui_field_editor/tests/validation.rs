@@ -0,0 +1,128 @@
+//! Field and struct validators, and their errors shown by the inspector.
+
+mod common;
+
+use common::Harness;
+use egui::accesskit::Role;
+use egui_field_editor::{validation_errors, EguiInspect, EguiInspector, ValidationError};
+
+// validators take a reference to the field
+#[allow(clippy::ptr_arg)]
+fn not_empty(name: &String) -> Result<(), String> {
+    if name.is_empty() { Err("Name is required".to_owned()) } else { Ok(()) }
+}
+
+fn not_privileged(port: &u16) -> Result<(), String> {
+    if *port < 1024 { Err(format!("Port {port} is privileged")) } else { Ok(()) }
+}
+
+fn min_below_max(window: &Window) -> Result<(), String> {
+    if window.min > window.max { Err("Min is above max".to_owned()) } else { Ok(()) }
+}
+
+fn has_hosts(network: &Network) -> Result<(), String> {
+    if network.hosts.is_empty() { Err("No host".to_owned()) } else { Ok(()) }
+}
+
+fn few_hosts(network: &Network) -> Result<(), String> {
+    if network.hosts.len() > 2 { Err("Too many hosts".to_owned()) } else { Ok(()) }
+}
+
+#[derive(EguiInspect, Clone, Default)]
+struct Host {
+    #[inspect(validate = "not_privileged")]
+    port: u16,
+}
+
+#[derive(EguiInspect, Clone, Default)]
+#[inspect(validate_struct = "min_below_max")]
+struct Window {
+    min: u32,
+    max: u32,
+}
+
+#[derive(EguiInspect, Clone, Default)]
+enum Transport {
+    #[default]
+    Local,
+    Tcp(#[inspect(validate = "not_privileged")] u16),
+}
+
+#[derive(EguiInspect, Clone, Default)]
+#[inspect(validate_struct = "has_hosts", validate_struct = "few_hosts")]
+struct Network {
+    #[inspect(validate = "not_empty")]
+    name: String,
+    hosts: Vec<Host>,
+    window: Window,
+    transport: Transport,
+}
+
+fn error(path: &str, message: &str) -> ValidationError {
+    ValidationError {
+        path: path.to_owned(),
+        message: message.to_owned(),
+    }
+}
+
+fn valid_network() -> Network {
+    Network {
+        name: "lan".to_owned(),
+        hosts: vec![Host { port: 8080 }],
+        window: Window { min: 1, max: 2 },
+        transport: Transport::Tcp(8000),
+    }
+}
+
+#[test]
+fn errors_are_reported_with_their_path() {
+    assert_eq!(validation_errors(&valid_network()), []);
+
+    let mut network = valid_network();
+    network.name.clear();
+    network.hosts = vec![Host { port: 8080 }, Host { port: 80 }, Host { port: 22 }];
+    network.window.min = 3;
+    network.transport = Transport::Tcp(21);
+    assert_eq!(
+        validation_errors(&network),
+        [
+            error("name", "Name is required"),
+            error("hosts[1].port", "Port 80 is privileged"),
+            error("hosts[2].port", "Port 22 is privileged"),
+            error("window", "Min is above max"),
+            error("transport.0", "Port 21 is privileged"),
+            error("", "Too many hosts"),
+        ]
+    );
+
+    network = valid_network();
+    network.hosts.clear();
+    assert_eq!(validation_errors(&network), [error("", "No host")]);
+}
+
+#[test]
+fn inspector_is_valid() {
+    let mut network = valid_network();
+    assert!(EguiInspector::new(&mut network).is_valid());
+    network.name.clear();
+    let inspector = EguiInspector::new(&mut network);
+    assert!(!inspector.is_valid());
+    assert_eq!(inspector.validation_errors(), [error("name", "Name is required")]);
+}
+
+#[test]
+fn errors_are_shown_until_fixed() {
+    let mut network = valid_network();
+    network.name.clear();
+    let mut harness = Harness::new(network, |network, ui| {
+        ui.add(EguiInspector::new(network));
+    });
+    assert!(harness.frame.shown("❌ Name is required"), "{:?}", harness.frame.texts);
+
+    let name = harness.frame.widget_on_row(Role::TextInput, "Name").rect.center();
+    harness.click(name);
+    harness.type_text("wan");
+    assert_eq!(harness.state.name, "wan");
+    assert!(EguiInspector::new(&mut harness.state).is_valid());
+    assert!(!harness.frame.shown("❌ Name is required"), "{:?}", harness.frame.texts);
+}
@@ -0,0 +1,111 @@
+//! Read-only display of objects borrowed immutably with `EguiInspector::view`.
+
+mod common;
+
+use std::sync::{Arc, RwLock};
+
+use common::Harness;
+use egui::accesskit::Role;
+use egui_field_editor::{EguiInspect, EguiInspector};
+
+#[derive(EguiInspect, Clone, Debug, PartialEq)]
+enum Shape {
+    Circle { radius: u8 },
+    Square(u8),
+}
+
+impl Default for Shape {
+    fn default() -> Self {
+        Self::Square(1)
+    }
+}
+
+#[derive(EguiInspect, Clone, Debug, Default, PartialEq)]
+struct Tile {
+    name: String,
+    visible: bool,
+    shape: Shape,
+}
+
+#[derive(EguiInspect, Debug)]
+struct Board {
+    title: String,
+    tiles: Vec<Tile>,
+    selected: Option<Tile>,
+    score: Arc<RwLock<u32>>,
+}
+
+fn board() -> Board {
+    let tile = |name: &str, shape| Tile {
+        name: name.to_owned(),
+        visible: true,
+        shape,
+    };
+    Board {
+        title: "Level 1".to_owned(),
+        tiles: vec![tile("start", Shape::Circle { radius: 3 }), tile("goal", Shape::Square(4))],
+        selected: Some(tile("hint", Shape::Square(7))),
+        score: Arc::new(RwLock::new(42)),
+    }
+}
+
+/// Widgets editing a value.
+const EDITORS: [Role; 5] = [Role::TextInput, Role::SpinButton, Role::CheckBox, Role::ComboBox, Role::Slider];
+
+#[test]
+fn labels_and_values_are_shown_disabled() {
+    let mut harness = Harness::new(board(), |board, ui| {
+        ui.add(EguiInspector::view(board));
+    });
+    harness.click_text("Tiles[2]");
+    harness.click_text("Item 0");
+    harness.click_text("Item 1");
+    let frame = &harness.frame;
+    for text in ["Title", "Level 1", "Selected", "Score", "42", "Name", "start", "goal", "hint", "Radius", "3", "4", "7"] {
+        assert!(frame.shown(text), "{text}: {:?}", frame.texts);
+    }
+    // the variants are shown
+    assert!(frame.shown("Circle") && frame.shown("Square"), "{:?}", frame.texts);
+    let editors: Vec<_> = frame.widgets.iter().filter(|widget| EDITORS.contains(&widget.role)).collect();
+    assert!(editors.len() >= 10, "{:?}", frame.widgets);
+    assert!(editors.iter().all(|widget| widget.disabled), "{editors:?}");
+    // no list action is offered
+    assert!(!frame.shown("☰") && !frame.shown("+"), "{:?}", frame.texts);
+
+    // the read lock of the score is released
+    assert!(harness.state.score.try_write().is_ok());
+}
+
+#[test]
+fn nothing_can_be_edited() {
+    let mut harness = Harness::new(board(), |board, ui| {
+        ui.add(EguiInspector::view(board));
+    });
+    let title = harness.frame.widget_on_row(Role::TextInput, "Title").rect;
+    harness.click(title.center());
+    harness.type_text("2");
+    let visible = harness.frame.widget_on_row(Role::CheckBox, "Visible").rect;
+    harness.click(visible.center());
+    assert_eq!(harness.state.title, "Level 1");
+    assert_eq!(harness.state.selected, board().selected);
+}
+
+/// A manual implementation without `EguiDisplay`.
+struct Counter(u32);
+
+impl EguiInspect for Counter {
+    fn inspect_with_custom_id(&mut self, _parent_id: egui::Id, label: &str, tooltip: &str, read_only: bool, ui: &mut egui::Ui) -> egui_field_editor::InspectResponse {
+        egui_field_editor::add_number(&mut self.0, label, tooltip, read_only, None, ui)
+    }
+}
+
+#[test]
+fn locks_of_types_without_display_are_inspected() {
+    let counter = Arc::new(RwLock::new(Counter(5)));
+    let harness = Harness::new(counter, |counter, ui| {
+        ui.add(EguiInspector::new(counter).read_only());
+    });
+    let value = harness.frame.widgets(Role::SpinButton)[0];
+    assert_eq!(value.value, Some(5.));
+    assert!(value.disabled);
+}
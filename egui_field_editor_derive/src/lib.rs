@@ -1,8 +1,8 @@
 #![forbid(unsafe_code)] 
 use proc_macro2::{Ident, TokenStream};
-use quote::{quote, quote_spanned};
+use quote::{quote, quote_spanned, ToTokens};
 use syn::{
-	parse_macro_input, parse_quote, spanned::Spanned, Data, DataEnum, DataStruct, DeriveInput, Fields, FieldsNamed, FieldsUnnamed, GenericParam, Generics, Index, LitStr, Meta
+	parse_macro_input, parse_quote, spanned::Spanned, Data, DataEnum, DataStruct, DeriveInput, Fields, FieldsNamed, FieldsUnnamed, GenericParam, Generics, Index, LitStr, Meta, TypeParamBound, Variant
 };
 
 use darling::{FromDeriveInput, FromField, FromMeta, FromVariant};
@@ -76,6 +76,22 @@ impl FromMeta for Multiline {
 		}
 	}
 }
+/// Key of the items of a list: `InspectKey` for `key`, a field of the items or a function for
+/// `key = "..."`
+#[derive(Debug)]
+enum ItemKey {
+	Trait,
+	Value(String),
+}
+impl FromMeta for ItemKey {
+	fn from_meta(item: &Meta) -> darling::Result<Self> {
+		match item {
+			Meta::Path(_) => Ok(ItemKey::Trait),
+			Meta::NameValue(nv) => Ok(ItemKey::Value(String::from_expr(&nv.value)?)),
+			Meta::List(_) => Err(darling::Error::unsupported_format("list")),
+		}
+	}
+}
 #[derive(Debug, FromMeta)]
 struct ExecuteBtn {
 	fn_name: LitStr,
@@ -91,7 +107,22 @@ fn bool_true() -> bool {
 #[darling(attributes(inspect), default)]
 struct ObjectAttributeArgs {
 	#[darling(multiple)]
-	execute_btn: Vec<ExecuteBtn>
+	execute_btn: Vec<ExecuteBtn>,
+	/// Functions validating the whole object (`fn(&Self) -> Result<(), String>`)
+	#[darling(multiple)]
+	validate_struct: Vec<String>,
+	/// Restore the value of a variant picked again in the combo box of an enum (needs
+	/// `Self: Clone + Send + Sync + 'static`)
+	remember_variants: bool,
+	/// How the variant of an enum is picked
+	style: Option<EnumStyle>,
+	/// Don't use the doc comments of the type, its fields and variants as their default tooltip
+	no_doc_tooltip: bool,
+	/// How the names of the fields and variants are turned into labels
+	rename_all: Option<RenameAll>,
+	/// Doc comment of the type (unless `no_doc_tooltip`), the default tooltip of its header
+	#[darling(skip)]
+	doc: Option<String>,
 }
 #[derive(Debug, FromField, FromVariant, Default)]
 #[darling(attributes(inspect), default)]
@@ -118,41 +149,240 @@ struct AttributeArgs {
 	file: Option<FilePathParams>,
 	/// Force edition from string conversion (needs type to implement FromString and Display)
 	from_string: bool,
+	/// When a `from_string` field is updated from the typed text
+	commit: Option<CommitMode>,
 	/// Use a custom function instead of calling [`EguiInspect::inspect_with_custom_id`]
-	custom_fn: Option<String>
+	custom_fn: Option<String>,
+	/// Use a custom function instead of calling [`EguiDisplay::display_with_custom_id`]
+	display_fn: Option<String>,
+	/// Function validating the field value (`fn(&T) -> Result<(), String>`)
+	validate: Option<String>,
+	/// Minimum length of a list: its items can't be removed below it
+	min_len: Option<usize>,
+	/// Maximum length of a list: items can't be added above it
+	max_len: Option<usize>,
+	/// Items can't be added to or removed from the list, only reordered
+	fixed_len: bool,
+	/// Stable key of the items of a list, used for their ids and labels
+	key: Option<ItemKey>,
+	/// Expression building the value of a variant when it is picked (instead of its fields
+	/// set to their default value)
+	default: Option<String>,
+}
+
+/// When a `from_string` field is updated from the typed text (see `egui_field_editor::CommitMode`)
+#[derive(Debug, Default, Clone, Copy, FromMeta)]
+enum CommitMode {
+	/// As soon as the text can be parsed
+	#[default]
+	OnValid,
+	/// When Enter is pressed or the field loses the focus
+	#[darling(rename = "on_enter")]
+	OnEnterOrFocusLost,
+}
+
+/// How the variant of an enum is picked (see `egui_field_editor::derive_support::EnumStyle`)
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, FromMeta)]
+enum EnumStyle {
+	/// A combo box
+	#[default]
+	Combo,
+	/// A radio button per variant
+	Radio,
+	/// A row of buttons
+	Toggle,
+	/// A tab strip above the fields of the variant
+	Tabs,
+}
+
+impl ToTokens for EnumStyle {
+	fn to_tokens(&self, tokens: &mut TokenStream) {
+		let variant = match self {
+			EnumStyle::Combo => quote!(Combo),
+			EnumStyle::Radio => quote!(Radio),
+			EnumStyle::Toggle => quote!(Toggle),
+			EnumStyle::Tabs => quote!(Tabs),
+		};
+		tokens.extend(quote! { egui_field_editor::derive_support::EnumStyle::#variant });
+	}
+}
+
+/// How the names of the fields and variants are turned into labels (`rename_all` attribute)
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, FromMeta)]
+enum RenameAll {
+	/// `max_speed` gives `Max Speed`, `HttpServer` gives `Http Server` (the fields are labelled
+	/// this way without `rename_all`, the case changes aside)
+	#[default]
+	#[darling(rename = "Title Case")]
+	TitleCase,
+	/// `max_speed` gives `max speed`
+	#[darling(rename = "lowercase")]
+	Lowercase,
+	/// `max_speed` gives `MAX_SPEED`
+	#[darling(rename = "SCREAMING_SNAKE")]
+	ScreamingSnake,
+	/// The name is kept (default of the variants)
+	#[darling(rename = "as-is")]
+	AsIs,
+}
+
+/// Trait implementation being generated
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Mode {
+	/// `EguiInspect`, fields are accessed through `&mut`
+	Inspect,
+	/// `EguiDisplay`, fields are accessed through `&`
+	Display,
 }
 
 #[proc_macro_derive(EguiInspect, attributes(inspect))]
 pub fn derive_egui_field_editor(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
 	let input = parse_macro_input!(input as DeriveInput);
-	let attrs= match ObjectAttributeArgs::from_derive_input(&input) {
+	if let Data::Union(data) = &input.data {
+		// the code generators below only handle structs and enums
+		return syn::Error::new_spanned(data.union_token, "Unions are not supported (would need unsafe code)").to_compile_error().into();
+	}
+	if let Err(error) = check_list_attributes(&input.data) {
+		return error.to_compile_error().into();
+	}
+	let mut attrs= match ObjectAttributeArgs::from_derive_input(&input) {
 			Ok(_attrs) => {
 				_attrs
 			}
 			Err(e) => {
 				let msg = e.to_string();
+				// at item level: the error would be dropped with a block around it
 				return proc_macro::TokenStream::from(quote_spanned! {
-					e.span() => {
-						compile_error!(#msg);
-					}
+					e.span() => compile_error!(#msg);
 				});
 			}
 		};
+	if !attrs.no_doc_tooltip {
+		attrs.doc = utils::get_doc(&input.attrs);
+	}
 	let exec_code = get_code_execute_btns(&attrs.execute_btn);
+	let struct_validators = match parse_functions(&attrs.validate_struct) {
+		Ok(validators) => validators,
+		Err(e) => return proc_macro::TokenStream::from(e),
+	};
 	let name = input.ident;
+	let is_generic = !input.generics.params.is_empty();
+	// see `utils::get_field_menu`
+	let is_owned = input.generics.lifetimes().next().is_none();
+	if (attrs.remember_variants || attrs.style.is_some()) && !matches!(input.data, Data::Enum(_)) {
+		return proc_macro::TokenStream::from(quote_spanned! {
+			name.span() => compile_error!("`remember_variants` and `style` are only available on enums");
+		});
+	}
 
-	let generics = add_trait_bounds(input.generics);
+	let mut generics = add_trait_bounds(input.generics.clone(), parse_quote!(egui_field_editor::EguiInspect));
+	if attrs.remember_variants {
+		// the values of the variants are stored in the egui memory
+		let (_, ty_generics, _) = input.generics.split_for_impl();
+		generics.make_where_clause().predicates.push(parse_quote!(#name #ty_generics: Clone + Send + Sync + 'static));
+	}
 	let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+	let display_generics = add_trait_bounds(input.generics, parse_quote!(egui_field_editor::EguiDisplay));
+	let (display_impl_generics, _, display_where_clause) = display_generics.split_for_impl();
 
-	let inspect_code = get_code_for_data(&input.data, &name);
+	let inspect_code = with_struct_validation(get_code_for_data(&input.data, &name, Mode::Inspect, &attrs), &struct_validators);
+	let display_code = with_struct_validation(get_code_for_data(&input.data, &name, Mode::Display, &attrs), &struct_validators);
+	let validation_code = get_validation_code_for_data(&input.data, &name);
+	let diff_code = get_diff_code_for_data(&input.data, &name);
+	let changes_code = get_changes_code_for_data(&input.data, &name);
+	let (copy_all_code, copy_code) = get_copy_code_for_data(&input.data, &name);
+	let (paths_code, get_code, set_code) = get_reflection_code_for_data(&input.data, &name, is_generic, &attrs);
+	let set_variant_code = get_variant_set_code(&input.data, &name);
+	let variants_code = get_all_variants_code(&input.data, &name, &attrs);
+	// see `utils::get_path_imports`
+	let any_imports = if is_generic {
+		quote! { use egui_field_editor::derive_support::AnyValueNothing as _; }
+	} else {
+		quote! { use egui_field_editor::derive_support::{AnyValueStatic as _, AnyValueNothing as _}; }
+	};
+	let self_menu = utils::get_field_menu(quote!(self), is_owned, &name);
 
 	let expanded = quote! {
 		impl #impl_generics egui_field_editor::EguiInspect for #name #ty_generics #where_clause {
-			fn inspect_with_custom_id(&mut self, _parent_id: egui::Id, label: &str, tooltip: &str, read_only: bool, ui: &mut egui::Ui) {
+			fn inspect_with_custom_id(&mut self, _parent_id: egui::Id, label: &str, tooltip: &str, read_only: bool, ui: &mut egui::Ui) -> egui_field_editor::InspectResponse {
 				let id = if _parent_id == egui::Id::NULL { ui.next_auto_id() } else { _parent_id.with(label) };
 				let parent_id = if _parent_id == egui::Id::NULL { egui::Id::NULL } else { id };
-				#inspect_code
+				// registered for the labels of `self` (e.g. the items of a `Vec`)
+				let self_menu = #self_menu;
+				#[allow(unused_mut)]
+				let mut response: egui_field_editor::InspectResponse = #inspect_code;
 				#exec_code
+				response | self_menu.apply(self, ui)
+			}
+			#[allow(unused_variables)]
+			fn validate(&self, path: &str, errors: &mut Vec<egui_field_editor::ValidationError>) {
+				#validation_code
+				#(
+					if let Err(message) = #struct_validators(self) {
+						errors.push(egui_field_editor::ValidationError { path: path.to_owned(), message });
+					}
+				)*
+			}
+			#[allow(unused_variables)]
+			fn diff(&self, other: &Self, path: &str, diffs: &mut Vec<String>) {
+				#diff_code
+			}
+			#[allow(unused_variables)]
+			fn collect_changes(&self, other: &Self, path: &str, changes: &mut Vec<egui_field_editor::FieldChange>) {
+				#changes_code
+			}
+			#[allow(unused_variables)]
+			fn collect_field_paths(&self, path: &str, fields: &mut Vec<egui_field_editor::FieldInfo>) {
+				#paths_code
+			}
+			#[allow(unused_variables)]
+			fn get_by_path(&self, path: &str) -> Option<&dyn std::any::Any> {
+				if path.is_empty() {
+					#[allow(unused_imports)]
+					#any_imports
+					return (&&egui_field_editor::derive_support::AnyValue(self)).as_any();
+				}
+				let (field, rest) = egui_field_editor::derive_support::split_field(path)?;
+				#get_code
+			}
+			#[allow(unused_variables)]
+			fn set_by_path_from_str(&mut self, path: &str, value: &str) -> Result<(), String> {
+				#set_variant_code
+				let Some((field, rest)) = egui_field_editor::derive_support::split_field(path) else {
+					return Err(egui_field_editor::derive_support::no_field(path));
+				};
+				#set_code
+			}
+			#[allow(unused_variables)]
+			fn copy_field_from(&mut self, other: &Self, path: &str) -> Result<(), String> {
+				if path.is_empty() {
+					#copy_all_code
+				}
+				let Some((field, rest)) = egui_field_editor::derive_support::split_field(path) else {
+					return Err(egui_field_editor::derive_support::no_field(path));
+				};
+				#copy_code
+			}
+			fn default_value() -> Option<Self> {
+				#[allow(unused_imports)]
+				use egui_field_editor::derive_support::{MenuDefault as _, MenuDefaultNothing as _};
+				(&&egui_field_editor::derive_support::MenuField::<Self>::of_type()).default_fn().map(|default| default())
+			}
+			fn duplicate(&self) -> Option<Self> {
+				#[allow(unused_imports)]
+				use egui_field_editor::derive_support::{CloneWithClone as _, CloneNothing as _};
+				(&&egui_field_editor::derive_support::CloneValue::of(self)).cloner().map(|clone| clone(self))
+			}
+			#variants_code
+		}
+		impl #display_impl_generics egui_field_editor::EguiDisplay for #name #ty_generics #display_where_clause {
+			fn display_with_custom_id(&self, _parent_id: egui::Id, label: &str, tooltip: &str, ui: &mut egui::Ui) {
+				// the code generated for `inspect_with_custom_id` is reused with the fields borrowed immutably
+				#[allow(unused_variables)]
+				let read_only = true;
+				let id = if _parent_id == egui::Id::NULL { ui.next_auto_id() } else { _parent_id.with(label) };
+				let parent_id = if _parent_id == egui::Id::NULL { egui::Id::NULL } else { id };
+				let _: egui_field_editor::InspectResponse = #display_code;
 			}
 		}
 	};
@@ -160,16 +390,77 @@ pub fn derive_egui_field_editor(input: proc_macro::TokenStream) -> proc_macro::T
 	proc_macro::TokenStream::from(expanded)
 }
 
-fn add_trait_bounds(mut generics: Generics) -> Generics {
+/// Rejects the list attributes (`min_len`, `max_len`, `fixed_len` and `key`) put on a variant or
+/// on a field which isn't a list, they would be ignored.
+fn check_list_attributes(data: &Data) -> syn::Result<()> {
+	let mut errors: Option<syn::Error> = None;
+	let mut add_error = |error: syn::Error| match &mut errors {
+		Some(errors) => errors.combine(error),
+		None => errors = Some(error),
+	};
+	let fields: Vec<&syn::Field> = match data {
+		Data::Struct(data) => data.fields.iter().collect(),
+		Data::Enum(data) => {
+			for variant in &data.variants {
+				if let Ok(attrs) = AttributeArgs::from_variant(variant)
+					&& let Some(name) = utils::get_list_limit_attribute(&attrs).or(attrs.key.as_ref().map(|_| "key")) {
+					add_error(syn::Error::new_spanned(&variant.ident, format!("`{name}` only applies to list fields, not to variants")));
+				}
+			}
+			data.variants.iter().flat_map(|variant| &variant.fields).collect()
+		}
+		Data::Union(_) => unreachable!("unions are rejected by `derive_egui_field_editor`"),
+	};
+	for field in fields {
+		let Ok(attrs) = AttributeArgs::from_field(field) else {
+			continue;
+		};
+		if let Some(name) = utils::get_list_limit_attribute(&attrs) && !utils::is_vec_type(&field.ty) {
+			add_error(syn::Error::new_spanned(&field.ty, format!("`{name}` only applies to `Vec` fields")));
+		}
+		if attrs.key.is_some() && !utils::is_list_type(&field.ty) {
+			add_error(syn::Error::new_spanned(&field.ty, "`key` only applies to `Vec`, array and slice fields"));
+		}
+	}
+	errors.map_or(Ok(()), Err)
+}
+
+fn add_trait_bounds(mut generics: Generics, bound: TypeParamBound) -> Generics {
 	for param in &mut generics.params {
 		if let GenericParam::Type(ref mut type_param) = *param {
 			type_param
 				.bounds
-				.push(parse_quote!(egui_field_editor::EguiInspect));
+				.push(bound.clone());
 		}
 	}
 	generics
 }
+/// Parse the paths of functions given as strings in attributes
+fn parse_functions(functions: &[String]) -> Result<Vec<TokenStream>, TokenStream> {
+	functions.iter().map(|function| {
+		function.parse::<TokenStream>().map_err(|e| {
+			let msg = e.to_string();
+			quote_spanned! {e.span() => {compile_error!{#msg}}}
+		})
+	}).collect()
+}
+/// Wrap the inspection code to show the errors returned by the struct level validators
+fn with_struct_validation(code: TokenStream, struct_validators: &[TokenStream]) -> TokenStream {
+	if struct_validators.is_empty() {
+		return code;
+	}
+	quote! {
+		{
+			let object = ui.scope(|ui| -> egui_field_editor::InspectResponse { #code });
+			#(
+				if let Err(message) = #struct_validators(self) {
+					egui_field_editor::add_validation_error(object.response.rect, &message, ui);
+				}
+			)*
+			object.inner
+		}
+	}
+}
 fn get_code_execute_btns(execs: &[ExecuteBtn]) -> TokenStream {
 	let recurse = execs.iter().map(|exec_fn| {
 		let label = if let Some(l) = &exec_fn.label {
@@ -195,7 +486,7 @@ fn get_code_execute_btns(execs: &[ExecuteBtn]) -> TokenStream {
 			quote! { #func(); }
 		};
 		quote! {
-			egui_field_editor::add_button(#label, #tooltip, read_only, ui, |ui| {
+			response |= egui_field_editor::add_button(#label, #tooltip, read_only, ui, |ui| {
 				#call_func();
 			});
 		}
@@ -204,31 +495,62 @@ fn get_code_execute_btns(execs: &[ExecuteBtn]) -> TokenStream {
 		#(#recurse)*
 	}
 }
-fn get_code_for_data(data: &Data, struct_name: &Ident) -> TokenStream {
+fn get_code_for_data(data: &Data, struct_name: &Ident, mode: Mode, attrs: &ObjectAttributeArgs) -> TokenStream {
 	match *data {
-		Data::Struct(ref data) => get_code_for_struct(data),
-		Data::Enum(ref an_enum) => get_code_for_enum(struct_name, an_enum),
-		Data::Union(_) => unimplemented!("Unions are not supported (would need unsafe code)"),
+		Data::Struct(ref data) => get_code_for_struct(data, mode, attrs),
+		Data::Enum(ref an_enum) => get_code_for_enum(struct_name, an_enum, mode, attrs),
+		Data::Union(_) => unreachable!("unions are rejected by `derive_egui_field_editor`"),
 	}
 }
 
-fn get_code_for_struct(data: &DataStruct)  -> TokenStream {
+fn get_code_for_struct(data: &DataStruct, mode: Mode, struct_attrs: &ObjectAttributeArgs)  -> TokenStream {
 	match data.fields {
-		Fields::Named(ref fields) => get_code_for_struct_named_fields(fields),
-		Fields::Unnamed(ref fields) => get_code_for_struct_unnamed_fields(fields),
-		Fields::Unit => quote! {}
+		Fields::Named(ref fields) => get_code_for_struct_named_fields(fields, mode, struct_attrs),
+		Fields::Unnamed(ref fields) => get_code_for_struct_unnamed_fields(fields, mode, struct_attrs),
+		Fields::Unit => quote! { egui_field_editor::InspectResponse::default() }
+	}
+}
+/// Generate the ```all_variants``` method listing the variants (with their label) of an enum
+/// whose variants have no fields, or nothing for the other types.
+fn get_all_variants_code(data: &Data, enum_name: &Ident, enum_attrs: &ObjectAttributeArgs) -> TokenStream {
+	let Data::Enum(data_enum) = data else {
+		return quote!();
+	};
+	if data_enum.variants.iter().any(|variant| !matches!(variant.fields, Fields::Unit)) {
+		return quote!();
+	}
+	let mut variants = Vec::new();
+	for variant in &data_enum.variants {
+		// the errors are reported by `get_code_for_enum`
+		let Ok(attrs) = AttributeArgs::from_variant(variant) else {
+			return quote!();
+		};
+		if attrs.hidden {
+			continue;
+		}
+		let variant_name = &variant.ident;
+		let label = utils::get_variant_label(variant, &attrs, enum_attrs);
+		variants.push(quote! { (#enum_name::#variant_name, #label) });
+	}
+	quote! {
+		fn all_variants() -> Vec<(Self, &'static str)> {
+			vec![#(#variants),*]
+		}
 	}
 }
+
 /// Generate the code to edit an enum (the content of the ```inspect_with_custom_id``` method)
-fn get_code_for_enum(enum_name: &Ident, data_enum: &DataEnum) -> TokenStream {
+fn get_code_for_enum(enum_name: &Ident, data_enum: &DataEnum, mode: Mode, enum_attrs: &ObjectAttributeArgs) -> TokenStream {
+	let style = enum_attrs.style.unwrap_or_default();
+	let enum_doc = enum_attrs.doc.clone().unwrap_or_default();
 	let mut variant_texts = Vec::new();
 	let mut variant_select_conditions = Vec::new();
+	let mut variant_display_items = Vec::new();
 	let mut variant_content_edit = Vec::new();
 	let mut has_hidden = false;
 
 	for variant in &data_enum.variants {
 		let variant_name = &variant.ident;
-		let mut label = variant_name.to_string();
 		let attrs= match AttributeArgs::from_variant(variant) {
 			Ok(_attr) => {
 				_attr
@@ -246,91 +568,110 @@ fn get_code_for_enum(enum_name: &Ident, data_enum: &DataEnum) -> TokenStream {
 			has_hidden = true;
 			continue;
 		}
-		if let Some(name) = &attrs.name {
-			label = name.clone();
-		}
+		let label = utils::get_variant_label(variant, &attrs, enum_attrs);
+		let variant_tooltip = utils::get_tooltip(&attrs, &variant.attrs, !enum_attrs.no_doc_tooltip).unwrap_or_default();
+		let new_value = match get_new_variant_value(enum_name, variant, &attrs) {
+			Ok(new_value) => new_value,
+			Err(error) => return error,
+		};
 		match &variant.fields {
 			Fields::Unit => get_code_blocks_for_unit_variant(
 				enum_name,
 				variant_name,
-				label, 
+				label.clone(),
 				&mut variant_texts,
-				&mut variant_select_conditions,
 				&mut variant_content_edit
 			),
 			Fields::Unnamed(fields) => get_code_blocks_for_unamed_variant(
 				enum_name,
 				variant_name,
-				label,
+				label.clone(),
 				fields,
 				attrs.read_only,
+				enum_attrs,
+				mode,
 				&mut variant_texts,
-				&mut variant_select_conditions,
 				&mut variant_content_edit
 			),
 			Fields::Named(fields) => get_code_blocks_for_named_variant(
 				enum_name,
 				variant_name,
-				label,
+				label.clone(),
 				fields,
 				attrs.read_only,
+				enum_attrs,
+				mode,
 				&mut variant_texts,
-				&mut variant_select_conditions,
 				&mut variant_content_edit
 			)
 
-		}
+		};
+		variant_select_conditions.push(get_variant_selection_code(enum_name, variant_name, &label, &variant_tooltip, new_value, style, enum_attrs.remember_variants));
+		variant_display_items.push(quote! {
+			egui_field_editor::derive_support::variant_item(#style, matches!(self, #enum_name::#variant_name { .. }), #label, #variant_tooltip, ui);
+		});
 	}
 	
 	if has_hidden {
 		variant_texts.push(quote!{_ => {""}});
-		variant_content_edit.push(quote! {_ => {} });
+		variant_content_edit.push(quote! {_ => { egui_field_editor::InspectResponse::default() } });
 	}
+	// the selected variant can't be changed through a shared reference
+	let variant_selection = match (mode, style) {
+		(Mode::Inspect, _) => quote! { |ui: &mut egui::Ui| { #(#variant_select_conditions)* } },
+		(Mode::Display, EnumStyle::Combo) => quote! { |_ui: &mut egui::Ui| {} },
+		(Mode::Display, _) => quote! { |ui: &mut egui::Ui| { #(#variant_display_items)* } },
+	};
+	let selector = quote! {
+		ui.add_enabled_ui(!read_only, |ui| {
+			let selected_text = if egui_field_editor::derive_support::is_mixed(ui) {
+				egui_field_editor::derive_support::MIXED
+			} else {
+				match self {
+					#(#variant_texts)*
+				}
+			};
+			egui_field_editor::derive_support::variant_selector(#style, id, selected_text, field_width, ui, #variant_selection);
+		});
+	};
+	// the tabs are shown below the label, the other selectors next to it
+	let (row_selector, tabs) = if style == EnumStyle::Tabs { (quote!(), selector) } else { (selector, quote!()) };
 
 	quote_spanned! {
 		enum_name.span() => {
+				let tooltip = if tooltip.is_empty() { #enum_doc } else { tooltip };
 				let id = if _parent_id == egui::Id::NULL { ui.next_auto_id() } else { _parent_id.with(label) };
 				//TODO: find a way to use it (if only Unit variants) or don't declare it if not needed
 				#[allow(unused_variables)]
 				let parent_id = if _parent_id == egui::Id::NULL { egui::Id::NULL } else { id };
 				let available_width = ui.available_width();
 				let label_width = available_width * 0.4;
-				let field_width = 100.0f32.max(available_width * 0.6 - 15.0);
+				let field_width = 100.0f32.max(available_width * 0.6 - 15.0 - egui_field_editor::derive_support::reset_width(ui));
+				let mut response = egui_field_editor::InspectResponse::default();
 
 				ui.horizontal(|ui| {
-					let r = ui.add_sized(
-						[label_width, 0.0],
-						egui::Label::new(label)
-							.truncate()
-							.show_tooltip_when_elided(true)
-							.halign(egui::Align::LEFT),
-					);
+					let r = ui.add_sized([label_width, 0.0], egui_field_editor::derive_support::field_label(label, ui));
+					egui_field_editor::derive_support::add_field_menu(&r, read_only, ui);
 
 					if !tooltip.is_empty() {
 						r.on_hover_text(tooltip).on_disabled_hover_text(tooltip);
 					}
-					ui.add_enabled_ui(!read_only, |ui| {
-						egui::ComboBox::from_id_salt(id)
-						.width(field_width)
-						.selected_text(match self {
-							#(#variant_texts)*
-						})
-						.show_ui(ui, |ui| {
-							#(#variant_select_conditions)*
-						});
-					});
+					#row_selector
+					egui_field_editor::derive_support::reset_button(read_only, ui);
 				});
+				#tabs
 
-			match self {
+			response |= match self {
 				#(#variant_content_edit)*
-			}
+			};
+			response
 		}
 	}
 }
 /// Generate the code to edit an named struct (the content of the ```inspect_with_custom_id``` method)
-fn get_code_for_struct_named_fields(fields: &FieldsNamed) -> TokenStream {
-	let recurse = fields.named.iter().map(|f| {
-		let attrs = match AttributeArgs::from_field(f) {
+fn get_code_for_struct_named_fields(fields: &FieldsNamed, mode: Mode, struct_attrs: &ObjectAttributeArgs) -> TokenStream {
+	let recurse = fields.named.iter().enumerate().map(|(i, f)| {
+		let mut attrs = match AttributeArgs::from_field(f) {
 			Ok(_attrs) => {
 				_attrs
 			}
@@ -345,28 +686,43 @@ fn get_code_for_struct_named_fields(fields: &FieldsNamed) -> TokenStream {
 		if attrs.hidden {
 			return quote!();
 		}
+		utils::resolve_field_args(&mut attrs, f, i, struct_attrs);
 		let name = &f.ident;
+		let field_access = match mode {
+			Mode::Inspect => quote!{&mut self.#name},
+			Mode::Display => quote!{&self.#name},
+		};
 
-		utils::get_function_call(quote!{&mut self.#name}, f, &attrs, "".into())
+		let field_name = name.as_ref().map(|name| name.to_string()).unwrap_or_default();
+		let call = utils::get_function_call(field_access, f, &attrs, field_name, mode);
+		quote! { response |= #call; }
 	});
+	let header_tooltip = utils::get_header_tooltip(quote!{collapsing.header_response}, struct_attrs);
 	quote_spanned! {
 		fields.span() => {
+			#[allow(unused_mut)]
 			let mut add_content=|ui:&mut egui::Ui| {
+				#[allow(unused_mut)]
+				let mut response = egui_field_editor::InspectResponse::default();
 				#(#recurse)*
+				response
 			};
 			if !label.is_empty() {
-				egui::CollapsingHeader::new(label).id_salt(id).show(ui, add_content);
+				let collapsing = egui::CollapsingHeader::new(egui_field_editor::derive_support::label_text(label, ui)).id_salt(id).open(egui_field_editor::derive_support::header_open(ui)).show(ui, add_content);
+				egui_field_editor::derive_support::add_field_menu(&collapsing.header_response, read_only, ui);
+				#header_tooltip
+				collapsing.body_returned.unwrap_or_default()
 			} else {
-				add_content(ui);
+				add_content(ui)
 			}
 		}
 	}
 }
 /// Generate the code to edit an unnamed struct (the content of the ```inspect_with_custom_id``` method)
-fn get_code_for_struct_unnamed_fields(fields: &FieldsUnnamed) -> TokenStream {
+fn get_code_for_struct_unnamed_fields(fields: &FieldsUnnamed, mode: Mode, struct_attrs: &ObjectAttributeArgs) -> TokenStream {
 	let mut recurse = Vec::new();
 	for (i,f) in fields.unnamed.iter().enumerate() {
-		let attrs = match AttributeArgs::from_field(f) {
+		let mut attrs = match AttributeArgs::from_field(f) {
 			Ok(_attrs) => {
 				_attrs
 			}
@@ -381,50 +737,115 @@ fn get_code_for_struct_unnamed_fields(fields: &FieldsUnnamed) -> TokenStream {
 		if attrs.hidden {
 			continue;
 		}
+		utils::resolve_field_args(&mut attrs, f, i, struct_attrs);
 		let tuple_index = Index::from(i);
-		recurse.push(utils::get_function_call(quote!{&mut self.#tuple_index}, f, &attrs, format!("Field {i}")))
+		let field_access = match mode {
+			Mode::Inspect => quote!{&mut self.#tuple_index},
+			Mode::Display => quote!{&self.#tuple_index},
+		};
+		let call = utils::get_function_call(field_access, f, &attrs, i.to_string(), mode);
+		recurse.push(quote! { response |= #call; })
 	};
+	let header_tooltip = utils::get_header_tooltip(quote!{collapsing.header_response}, struct_attrs);
 
 	let result = quote_spanned! {
 		fields.span() => {
+			#[allow(unused_mut)]
 			let mut add_content=|ui:&mut egui::Ui| {
+				#[allow(unused_mut)]
+				let mut response = egui_field_editor::InspectResponse::default();
 				#(#recurse)*
+				response
 			};
 			if !label.is_empty() {
-				egui::CollapsingHeader::new(label).id_salt(id).show(ui, add_content);
+				let collapsing = egui::CollapsingHeader::new(egui_field_editor::derive_support::label_text(label, ui)).id_salt(id).open(egui_field_editor::derive_support::header_open(ui)).show(ui, add_content);
+				egui_field_editor::derive_support::add_field_menu(&collapsing.header_response, read_only, ui);
+				#header_tooltip
+				collapsing.body_returned.unwrap_or_default()
 			} else {
-				add_content(ui);
+				add_content(ui)
 			}
 		}
 	};
 	result
 }
-/// Fill the ```variant_texts```, ```variant_select_conditions``` and ```variant_content_edit``` code blocks for a unit variant
+/// Generate the expression building the value of a variant when it is picked: its `default`
+/// attribute, or its fields set to their default value.
+fn get_new_variant_value(enum_name: &Ident, variant: &Variant, attrs: &AttributeArgs) -> Result<TokenStream, TokenStream> {
+	match attrs.default.as_deref().map(syn::parse_str::<syn::Expr>) {
+		Some(Ok(expr)) => return Ok(expr.to_token_stream()),
+		Some(Err(e)) => {
+			let msg = format!("Invalid `default` expression: {e}");
+			return Err(quote_spanned! {
+				variant.span() => {
+					compile_error!(#msg);
+				}
+			});
+		}
+		None => {}
+	}
+	let variant_name = &variant.ident;
+	Ok(match &variant.fields {
+		Fields::Unit => quote! { #enum_name::#variant_name },
+		Fields::Unnamed(fields) => {
+			let defaults = std::iter::repeat_n(quote! { Default::default() }, fields.unnamed.len());
+			quote! { #enum_name::#variant_name(#(#defaults),*) }
+		}
+		Fields::Named(fields) => {
+			let defaults = fields.named.iter().map(|field| {
+				let name = field.ident.as_ref().unwrap(); // safety: fields is NamedFields
+				quote! { #name: Default::default() }
+			});
+			quote! { #enum_name::#variant_name { #(#defaults),* } }
+		}
+	})
+}
+/// Generate the item of the variant combo box selecting a variant.
+///
+/// The variants are compared with a pattern, `new_value` (building the value of the variant) is
+/// only evaluated when the variant is picked: neither the enum nor the fields of the variant
+/// need to implement `PartialEq`. With `remember_variants`, the value of the variant is
+/// restored if it was picked before, and the value of the previous variant is remembered.
+fn get_variant_selection_code(enum_name: &Ident, variant_name: &Ident, label: &str, tooltip: &str, new_value: TokenStream, style: EnumStyle, remember_variants: bool) -> TokenStream {
+	let new_value = if remember_variants {
+		quote! {
+			{
+				let remembered = egui_field_editor::derive_support::take_remembered_variant(ui, id, |value: &Self| matches!(value, #enum_name::#variant_name { .. }));
+				let previous = std::mem::replace(self, remembered.unwrap_or_else(|| #new_value));
+				egui_field_editor::derive_support::remember_variant(ui, id, previous);
+			}
+		}
+	} else {
+		quote! { *self = #new_value; }
+	};
+	quote! {
+		let selected = matches!(self, #enum_name::#variant_name { .. });
+		if egui_field_editor::derive_support::variant_item(#style, selected, #label, #tooltip, ui) && !selected {
+			#new_value
+			response.mark_changed();
+		}
+	}
+}
+/// Fill the ```variant_texts``` and ```variant_content_edit``` code blocks for a unit variant
 fn get_code_blocks_for_unit_variant(
 		enum_name: &Ident,
 		variant_name: &Ident,
 		label:String,
 		variant_texts:&mut Vec<TokenStream>,
-		variant_select_conditions:&mut Vec<TokenStream>,
 		variant_content_edit:&mut Vec<TokenStream>) {
 	variant_texts.push(quote! {
 		#enum_name::#variant_name => #label,
 	});
 
-	variant_select_conditions.push(quote! {
-		if ui.selectable_value(self, #enum_name::#variant_name, #label).changed() {
-			*self = #enum_name::#variant_name;
-		}
-	});
-
 
 	variant_content_edit.push(quote! {
 		#enum_name::#variant_name => {
 			// nothing to edit
+			egui_field_editor::InspectResponse::default()
 		}
 	});
 }
-/// Fill the ```variant_texts```, ```variant_select_conditions``` and ```variant_content_edit``` code blocks for a unamed fields variant
+/// Fill the ```variant_texts``` and ```variant_content_edit``` code blocks for a unamed fields variant
 #[allow(clippy::too_many_arguments)]
 fn get_code_blocks_for_unamed_variant(
 	enum_name: &Ident,
@@ -432,27 +853,17 @@ fn get_code_blocks_for_unamed_variant(
 	label:String,
 	fields : &FieldsUnnamed,
 	read_only: bool,
+	enum_attrs: &ObjectAttributeArgs,
+	mode: Mode,
 	variant_texts:&mut Vec<TokenStream>,
-	variant_select_conditions:&mut Vec<TokenStream>,
 	variant_content_edit:&mut Vec<TokenStream>) {
 
-	let default_value = if fields.unnamed.len() == 1 {
-		quote! { Default::default() }
-	} else {
-		let defaults = std::iter::repeat_n(quote! { Default::default() }, fields.unnamed.len());
-		quote! {  #(#defaults),*  }
-	};
 	let bindings_ignore = (0..fields.unnamed.len())
 		.map(|_i| Ident::new("_", proc_macro2::Span::call_site()));
 	variant_texts.push(quote! {
 		#enum_name::#variant_name(#(#bindings_ignore),*) => #label,
 	});
 	
-	variant_select_conditions.push(quote! {
-		if ui.selectable_value(self, #enum_name::#variant_name(#default_value), #label).changed() {
-			*self = #enum_name::#variant_name(#default_value);
-		}
-	});
 	let mut fieldnames_list = vec![];
 	let bindings = (0..fields.unnamed.len())
 		.map(|i| Ident::new(&format!("field{i}"), proc_macro2::Span::call_site()));
@@ -474,23 +885,28 @@ fn get_code_blocks_for_unamed_variant(
 			return quote!();
 		}
 		attrs.read_only = attrs.read_only || read_only;
+		utils::resolve_field_args(&mut attrs, f, i, enum_attrs);
 
 		let fieldname = format!("field{i}");
 		let fieldname = Ident::new(&fieldname, proc_macro2::Span::call_site());
 		fieldnames_list.push(quote!{#fieldname});
 
-		utils::get_function_call(quote!{#fieldname}, f, &attrs, format!("Field {i}"))
+		let call = utils::get_function_call(quote!{#fieldname}, f, &attrs, i.to_string(), mode);
+		quote! { response |= #call; }
 	});
 	let bindings_for_match = bindings.clone();
 	variant_content_edit.push(quote! {
 		#enum_name::#variant_name(#(#bindings_for_match),* ) => {
 			ui.indent(id, |ui| {
+				#[allow(unused_mut)]
+				let mut response = egui_field_editor::InspectResponse::default();
 				#(#recurse)*
-			});
+				response
+			}).inner
 		}
 	});
 }
-/// Fill the ```variant_texts```, ```variant_select_conditions``` and ```variant_content_edit``` code blocks for a named fields variant
+/// Fill the ```variant_texts``` and ```variant_content_edit``` code blocks for a named fields variant
 #[allow(clippy::too_many_arguments)]
 fn get_code_blocks_for_named_variant(
 		enum_name: &Ident,
@@ -498,8 +914,9 @@ fn get_code_blocks_for_named_variant(
 		label:String,
 		fields : &FieldsNamed,
 		read_only: bool,
+		enum_attrs: &ObjectAttributeArgs,
+		mode: Mode,
 		variant_texts:&mut Vec<TokenStream>,
-		variant_select_conditions:&mut Vec<TokenStream>,
 		variant_content_edit:&mut Vec<TokenStream>) {
 
 	let mut field_bindings = Vec::new();
@@ -514,27 +931,18 @@ fn get_code_blocks_for_named_variant(
 	variant_texts.push(quote! {
 		#enum_name::#variant_name{#(#bindings_ignore),*} => #label,
 	});
-	
-	let defaults = fields.named.iter().map(|field| {
-		let name = field.ident.as_ref().unwrap(); // safety: fields is NamedFields
-		Some(quote! { #name: Default::default() })
-	}).collect::<Vec<_>>();
-	let default_value = quote! {  #(#defaults),* };
 
-	variant_select_conditions.push(quote! {
-		if ui.selectable_value(self, #enum_name::#variant_name{#default_value}, #label).changed() {
-			*self = #enum_name::#variant_name { #default_value };
-		}
-	});
-
-	for f in &fields.named {
+	for (i, f) in fields.named.iter().enumerate() {
 		let fieldname = f.ident.as_ref().unwrap(); //safety: fields is NamedFields
+		let (binding, _) = utils::get_variant_bindings(f, i);
 		let mut hidden = false;
 		match AttributeArgs::from_field(f) {
 			Ok(mut attrs) => {
 				if !attrs.hidden {
 					attrs.read_only = attrs.read_only || read_only;
-					inspect_calls.push(utils::get_function_call(quote!{#fieldname}, f, &attrs, "".into()));
+					utils::resolve_field_args(&mut attrs, f, i, enum_attrs);
+					let call = utils::get_function_call(quote!{#binding}, f, &attrs, fieldname.to_string(), mode);
+					inspect_calls.push(quote! { response |= #call; });
 				}
 				hidden = attrs.hidden;
 			},
@@ -547,7 +955,7 @@ fn get_code_blocks_for_named_variant(
 			}
 		}
 		if !hidden {
-			field_bindings.push(quote!{#fieldname});
+			field_bindings.push(quote!{#fieldname: #binding});
 		} else {
 			field_bindings.push(quote!{#fieldname: _});
 		}
@@ -556,8 +964,501 @@ fn get_code_blocks_for_named_variant(
 	variant_content_edit.push(quote! {
 		#enum_name::#variant_name { #( #field_bindings ),* } => {
 			ui.indent(id, |ui| {
+				#[allow(unused_mut)]
+				let mut response = egui_field_editor::InspectResponse::default();
 				#( #inspect_calls )*
+				response
+			}).inner
+		}
+	});
+}
+
+/// Generate the content of the ```validate``` method
+fn get_validation_code_for_data(data: &Data, name: &Ident) -> TokenStream {
+	match *data {
+		Data::Struct(ref data) => {
+			let validations = data.fields.iter().enumerate().filter_map(|(i, f)| {
+				let attrs = AttributeArgs::from_field(f).ok().filter(|attrs| !attrs.hidden)?;
+				let (field_access, field_name) = match &f.ident {
+					Some(ident) => (quote!{&self.#ident}, ident.to_string()),
+					None => {
+						let tuple_index = Index::from(i);
+						(quote!{&self.#tuple_index}, i.to_string())
+					}
+				};
+				Some(utils::get_field_validation(field_access, field_name, f, &attrs))
+			});
+			quote! { #(#validations)* }
+		},
+		Data::Enum(ref data_enum) => {
+			let arms = data_enum.variants.iter().filter_map(|variant| {
+				let variant_attrs = AttributeArgs::from_variant(variant).ok()?;
+				if variant_attrs.hidden || variant.fields.is_empty() {
+					return None;
+				}
+				let variant_name = &variant.ident;
+				let mut bindings = Vec::new();
+				let mut validations = Vec::new();
+				for (i, f) in variant.fields.iter().enumerate() {
+					let (binding, _) = utils::get_variant_bindings(f, i);
+					let field_name = f.ident.as_ref().map(|ident| ident.to_string()).unwrap_or_else(|| i.to_string());
+					match AttributeArgs::from_field(f) {
+						Ok(attrs) if !attrs.hidden => {
+							validations.push(utils::get_field_validation(quote!{#binding}, field_name, f, &attrs));
+							bindings.push(match &f.ident {
+								Some(ident) => quote!{#ident: #binding},
+								None => quote!{#binding},
+							});
+						}
+						_ => {
+							bindings.push(match &f.ident {
+								Some(ident) => quote!{#ident: _},
+								None => quote!{_},
+							});
+						}
+					}
+				}
+				let pattern = match &variant.fields {
+					Fields::Named(_) => quote!{ #name::#variant_name { #(#bindings),* } },
+					_ => quote!{ #name::#variant_name ( #(#bindings),* ) },
+				};
+				Some(quote! { #pattern => { #(#validations)* } })
 			});
+			quote! {
+				#[allow(unreachable_patterns)]
+				match self {
+					#(#arms)*
+					_ => {}
+				}
+			}
+		},
+		Data::Union(_) => unreachable!("unions are rejected by `derive_egui_field_editor`"),
+	}
+}
+/// Generate the content of the ```diff``` method
+fn get_diff_code_for_data(data: &Data, name: &Ident) -> TokenStream {
+	let other_variant = quote! {
+		if std::mem::discriminant(self) != std::mem::discriminant(other) {
+			diffs.push(path.to_owned());
+		}
+	};
+	get_comparison_code_for_data(data, name, utils::get_field_diff, other_variant)
+}
+/// Generate the content of the ```collect_changes``` method
+///
+/// A change of variant is reported as a ```Set``` of the variant names (see
+/// [`get_variant_set_code`]), followed by the changes from the value built when the variant is
+/// picked to the new value.
+fn get_changes_code_for_data(data: &Data, name: &Ident) -> TokenStream {
+	let (variant_names, new_values) = match data {
+		Data::Enum(data_enum) => {
+			let variant_names = data_enum.variants.iter().map(|variant| {
+				let variant_name = &variant.ident;
+				let variant_str = variant_name.to_string();
+				quote! { #name::#variant_name { .. } => #variant_str, }
+			}).collect();
+			let new_values = data_enum.variants.iter().filter_map(|variant| {
+				let attrs = AttributeArgs::from_variant(variant).ok().filter(|attrs| !attrs.hidden && !variant.fields.is_empty())?;
+				let new_value = get_new_variant_value(name, variant, &attrs).ok()?;
+				let variant_name = &variant.ident;
+				Some(quote! { #name::#variant_name { .. } => #new_value, })
+			}).collect();
+			(variant_names, new_values)
+		}
+		_ => (Vec::new(), Vec::<TokenStream>::new()),
+	};
+	let new_variant_changes = if new_values.is_empty() {
+		quote!()
+	} else {
+		quote! {
+			#[allow(unreachable_patterns)]
+			let new_value: Self = match other {
+				#(#new_values)*
+				_ => return,
+			};
+			new_value.collect_changes(other, path, changes);
 		}
+	};
+	let other_variant = quote! {
+		if std::mem::discriminant(self) != std::mem::discriminant(other) {
+			let variant_name = |value: &Self| match *value {
+				#(#variant_names)*
+			};
+			changes.push(egui_field_editor::FieldChange::set(path, variant_name(self), variant_name(other)));
+			#new_variant_changes
+		}
+	};
+	get_comparison_code_for_data(data, name, utils::get_field_changes, other_variant)
+}
+/// Generate the code comparing the fields of `self` and `other`
+///
+/// `field_code` generates the code comparing a field (see `utils::get_field_diff`), `other_variant`
+/// the code run when the enum variants don't match.
+fn get_comparison_code_for_data(
+	data: &Data,
+	name: &Ident,
+	field_code: fn(TokenStream, TokenStream, String, &syn::Field) -> TokenStream,
+	other_variant: TokenStream,
+) -> TokenStream {
+	match *data {
+		Data::Struct(ref data) => {
+			let diffs = data.fields.iter().enumerate().filter_map(|(i, f)| {
+				AttributeArgs::from_field(f).ok().filter(|attrs| !attrs.hidden)?;
+				let (field_ref, other_field_ref, field_name) = match &f.ident {
+					Some(ident) => (quote!{&self.#ident}, quote!{&other.#ident}, ident.to_string()),
+					None => {
+						let tuple_index = Index::from(i);
+						(quote!{&self.#tuple_index}, quote!{&other.#tuple_index}, i.to_string())
+					}
+				};
+				Some(field_code(field_ref, other_field_ref, field_name, f))
+			});
+			quote! { #(#diffs)* }
+		},
+		Data::Enum(ref data_enum) => {
+			let arms = data_enum.variants.iter().filter_map(|variant| {
+				let variant_attrs = AttributeArgs::from_variant(variant).ok()?;
+				if variant_attrs.hidden || variant.fields.is_empty() {
+					return None;
+				}
+				let variant_name = &variant.ident;
+				let mut bindings = Vec::new();
+				let mut other_bindings = Vec::new();
+				let mut diffs = Vec::new();
+				for (i, f) in variant.fields.iter().enumerate() {
+					let (binding, other_binding) = utils::get_variant_bindings(f, i);
+					let field_name = f.ident.as_ref().map(|ident| ident.to_string()).unwrap_or_else(|| i.to_string());
+					let hidden = AttributeArgs::from_field(f).map(|attrs| attrs.hidden).unwrap_or(true);
+					match (&f.ident, hidden) {
+						(Some(ident), false) => {
+							bindings.push(quote!{#ident: #binding});
+							other_bindings.push(quote!{#ident: #other_binding});
+						}
+						(Some(ident), true) => {
+							bindings.push(quote!{#ident: _});
+							other_bindings.push(quote!{#ident: _});
+						}
+						(None, false) => {
+							bindings.push(quote!{#binding});
+							other_bindings.push(quote!{#other_binding});
+						}
+						(None, true) => {
+							bindings.push(quote!{_});
+							other_bindings.push(quote!{_});
+						}
+					}
+					if !hidden {
+						diffs.push(field_code(quote!{#binding}, quote!{#other_binding}, field_name, f));
+					}
+				}
+				let (pattern, other_pattern) = match &variant.fields {
+					Fields::Named(_) => (
+						quote!{ #name::#variant_name { #(#bindings),* } },
+						quote!{ #name::#variant_name { #(#other_bindings),* } },
+					),
+					_ => (
+						quote!{ #name::#variant_name ( #(#bindings),* ) },
+						quote!{ #name::#variant_name ( #(#other_bindings),* ) },
+					),
+				};
+				Some(quote! { (#pattern, #other_pattern) => { #(#diffs)* } })
+			});
+			quote! {
+				#[allow(unreachable_patterns)]
+				match (self, other) {
+					#(#arms)*
+					_ => {
+						#other_variant
+					}
+				}
+			}
+		},
+		Data::Union(_) => unreachable!("unions are rejected by `derive_egui_field_editor`"),
+	}
+}
+/// Generate the code switching an enum to the variant named `value` in the
+/// ```set_by_path_from_str``` method, for an empty path (nothing for the other types).
+///
+/// The value of the variant is built as when it is picked, it is kept if the variant doesn't change.
+fn get_variant_set_code(data: &Data, name: &Ident) -> TokenStream {
+	let Data::Enum(data_enum) = data else {
+		return quote!();
+	};
+	let arms = data_enum.variants.iter().filter_map(|variant| {
+		let attrs = AttributeArgs::from_variant(variant).ok().filter(|attrs| !attrs.hidden)?;
+		let new_value = get_new_variant_value(name, variant, &attrs).ok()?;
+		let variant_str = variant.ident.to_string();
+		let variant_name = &variant.ident;
+		Some(quote! {
+			#variant_str => {
+				if !matches!(self, #name::#variant_name { .. }) {
+					*self = #new_value;
+				}
+				Ok(())
+			}
+		})
 	});
-}
\ No newline at end of file
+	quote! {
+		if path.is_empty() {
+			return match value {
+				#(#arms)*
+				_ => Err(egui_field_editor::derive_support::no_variant(value)),
+			};
+		}
+	}
+}
+/// Generate the content of the ```collect_field_paths```, ```get_by_path``` and ```set_by_path_from_str``` methods
+///
+/// The last two match the name of the first field of the path (`field`), the rest of the path being `rest`.
+fn get_reflection_code_for_data(data: &Data, name: &Ident, is_generic: bool, type_attrs: &ObjectAttributeArgs) -> (TokenStream, TokenStream, TokenStream) {
+	match *data {
+		Data::Struct(ref data) => {
+			let mut paths = Vec::new();
+			let mut gets = Vec::new();
+			let mut sets = Vec::new();
+			for (i, f) in data.fields.iter().enumerate() {
+				let Some(mut attrs) = AttributeArgs::from_field(f).ok().filter(|attrs| !attrs.hidden) else {
+					continue;
+				};
+				utils::resolve_field_args(&mut attrs, f, i, type_attrs);
+				let (field_ref, field_access, field_name) = match &f.ident {
+					Some(ident) => (quote!{&self.#ident}, quote!{&mut self.#ident}, ident.to_string()),
+					None => {
+						let tuple_index = Index::from(i);
+						(quote!{&self.#tuple_index}, quote!{&mut self.#tuple_index}, i.to_string())
+					}
+				};
+				paths.push(utils::get_field_paths(field_ref.clone(), field_name.clone(), f, &attrs, is_generic));
+				gets.push(utils::get_field_get(field_ref, field_name.clone(), f, is_generic));
+				sets.push(utils::get_field_set(field_access, field_name, f));
+			}
+			(
+				quote! { #(#paths)* },
+				quote! {
+					match field {
+						#(#gets)*
+						_ => None,
+					}
+				},
+				quote! {
+					match field {
+						#(#sets)*
+						_ => Err(egui_field_editor::derive_support::no_field(path)),
+					}
+				},
+			)
+		},
+		Data::Enum(ref data_enum) => {
+			let mut path_arms = Vec::new();
+			let mut get_arms = Vec::new();
+			let mut set_arms = Vec::new();
+			for variant in &data_enum.variants {
+				let Ok(variant_attrs) = AttributeArgs::from_variant(variant) else {
+					continue;
+				};
+				if variant_attrs.hidden || variant.fields.is_empty() {
+					continue;
+				}
+				let variant_name = &variant.ident;
+				let mut bindings = Vec::new();
+				let mut paths = Vec::new();
+				let mut gets = Vec::new();
+				let mut sets = Vec::new();
+				for (i, f) in variant.fields.iter().enumerate() {
+					let (binding, _) = utils::get_variant_bindings(f, i);
+					let field_name = match &f.ident {
+						Some(ident) => ident.to_string(),
+						None => i.to_string(),
+					};
+					match AttributeArgs::from_field(f) {
+						Ok(mut attrs) if !attrs.hidden => {
+							utils::resolve_field_args(&mut attrs, f, i, type_attrs);
+							paths.push(utils::get_field_paths(quote!{#binding}, field_name.clone(), f, &attrs, is_generic));
+							gets.push(utils::get_field_get(quote!{#binding}, field_name.clone(), f, is_generic));
+							sets.push(utils::get_field_set(quote!{#binding}, field_name, f));
+							bindings.push(match &f.ident {
+								Some(ident) => quote!{#ident: #binding},
+								None => quote!{#binding},
+							});
+						}
+						_ => {
+							bindings.push(match &f.ident {
+								Some(ident) => quote!{#ident: _},
+								None => quote!{_},
+							});
+						}
+					}
+				}
+				let pattern = match &variant.fields {
+					Fields::Named(_) => quote!{ #name::#variant_name { #(#bindings),* } },
+					_ => quote!{ #name::#variant_name ( #(#bindings),* ) },
+				};
+				path_arms.push(quote! { #pattern => { #(#paths)* } });
+				get_arms.push(quote! {
+					#pattern => match field {
+						#(#gets)*
+						_ => None,
+					}
+				});
+				set_arms.push(quote! {
+					#pattern => match field {
+						#(#sets)*
+						_ => Err(egui_field_editor::derive_support::no_field(path)),
+					}
+				});
+			}
+			(
+				quote! {
+					#[allow(unreachable_patterns)]
+					match self {
+						#(#path_arms)*
+						_ => {}
+					}
+				},
+				quote! {
+					#[allow(unreachable_patterns)]
+					match self {
+						#(#get_arms)*
+						_ => None,
+					}
+				},
+				quote! {
+					#[allow(unreachable_patterns)]
+					match self {
+						#(#set_arms)*
+						_ => Err(egui_field_editor::derive_support::no_field(path)),
+					}
+				},
+			)
+		},
+		Data::Union(_) => unreachable!("unions are rejected by `derive_egui_field_editor`"),
+	}
+}
+/// Generate the content of the ```copy_field_from``` method: the code copying every field for an
+/// empty path, and the code copying the field named `field` (the rest of the path being `rest`)
+fn get_copy_code_for_data(data: &Data, name: &Ident) -> (TokenStream, TokenStream) {
+	match *data {
+		Data::Struct(ref data) => {
+			let mut copies = Vec::new();
+			let mut arms = Vec::new();
+			for (i, f) in data.fields.iter().enumerate() {
+				if AttributeArgs::from_field(f).map(|attrs| attrs.hidden).unwrap_or(true) {
+					continue;
+				}
+				let (field_access, other_field_ref, field_name) = match &f.ident {
+					Some(ident) => (quote!{&mut self.#ident}, quote!{&other.#ident}, ident.to_string()),
+					None => {
+						let tuple_index = Index::from(i);
+						(quote!{&mut self.#tuple_index}, quote!{&other.#tuple_index}, i.to_string())
+					}
+				};
+				let copy = utils::get_field_copy(field_access.clone(), other_field_ref.clone(), quote!(""), f);
+				copies.push(quote! { #copy?; });
+				let copy = utils::get_field_copy(field_access, other_field_ref, quote!(rest), f);
+				arms.push(quote! { #field_name => #copy, });
+			}
+			(
+				quote! {
+					#(#copies)*
+					return Ok(());
+				},
+				quote! {
+					match field {
+						#(#arms)*
+						_ => Err(egui_field_editor::derive_support::no_field(path)),
+					}
+				},
+			)
+		},
+		Data::Enum(ref data_enum) => {
+			let mut copy_all_arms = Vec::new();
+			let mut copy_arms = Vec::new();
+			for variant in &data_enum.variants {
+				let Ok(variant_attrs) = AttributeArgs::from_variant(variant) else {
+					continue;
+				};
+				if variant_attrs.hidden || variant.fields.is_empty() {
+					continue;
+				}
+				let variant_name = &variant.ident;
+				let mut bindings = Vec::new();
+				let mut other_bindings = Vec::new();
+				let mut copies = Vec::new();
+				let mut arms = Vec::new();
+				for (i, f) in variant.fields.iter().enumerate() {
+					let (binding, other_binding) = utils::get_variant_bindings(f, i);
+					let field_name = f.ident.as_ref().map(|ident| ident.to_string()).unwrap_or_else(|| i.to_string());
+					let hidden = AttributeArgs::from_field(f).map(|attrs| attrs.hidden).unwrap_or(true);
+					match (&f.ident, hidden) {
+						(Some(ident), false) => {
+							bindings.push(quote!{#ident: #binding});
+							other_bindings.push(quote!{#ident: #other_binding});
+						}
+						(Some(ident), true) => {
+							bindings.push(quote!{#ident: _});
+							other_bindings.push(quote!{#ident: _});
+						}
+						(None, false) => {
+							bindings.push(quote!{#binding});
+							other_bindings.push(quote!{#other_binding});
+						}
+						(None, true) => {
+							bindings.push(quote!{_});
+							other_bindings.push(quote!{_});
+						}
+					}
+					if !hidden {
+						let copy = utils::get_field_copy(quote!{#binding}, quote!{#other_binding}, quote!(""), f);
+						copies.push(quote! { #copy?; });
+						let copy = utils::get_field_copy(quote!{#binding}, quote!{#other_binding}, quote!(rest), f);
+						arms.push(quote! { #field_name => #copy, });
+					}
+				}
+				let (pattern, other_pattern) = match &variant.fields {
+					Fields::Named(_) => (
+						quote!{ #name::#variant_name { #(#bindings),* } },
+						quote!{ #name::#variant_name { #(#other_bindings),* } },
+					),
+					_ => (
+						quote!{ #name::#variant_name ( #(#bindings),* ) },
+						quote!{ #name::#variant_name ( #(#other_bindings),* ) },
+					),
+				};
+				copy_all_arms.push(quote! { (#pattern, #other_pattern) => { #(#copies)* } });
+				copy_arms.push(quote! {
+					(#pattern, #other_pattern) => match field {
+						#(#arms)*
+						_ => Err(egui_field_editor::derive_support::no_field(path)),
+					}
+				});
+			}
+			(
+				quote! {
+					if std::mem::discriminant(self) != std::mem::discriminant(other) {
+						#[allow(unused_imports)]
+						use egui_field_editor::derive_support::{CloneWithClone as _, CloneNothing as _};
+						let Some(clone) = (&&egui_field_editor::derive_support::CloneValue::of(self)).cloner() else {
+							return Err("The variant can't be changed without `Clone`".to_owned());
+						};
+						*self = clone(other);
+						return Ok(());
+					}
+					#[allow(unreachable_patterns)]
+					match (self, other) {
+						#(#copy_all_arms)*
+						_ => {}
+					}
+					return Ok(());
+				},
+				quote! {
+					#[allow(unreachable_patterns)]
+					match (self, other) {
+						#(#copy_arms)*
+						_ => Err(egui_field_editor::derive_support::no_field(path)),
+					}
+				},
+			)
+		},
+		Data::Union(_) => unreachable!("unions are rejected by `derive_egui_field_editor`"),
+	}
+}
@@ -1,152 +1,671 @@
-use proc_macro2::TokenStream;
-use quote::{quote, quote_spanned};
+use proc_macro2::{Ident, TokenStream};
+use quote::{format_ident, quote, quote_spanned, ToTokens};
+use syn::punctuated::Punctuated;
 use syn::spanned::Spanned;
-use syn::Type;
+use syn::{Attribute, Expr, ExprLit, Lit, Meta, Token, Type, Variant};
 use syn::{Field};
 
-use crate::AttributeArgs;
+use crate::{AttributeArgs, CommitMode, ItemKey, Mode, ObjectAttributeArgs, RenameAll};
 
 #[allow(dead_code)]
 pub fn get_path_str(type_path: &Type) -> String {
 	quote::ToTokens::to_token_stream(&type_path).to_string()
 }
 
+/// The names binding the field `i` of a variant when matching `self` and `other`.
+///
+/// The names of named fields are prefixed, they would otherwise shadow the parameters of the
+/// generated methods (e.g. a field named `label` or `path`).
+pub(crate) fn get_variant_bindings(field: &Field, i: usize) -> (Ident, Ident) {
+	match &field.ident {
+		Some(ident) => (format_ident!("__self_{}", ident), format_ident!("__other_{}", ident)),
+		None => (format_ident!("field{}", i), format_ident!("other_field{}", i)),
+	}
+}
+
+/// The doc comment (`///` lines) of an item, the lines of a paragraph being joined, or `None`
+/// if it has none.
+pub(crate) fn get_doc(attrs: &[Attribute]) -> Option<String> {
+	let lines = attrs.iter().filter(|attr| attr.path().is_ident("doc")).filter_map(|attr| match &attr.meta {
+		Meta::NameValue(nv) => match &nv.value {
+			Expr::Lit(ExprLit { lit: Lit::Str(s), .. }) => Some(s.value()),
+			_ => None,
+		},
+		_ => None,
+	});
+	let mut doc = String::new();
+	for line in lines {
+		let line = line.trim();
+		if line.is_empty() {
+			if !doc.is_empty() && !doc.ends_with('\n') {
+				doc.push('\n');
+			}
+		} else {
+			if !doc.is_empty() && !doc.ends_with('\n') {
+				doc.push(' ');
+			}
+			doc.push_str(line);
+		}
+	}
+	let doc = doc.trim_end();
+	(!doc.is_empty()).then(|| doc.to_owned())
+}
+
+/// The tooltip of a field or variant: its `tooltip` attribute, or its doc comment if
+/// `doc_tooltips` is set (i.e. the type doesn't have the `no_doc_tooltip` attribute).
+pub(crate) fn get_tooltip(args: &AttributeArgs, attrs: &[Attribute], doc_tooltips: bool) -> Option<String> {
+	args.tooltip.clone().or_else(|| get_doc(attrs).filter(|_| doc_tooltips))
+}
+
+/// Generate the code showing the tooltip of the header of a type: the `tooltip` given by the
+/// parent, or the doc comment of the type.
+pub(crate) fn get_header_tooltip(header_response: TokenStream, type_attrs: &ObjectAttributeArgs) -> TokenStream {
+	let doc = type_attrs.doc.clone().unwrap_or_default();
+	quote! {
+		let header_tooltip = if tooltip.is_empty() { #doc } else { tooltip };
+		if !header_tooltip.is_empty() {
+			#header_response.on_hover_text(header_tooltip);
+		}
+	}
+}
+
+/// Fills the label and the tooltip of the field `index` of a type when they aren't given by its
+/// attributes: its name styled by the `rename_all` attribute of the type (prettified without
+/// it, see `prettify_name`), and its doc comment.
+pub(crate) fn resolve_field_args(args: &mut AttributeArgs, field: &Field, index: usize, type_attrs: &ObjectAttributeArgs) {
+	args.tooltip = get_tooltip(args, &field.attrs, !type_attrs.no_doc_tooltip);
+	if args.name.is_none() {
+		args.name = Some(match (&field.ident, type_attrs.rename_all) {
+			(Some(ident), Some(rename_all)) => rename_all.apply(&ident.to_string()),
+			(Some(ident), None) => prettify_name(&ident.to_string()),
+			(None, rename_all) => tuple_field_label(index, rename_all.unwrap_or_default()),
+		});
+	}
+}
+
+/// Label of a variant: its `name` attribute, or its name styled by the `rename_all` attribute of
+/// the enum (unchanged without it).
+pub(crate) fn get_variant_label(variant: &Variant, args: &AttributeArgs, enum_attrs: &ObjectAttributeArgs) -> String {
+	let name = variant.ident.to_string();
+	args.name.clone().unwrap_or_else(|| enum_attrs.rename_all.map_or(name.clone(), |rename_all| rename_all.apply(&name)))
+}
+
+/// Label of the field `index` of a tuple struct or variant: `Field 0`, `field 0`, `FIELD_0`, or
+/// `0` as is.
+fn tuple_field_label(index: usize, rename_all: RenameAll) -> String {
+	match rename_all {
+		RenameAll::AsIs => index.to_string(),
+		_ => rename_all.apply(&format!("field_{index}")),
+	}
+}
+
+/// The words of an identifier, split at the underscores (`snake_case`) and at the case changes
+/// (`CamelCase`). The digits ending a word are a word of their own (`vec3` gives `vec`, `3`).
+fn split_words(name: &str) -> Vec<&str> {
+	let mut words = Vec::new();
+	for part in name.split('_').filter(|part| !part.is_empty()) {
+		let chars: Vec<(usize, char)> = part.char_indices().collect();
+		let mut start = 0;
+		for (k, &(i, c)) in chars.iter().enumerate().skip(1) {
+			let previous = chars[k - 1].1;
+			let next_is_lower = chars.get(k + 1).is_some_and(|&(_, next)| next.is_lowercase());
+			// `HTTPServer` gives `HTTP`, `Server`
+			if c.is_uppercase() && (!previous.is_uppercase() || next_is_lower) {
+				words.push(&part[start..i]);
+				start = i;
+			}
+		}
+		words.push(&part[start..]);
+	}
+	words
+		.into_iter()
+		.flat_map(|word| {
+			let letters = word.trim_end_matches(|c: char| c.is_ascii_digit());
+			[letters, &word[letters.len()..]]
+		})
+		.filter(|word| !word.is_empty())
+		.collect()
+}
+
+impl RenameAll {
+	/// Label of a field or variant named `name`.
+	pub(crate) fn apply(self, name: &str) -> String {
+		let words = split_words(name);
+		match self {
+			RenameAll::TitleCase => words.iter().map(|word| capitalize(word)).collect::<Vec<_>>().join(" "),
+			RenameAll::Lowercase => words.iter().map(|word| word.to_lowercase()).collect::<Vec<_>>().join(" "),
+			RenameAll::ScreamingSnake => words.iter().map(|word| word.to_uppercase()).collect::<Vec<_>>().join("_"),
+			RenameAll::AsIs => name.to_owned(),
+		}
+	}
+}
+
+/// The type of the bounds of a `Range<T>` or `RangeInclusive<T>`, shown by `add_range` or
+/// `add_range_slider` with the `range` and `slider` attributes.
+fn get_range_bound_type(ty: &Type) -> Option<&Type> {
+	let Type::Path(path) = ty else {
+		return None;
+	};
+	let segment = path.path.segments.last()?;
+	if segment.ident != "Range" && segment.ident != "RangeInclusive" {
+		return None;
+	}
+	let syn::PathArguments::AngleBracketed(args) = &segment.arguments else {
+		return None;
+	};
+	match args.args.first()? {
+		syn::GenericArgument::Type(bound) => Some(bound),
+		_ => None,
+	}
+}
+
+/// `word` with its first letter in upper case.
+fn capitalize(word: &str) -> String {
+	let mut chars = word.chars();
+	match chars.next() {
+		Some(first) => first.to_uppercase().collect::<String>() + chars.as_str(),
+		None => String::new(),
+	}
+}
+
+/// Default label of a field or method: `max_speed2` gives `Max Speed 2`. Unlike the
+/// `Title Case` style of `rename_all`, the words are only split at the underscores and before
+/// the ending digits (`HttpServer` is kept).
 pub fn prettify_name(s: &str) -> String {
 	s.split('_')
 		.filter(|part| !part.is_empty())
 		.map(|word| {
-			let split_index = word
-				.char_indices()
-				.rev()
-				.find(|&(_, c)| !c.is_ascii_digit())
-				.map(|(i, _)| i + 1)
-				.unwrap_or(0);
-
-			let (letters, digits) = word.split_at(split_index);
-
-			let mut chars = letters.chars();
-			let capitalized = match chars.next() {
-				Some(first) => first.to_uppercase().collect::<String>() + chars.as_str(),
-				None => String::new(),
-			};
-
+			let letters = word.trim_end_matches(|c: char| c.is_ascii_digit());
+			let digits = &word[letters.len()..];
 			if digits.is_empty() {
-				capitalized
+				capitalize(letters)
+			} else if letters.is_empty() {
+				digits.to_owned()
 			} else {
-				format!("{capitalized} {digits}")
+				format!("{} {digits}", capitalize(letters))
 			}
 		})
 		.collect::<Vec<_>>()
 		.join(" ")
 }
 
-pub(crate) fn get_function_call(field_access :TokenStream, field: &Field, attrs: &AttributeArgs, default_field_name:String) -> TokenStream {
-	let name = &field.ident;
+/// Generate the code rendering a field.
+///
+/// `field_name` is the name of the field in the paths (see `egui_field_editor::FieldScope`).
+pub(crate) fn get_function_call(field_access :TokenStream, field: &Field, attrs: &AttributeArgs, field_name: String, mode: Mode) -> TokenStream {
+	let call = get_field_call(field_access.clone(), field, attrs, mode);
+	let call = match &attrs.validate {
+		None => call,
+		Some(validate) => match validate.parse::<TokenStream>() {
+			Ok(validate_fn) => quote_spanned! {
+				field.span() => {
+					let field = ui.scope(|ui| #call);
+					if let Err(message) = #validate_fn(#field_access) {
+						egui_field_editor::add_validation_error(field.response.rect, &message, ui);
+					}
+					field.inner
+				}
+			},
+			Err(e) => {
+				let msg=e.to_string();
+				quote_spanned! {
+					field.span() => {
+						compile_error!(#msg);
+					}
+				}
+			}
+		}
+	};
+	let item_keys = get_item_keys(field_access.clone(), attrs, field);
+	let call = match mode {
+		Mode::Display => quote_spanned! {
+			field.span() => {
+				#item_keys
+				#call
+			}
+		},
+		Mode::Inspect => {
+			let field_menu = get_field_menu(field_access.clone(), !has_lifetime(&field.ty), field);
+			let list_limits = get_list_limits(attrs, field);
+			quote_spanned! {
+				field.span() => {
+					let field_menu = #field_menu;
+					#list_limits
+					#item_keys
+					let response = #call;
+					response | field_menu.apply(#field_access, ui)
+				}
+			}
+		}
+	};
+	quote_spanned! {
+		field.span() => {
+			let _field_scope = egui_field_editor::FieldScope::new(ui, #field_name);
+			// hidden by the search of the inspector
+			if !egui_field_editor::derive_support::is_field_visible(ui) {
+				egui_field_editor::InspectResponse::default()
+			} else #call
+		}
+	}
+}
+
+/// Generate the code registering the context menu (Copy/Paste/Reset) of a field, or of `self`.
+///
+/// The actions are picked from the traits implemented by the type of `field_access`. Values
+/// borrowing data (`owned` is `false`) are never pasted: `serde` can only deserialize them for
+/// some lifetimes (e.g. `&'static str`), which the trait selection can't tell apart.
+pub(crate) fn get_field_menu(field_access: TokenStream, owned: bool, span: impl Spanned) -> TokenStream {
+	let deserializer = if owned {
+		quote!((&&egui_field_editor::derive_support::MenuField::of(#field_access)).deserializer())
+	} else {
+		quote!(None)
+	};
+	quote_spanned! {
+		span.span() => {
+			#[allow(unused_imports)]
+			use egui_field_editor::derive_support::{MenuSerialize as _, MenuSerializeNothing as _, MenuDeserialize as _, MenuDeserializeNothing as _, MenuDefault as _, MenuDefaultNothing as _};
+			egui_field_editor::derive_support::FieldMenu::new(
+				(&&egui_field_editor::derive_support::MenuField::of(#field_access)).serializer(),
+				#deserializer,
+				(&&egui_field_editor::derive_support::MenuField::of(#field_access)).default_fn(),
+			).register(ui)
+		}
+	}
+}
+
+/// `true` if `ty` is a reference or has lifetime parameters, e.g. `&'static str` or `Cow<'a, str>`.
+pub(crate) fn has_lifetime(ty: &Type) -> bool {
+	fn tokens_have_lifetime(tokens: TokenStream) -> bool {
+		tokens.into_iter().any(|token| match token {
+			proc_macro2::TokenTree::Punct(punct) => punct.as_char() == '&' || punct.as_char() == '\'',
+			proc_macro2::TokenTree::Group(group) => tokens_have_lifetime(group.stream()),
+			_ => false,
+		})
+	}
+	tokens_have_lifetime(ty.to_token_stream())
+}
+
+/// The name of the first length limit attribute (`min_len`, `max_len` or `fixed_len`) set in
+/// `attrs`, if any.
+pub(crate) fn get_list_limit_attribute(attrs: &AttributeArgs) -> Option<&'static str> {
+	if attrs.min_len.is_some() {
+		Some("min_len")
+	} else if attrs.max_len.is_some() {
+		Some("max_len")
+	} else if attrs.fixed_len {
+		Some("fixed_len")
+	} else {
+		None
+	}
+}
+
+/// Whether `ty` is written as a `Vec`, the only list whose length can be limited.
+pub(crate) fn is_vec_type(ty: &Type) -> bool {
+	matches!(ty, Type::Path(path) if path.path.segments.last().is_some_and(|segment| segment.ident == "Vec"))
+}
+
+/// Whether `ty` is written as a list whose items can have a key: a `Vec`, an array or a slice.
+pub(crate) fn is_list_type(ty: &Type) -> bool {
+	match ty {
+		Type::Array(_) | Type::Slice(_) => true,
+		Type::Reference(reference) => is_list_type(&reference.elem),
+		Type::Group(group) => is_list_type(&group.elem),
+		ty => is_vec_type(ty),
+	}
+}
 
-	let name_str = match &attrs.name {
-		Some(n) => n.clone(),
-		None => {
-			if let Some(name) = name {
-				prettify_name(&name.to_string())
+/// Generate the code registering the length limits of a list field (`min_len`, `max_len` and
+/// `fixed_len` attributes), if any.
+fn get_list_limits(attrs: &AttributeArgs, span: impl Spanned) -> TokenStream {
+	if attrs.min_len.is_none() && attrs.max_len.is_none() && !attrs.fixed_len {
+		return quote!();
+	}
+	let min_len = attrs.min_len.unwrap_or(0);
+	let max_len = match attrs.max_len {
+		Some(max_len) => quote!(Some(#max_len)),
+		None => quote!(None),
+	};
+	let fixed_len = attrs.fixed_len;
+	quote_spanned! {
+		span.span() => egui_field_editor::derive_support::ListLimits { min_len: #min_len, max_len: #max_len, fixed_len: #fixed_len }.register(ui);
+	}
+}
+
+/// Generate the code registering the keys of the items of a list field (`key` attribute), if any.
+///
+/// `key` uses `InspectKey`, `key = "name"` the field `name` of the items, and `key = "path::to_fn"`
+/// the function `fn(&T) -> impl ToString`.
+fn get_item_keys(field_access: TokenStream, attrs: &AttributeArgs, span: impl Spanned) -> TokenStream {
+	let key = match &attrs.key {
+		None => return quote!(),
+		Some(ItemKey::Trait) => quote!(egui_field_editor::InspectKey::inspect_key),
+		Some(ItemKey::Value(value)) => {
+			if let Ok(member) = syn::parse_str::<syn::Member>(value) {
+				quote!(|item| &item.#member)
+			} else if let Ok(path) = syn::parse_str::<syn::Path>(value) {
+				quote!(#path)
 			} else {
-				prettify_name(&default_field_name.to_string())
+				let msg = format!("`key` must be a field of the items or a function path, found `{value}`");
+				return quote_spanned!(span.span() => compile_error!(#msg););
 			}
+		}
+	};
+	quote_spanned! {
+		span.span() => egui_field_editor::derive_support::ItemKeys::of(&*#field_access, #key).register(ui);
+	}
+}
+
+/// Generate the code validating a field in the derived `validate` method.
+///
+/// `field_ref` is a shared reference to the field and `field_name` its name in the validation path.
+pub(crate) fn get_field_validation(field_ref: TokenStream, field_name: String, field: &Field, attrs: &AttributeArgs) -> TokenStream {
+	let validation = match &attrs.validate {
+		Some(validate) => match validate.parse::<TokenStream>() {
+			Ok(validate_fn) => quote_spanned! {
+				field.span() => {
+					if let Err(message) = #validate_fn(#field_ref) {
+						errors.push(egui_field_editor::ValidationError { path: field_path.clone(), message });
+					}
+				}
+			},
+			// already reported by `get_function_call`
+			Err(_) => quote!(),
 		},
+		None => quote!(),
 	};
+	quote_spanned! {
+		field.span() => {
+			let field_path = egui_field_editor::derive_support::join_path(path, #field_name);
+			#validation
+			#[allow(unused_imports)]
+			use egui_field_editor::derive_support::{ValidateWithEguiInspect as _, ValidateNothing as _};
+			(&&egui_field_editor::derive_support::ValidateField(#field_ref)).validate_field(&field_path, errors);
+		}
+	}
+}
+
+/// Generate the code comparing a field in the derived `diff` method.
+///
+/// `field_ref` and `other_field_ref` are shared references to the field of both objects.
+pub(crate) fn get_field_diff(field_ref: TokenStream, other_field_ref: TokenStream, field_name: String, field: &Field) -> TokenStream {
+	quote_spanned! {
+		field.span() => {
+			let field_path = egui_field_editor::derive_support::join_path(path, #field_name);
+			#[allow(unused_imports)]
+			use egui_field_editor::derive_support::{DiffWithEguiInspectEq as _, DiffWithEguiInspect as _, DiffWithEq as _, DiffNothing as _};
+			(&&&&egui_field_editor::derive_support::DiffField(#field_ref, #other_field_ref)).diff_field(&field_path, diffs);
+		}
+	}
+}
+
+/// Generate the code reporting the changes of a field in the derived `collect_changes` method.
+///
+/// `field_ref` and `other_field_ref` are shared references to the field of both objects.
+pub(crate) fn get_field_changes(field_ref: TokenStream, other_field_ref: TokenStream, field_name: String, field: &Field) -> TokenStream {
+	quote_spanned! {
+		field.span() => {
+			let field_path = egui_field_editor::derive_support::join_path(path, #field_name);
+			#[allow(unused_imports)]
+			use egui_field_editor::derive_support::{ChangeWithEguiInspectEq as _, ChangeWithEguiInspect as _, ChangeWithDisplayEq as _, ChangeNothing as _};
+			(&&&&egui_field_editor::derive_support::ChangeField(#field_ref, #other_field_ref)).collect_field_changes(&field_path, changes);
+		}
+	}
+}
+
+/// Generate the code copying a field from `other` in the derived `copy_field_from` method.
+///
+/// `field_access` is a mutable reference to the field, `other_field_ref` a shared reference to
+/// the field of `other`, and `path` the path to copy in the field.
+pub(crate) fn get_field_copy(field_access: TokenStream, other_field_ref: TokenStream, path: TokenStream, field: &Field) -> TokenStream {
+	quote_spanned! {
+		field.span() => {
+			#[allow(unused_imports)]
+			use egui_field_editor::derive_support::{CopyWithEguiInspectClone as _, CopyWithEguiInspect as _, CopyWithClone as _, CopyNothing as _};
+			let copy = (&&&&egui_field_editor::derive_support::CopyField::of(#field_access)).copier();
+			copy(#field_access, #other_field_ref, #path)
+		}
+	}
+}
+
+/// Imports of the traits reaching the fields in the derived reflection methods.
+///
+/// `PathWithAny` is left out for generic types: the `'static` bound of [`std::any::Any`] can't
+/// be checked by the autoref-based specialization, it would be picked for fields of type `T`.
+pub(crate) fn get_path_imports(is_generic: bool) -> TokenStream {
+	if is_generic {
+		quote! { use egui_field_editor::derive_support::{PathWithEguiInspect as _, PathNothing as _}; }
+	} else {
+		quote! { use egui_field_editor::derive_support::{PathWithEguiInspect as _, PathWithAny as _, PathNothing as _}; }
+	}
+}
+
+/// Generate the code describing a field in the derived `collect_field_paths` method.
+///
+/// `field_ref` is a shared reference to the field and `field_name` its name in the path.
+pub(crate) fn get_field_paths(field_ref: TokenStream, field_name: String, field: &Field, attrs: &AttributeArgs, is_generic: bool) -> TokenStream {
+	let label = get_field_label(attrs);
+	let tooltip = attrs.tooltip.clone().unwrap_or_default();
 	let read_only = attrs.read_only;
-	let slider= &attrs.slider;
-	let range= &attrs.range;
+	let attributes = get_field_attributes(field);
+	let imports = get_path_imports(is_generic);
+	quote_spanned! {
+		field.span() => {
+			let field_path = egui_field_editor::derive_support::join_path(path, #field_name);
+			fields.push(egui_field_editor::FieldInfo {
+				path: field_path.clone(),
+				label: #label.to_owned(),
+				tooltip: #tooltip.to_owned(),
+				type_name: std::any::type_name_of_val(#field_ref),
+				read_only: #read_only,
+				attributes: &[#(#attributes),*],
+			});
+			#[allow(unused_imports)]
+			#imports
+			(&&&egui_field_editor::derive_support::PathField(#field_ref)).collect_field_paths(&field_path, fields);
+		}
+	}
+}
+
+/// Generate the match arm returning a field (or one of its fields) in the derived `get_by_path` method.
+pub(crate) fn get_field_get(field_ref: TokenStream, field_name: String, field: &Field, is_generic: bool) -> TokenStream {
+	let imports = get_path_imports(is_generic);
+	quote_spanned! {
+		field.span() => #field_name => {
+			#[allow(unused_imports)]
+			#imports
+			(&&&egui_field_editor::derive_support::PathField(#field_ref)).get_field(rest)
+		}
+	}
+}
+
+/// Generate the match arm setting a field (or one of its fields) in the derived `set_by_path_from_str` method.
+///
+/// `field_access` is a mutable reference to the field.
+pub(crate) fn get_field_set(field_access: TokenStream, field_name: String, field: &Field) -> TokenStream {
+	quote_spanned! {
+		field.span() => #field_name => {
+			#[allow(unused_imports)]
+			use egui_field_editor::derive_support::{SetWithEguiInspect as _, SetWithFromStr as _, SetNothing as _};
+			let set = (&&&egui_field_editor::derive_support::SetField::of(#field_access)).setter();
+			set(#field_access, rest, value)
+		}
+	}
+}
+
+/// The `#[inspect(...)]` attributes of a field as `(name, value)` tuples, the value being the
+/// source code of the attribute arguments.
+fn get_field_attributes(field: &Field) -> Vec<TokenStream> {
+	let metas = field
+		.attrs
+		.iter()
+		.filter(|attr| attr.path().is_ident("inspect"))
+		.filter_map(|attr| attr.parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated).ok())
+		.flatten();
+	metas
+		.map(|meta| {
+			let name = meta.path().get_ident().map(|ident| ident.to_string()).unwrap_or_default();
+			let value = match &meta {
+				Meta::Path(_) => String::new(),
+				Meta::NameValue(nv) => match &nv.value {
+					Expr::Lit(ExprLit { lit: Lit::Str(s), .. }) => s.value(),
+					value => source_text(value.span(), value.to_token_stream()),
+				},
+				Meta::List(list) => {
+					let text = source_text(list.delimiter.span().join(), list.to_token_stream());
+					// `(...)`, or the whole list if the source isn't available
+					match text.find('(') {
+						Some(start) if text.ends_with(')') => text[start + 1..text.len() - 1].trim().to_owned(),
+						_ => list.tokens.to_string(),
+					}
+				}
+			};
+			quote! { (#name, #value) }
+		})
+		.collect()
+}
+
+/// The source code at `span`, or `tokens` printed if it isn't available.
+fn source_text(span: proc_macro2::Span, tokens: TokenStream) -> String {
+	span.source_text().unwrap_or_else(|| tokens.to_string())
+}
+
+/// Label of a field: its `name` attribute, filled by [`resolve_field_args`] if not given.
+fn get_field_label(attrs: &AttributeArgs) -> String {
+	attrs.name.clone().unwrap_or_default()
+}
+
+fn get_field_call(field_access :TokenStream, field: &Field, attrs: &AttributeArgs, mode: Mode) -> TokenStream {
+	let name_str = get_field_label(attrs);
 	let mut tooltip = "";
 	if let Some(ttip) = attrs.tooltip.as_ref() {
 		tooltip = ttip;
 	}
+	if mode == Mode::Display {
+		return get_display_call(field_access, field, attrs, &name_str, tooltip);
+	}
+	if let Some(call) = get_attribute_call(field_access.clone(), field, attrs, &name_str, tooltip) {
+		return call;
+	}
+	let read_only = attrs.read_only;
+
+	quote_spanned! {
+		field.span() => {
+			let id = if _parent_id == egui::Id::NULL { ui.next_auto_id() } else { _parent_id.with(label) };
+			let parent_id = if _parent_id == egui::Id::NULL { egui::Id::NULL } else { id };
+			ui.scope(|ui| {
+				egui_field_editor::EguiInspect::inspect_with_custom_id(#field_access, parent_id, &#name_str, #tooltip, read_only || #read_only, ui)
+			}).inner
+		}
+	}
+
+}
+
+/// Generate the call to the `add_*` function (or custom function) matching the field attributes.
+///
+/// Returns `None` if the field is inspected through [`EguiInspect::inspect_with_custom_id`].
+fn get_attribute_call(field_access :TokenStream, field: &Field, attrs: &AttributeArgs, name_str: &str, tooltip: &str) -> Option<TokenStream> {
+	let read_only = attrs.read_only;
+	let slider= &attrs.slider;
+	let range= &attrs.range;
 	if let Some(custom_fn) = &attrs.custom_fn {
 		match custom_fn.parse::<TokenStream>() {
 			Ok(custom_fn_ident) => {
-				return quote_spanned! {
+				return Some(quote_spanned! {
 					field.span() => {
 						ui.scope(|ui| {
-							#custom_fn_ident(#field_access, &#name_str, #tooltip, read_only || #read_only, ui);
-						});
+							// custom functions may return `()`, an `InspectResponse` or an `egui::Response`
+							#[allow(clippy::useless_conversion, clippy::unit_arg)]
+							let response = egui_field_editor::InspectResponse::from(#custom_fn_ident(#field_access, &#name_str, #tooltip, read_only || #read_only, ui));
+							response
+						}).inner
 					}
-				};
+				});
 			},
 			Err(e) => {
 				let msg=e.to_string();
-				return quote_spanned! {
+				return Some(quote_spanned! {
 					field.span() => {
 						compile_error!(#msg);
 					}
-				};
+				});
 			}
 		}
 	} else if let Some(range) = slider {
 		let min = range.min;
 		let max = range.max;
-		let ty = proc_macro2::Ident::new(&get_path_str(&field.ty), proc_macro2::Span::call_site()); 
-		return quote_spanned! {
+		let (ty, add_fn) = match get_range_bound_type(&field.ty) {
+			Some(bound) => (bound.to_token_stream(), quote!(add_range_slider)),
+			None => (proc_macro2::Ident::new(&get_path_str(&field.ty), proc_macro2::Span::call_site()).to_token_stream(), quote!(add_number_slider)),
+		};
+		return Some(quote_spanned! {
 			field.span() => {
 				ui.scope(|ui| {
-					egui_field_editor::add_number_slider(#field_access, &#name_str, #tooltip, read_only || #read_only, #min as #ty, #max as #ty, ui);
-				});
+					egui_field_editor::#add_fn(#field_access, &#name_str, #tooltip, read_only || #read_only, #min as #ty, #max as #ty, ui)
+				}).inner
 			}
-		};
+		});
 	} else if let Some(range) = range {
 		let min = range.min;
 		let max = range.max;
-		let ty = proc_macro2::Ident::new(&get_path_str(&field.ty), proc_macro2::Span::call_site()); 
-		return quote_spanned! {field.span() => {
+		let (ty, add_fn) = match get_range_bound_type(&field.ty) {
+			Some(bound) => (bound.to_token_stream(), quote!(add_range)),
+			None => (proc_macro2::Ident::new(&get_path_str(&field.ty), proc_macro2::Span::call_site()).to_token_stream(), quote!(add_number)),
+		};
+		return Some(quote_spanned! {field.span() => {
 				ui.scope(|ui| {
-					egui_field_editor::add_number(#field_access, &#name_str, #tooltip, read_only || #read_only, Some((#min as #ty, #max as #ty)), ui);
-				});
+					egui_field_editor::#add_fn(#field_access, &#name_str, #tooltip, read_only || #read_only, Some((#min as #ty, #max as #ty)), ui)
+				}).inner
 			}
-		};
+		});
 	} else if attrs.from_string {
-		if let Some(multiline) = &attrs.multiline {
-			let nb_lines = multiline.0;
-			return quote_spanned! {
-				field.span() => {
-					ui.scope(|ui| {
-						egui_field_editor::add_string_convertible_multiline(#field_access, &#name_str, #tooltip, read_only || #read_only, #nb_lines, ui);
-					});
-				}
-			};
-		} else {
-			return quote_spanned! {
-				field.span() => {
-					ui.scope(|ui| {
-						egui_field_editor::add_string_convertible(#field_access, &#name_str, #tooltip, read_only || #read_only, ui);
-					});
-				}
-			};
-		}
+		let nb_lines = match &attrs.multiline {
+			Some(multiline) => {
+				let nb_lines = multiline.0;
+				quote! { Some(#nb_lines) }
+			},
+			None => quote! { None },
+		};
+		let commit = match attrs.commit.unwrap_or_default() {
+			CommitMode::OnValid => quote! { egui_field_editor::CommitMode::OnValid },
+			CommitMode::OnEnterOrFocusLost => quote! { egui_field_editor::CommitMode::OnEnterOrFocusLost },
+		};
+		return Some(quote_spanned! {
+			field.span() => {
+				ui.scope(|ui| {
+					#[allow(unused_imports)]
+					use egui_field_editor::derive_support::{ParseErrorDisplay as _, ParseErrorGeneric as _};
+					let format_error = (&&egui_field_editor::derive_support::ParseError::of(#field_access)).formatter();
+					egui_field_editor::add_string_convertible_with_options(#field_access, &#name_str, #tooltip, read_only || #read_only, #nb_lines, #commit, format_error, ui)
+				}).inner
+			}
+		});
 	} else if let Some(multiline) = &attrs.multiline {
 		let nb_lines = multiline.0;
-		return quote_spanned! {
+		return Some(quote_spanned! {
 			field.span() => {
 				ui.scope(|ui| {
-					egui_field_editor::add_string_multiline(#field_access, &#name_str, #tooltip, read_only || #read_only, #nb_lines, ui);
-				});
+					egui_field_editor::add_string_multiline(#field_access, &#name_str, #tooltip, read_only || #read_only, #nb_lines, ui)
+				}).inner
 			}
-		};
+		});
 	} else if attrs.color {
-		return quote_spanned! {field.span() => {
+		return Some(quote_spanned! {field.span() => {
 				ui.scope(|ui| {
-					egui_field_editor::add_color(#field_access, &#name_str, #tooltip, read_only || #read_only, ui);
-				});
+					egui_field_editor::add_color(#field_access, &#name_str, #tooltip, read_only || #read_only, ui)
+				}).inner
 			}
-		};
+		});
 	} else if let Some(file) = &attrs.file {
 		let filters: Vec<proc_macro2::TokenStream> = file.filter.iter().map(|e| {
 			let lit = syn::LitStr::new(e, proc_macro2::Span::call_site());
 			quote! { #lit }
 		}).collect();
-		return quote_spanned! {field.span() => {
+		return Some(quote_spanned! {field.span() => {
 				ui.scope(|ui| {
-					egui_field_editor::add_path(#field_access, &#name_str, #tooltip, read_only || #read_only, vec![#(#filters),*], ui);
-				});
+					egui_field_editor::add_path(#field_access, &#name_str, #tooltip, read_only || #read_only, vec![#(#filters),*], ui)
+				}).inner
 			}
-		};
+		});
 	} else if let Some(date) = &attrs.date {
 		let combo_boxes=date.combo_boxes;
 		let arrows=date.arrows;
@@ -161,7 +680,7 @@ pub(crate) fn get_function_call(field_access :TokenStream, field: &Field, attrs:
 			let max = range.max;
 			start_end_years = quote_spanned!{field.span() => {Some(#min..=#max)}};
 		}
-		return quote_spanned! {field.span() => {
+		return Some(quote_spanned! {field.span() => {
 				ui.scope(|ui| {
 					egui_field_editor::add_date(#field_access, id, &#name_str, #tooltip, read_only || #read_only,
 						#combo_boxes,
@@ -172,20 +691,57 @@ pub(crate) fn get_function_call(field_access :TokenStream, field: &Field, attrs:
 						#format.to_string(),
 						#highlight_weekends,
 						#start_end_years,
-						ui);
-				});
+						ui)
+				}).inner
 			}
-		};
+		});
 	}
+	None
+}
 
+/// Generate the code displaying the field in a derived `EguiDisplay` implementation.
+///
+/// Fields using an attribute are rendered by their `add_*` function on a clone (when it is possible).
+fn get_display_call(field_access :TokenStream, field: &Field, attrs: &AttributeArgs, name_str: &str, tooltip: &str) -> TokenStream {
+	if let Some(display_fn) = &attrs.display_fn {
+		return match display_fn.parse::<TokenStream>() {
+			Ok(display_fn_ident) => quote_spanned! {
+				field.span() => {
+					ui.scope(|ui| {
+						#[allow(clippy::let_unit_value)]
+						let _ = #display_fn_ident(#field_access, &#name_str, #tooltip, ui);
+					});
+					egui_field_editor::InspectResponse::default()
+				}
+			},
+			Err(e) => {
+				let msg=e.to_string();
+				quote_spanned! {
+					field.span() => {
+						compile_error!(#msg);
+					}
+				}
+			}
+		};
+	}
+	if let Some(call) = get_attribute_call(quote!{value}, field, attrs, name_str, tooltip) {
+		return quote_spanned! {
+			field.span() => {
+				#[allow(unused_imports)]
+				use egui_field_editor::derive_support::{ViaClone as _, ViaClonePlaceholder as _};
+				(&&egui_field_editor::derive_support::DisplayField(#field_access)).display_cloned(&#name_str, #tooltip, ui, |value, ui| #call)
+			}
+		};
+	}
 	quote_spanned! {
 		field.span() => {
 			let id = if _parent_id == egui::Id::NULL { ui.next_auto_id() } else { _parent_id.with(label) };
 			let parent_id = if _parent_id == egui::Id::NULL { egui::Id::NULL } else { id };
 			ui.scope(|ui| {
-				egui_field_editor::EguiInspect::inspect_with_custom_id(#field_access, parent_id, &#name_str, #tooltip, read_only || #read_only, ui);
-			});
+				#[allow(unused_imports)]
+				use egui_field_editor::derive_support::{ViaDisplay as _, ViaInspect as _, ViaPlaceholder as _};
+				(&&&egui_field_editor::derive_support::DisplayField(#field_access)).display_field(parent_id, &#name_str, #tooltip, ui)
+			}).inner
 		}
 	}
-
-}
\ No newline at end of file
+}